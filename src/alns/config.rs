@@ -1,5 +1,7 @@
 //! ALNS configuration.
 
+use crate::direction::Direction;
+
 /// Configuration for the ALNS algorithm.
 ///
 /// # Scoring
@@ -78,6 +80,19 @@ pub struct AlnsConfig {
     /// Minimum temperature (stops cooling below this).
     pub min_temperature: f64,
 
+    /// Number of best distinct solutions kept in
+    /// [`AlnsResult::hall_of_fame`](super::AlnsResult::hall_of_fame)
+    /// (0, the default, disables it).
+    pub hall_of_fame: usize,
+
+    /// Minimum [`AlnsProblem::distance`](super::AlnsProblem::distance)
+    /// between hall of fame entries. 0 only rejects duplicates.
+    pub hall_of_fame_distance: f64,
+
+    /// Whether [`AlnsProblem::cost`](super::AlnsProblem::cost) is minimized
+    /// (the default) or maximized.
+    pub direction: Direction,
+
     /// Random seed for reproducibility.
     pub seed: Option<u64>,
 }
@@ -97,6 +112,9 @@ impl Default for AlnsConfig {
             initial_temperature: 100.0,
             cooling_rate: 0.9995,
             min_temperature: 0.01,
+            hall_of_fame: 0,
+            hall_of_fame_distance: 0.0,
+            direction: Direction::Minimize,
             seed: None,
         }
     }
@@ -138,6 +156,17 @@ impl AlnsConfig {
         self
     }
 
+    pub fn with_hall_of_fame(mut self, size: usize, min_distance: f64) -> Self {
+        self.hall_of_fame = size;
+        self.hall_of_fame_distance = min_distance;
+        self
+    }
+
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     pub fn with_seed(mut self, seed: u64) -> Self {
         self.seed = Some(seed);
         self
@@ -169,6 +198,9 @@ impl AlnsConfig {
         if self.min_destroy_degree > self.max_destroy_degree {
             return Err("min_destroy_degree must be <= max_destroy_degree".into());
         }
+        if self.hall_of_fame_distance.is_nan() || self.hall_of_fame_distance < 0.0 {
+            return Err("hall_of_fame_distance must be non-negative".into());
+        }
         Ok(())
     }
 }
@@ -218,6 +250,12 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_bad_hall_of_fame_distance() {
+        let config = AlnsConfig::default().with_hall_of_fame(5, -1.0);
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_builder_chain() {
         let config = AlnsConfig::default()
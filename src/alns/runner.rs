@@ -2,6 +2,8 @@
 
 use super::config::AlnsConfig;
 use super::types::{AlnsProblem, DestroyOperator, RepairOperator};
+use crate::hall_of_fame::HallOfFame;
+use crate::observer::{Event, NoopObserver, Observer, OperatorKind, TerminationReason};
 use rand::Rng;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -36,6 +38,11 @@ pub struct AlnsResult<S: Clone> {
 
     /// Best cost sampled at regular intervals.
     pub cost_history: Vec<f64>,
+
+    /// The best distinct solutions evaluated, with their costs, best first.
+    ///
+    /// Empty unless [`AlnsConfig::hall_of_fame`] is set.
+    pub hall_of_fame: Vec<(S, f64)>,
 }
 
 /// Tracks per-operator statistics for adaptive weight updates.
@@ -134,6 +141,39 @@ impl AlnsRunner {
         config: &AlnsConfig,
         cancel: Option<Arc<AtomicBool>>,
     ) -> Result<AlnsResult<P::Solution>, String>
+    where
+        P: AlnsProblem,
+        D: DestroyOperator<P::Solution>,
+        RP: RepairOperator<P::Solution>,
+    {
+        Self::run_observed(
+            problem,
+            destroy_ops,
+            repair_ops,
+            config,
+            cancel,
+            &mut NoopObserver,
+        )
+    }
+
+    /// Runs ALNS, reporting progress events to `observer`.
+    ///
+    /// Each iteration emits two [`Event::OperatorSelected`] (destroy, then
+    /// repair, with the operator names), [`Event::NewBest`] when the
+    /// candidate is a new best, [`Event::TemperatureChanged`] while the
+    /// temperature is above its minimum and [`Event::IterationFinished`].
+    /// The run ends with [`Event::Terminated`].
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid or operator slices are empty.
+    pub fn run_observed<P, D, RP>(
+        problem: &P,
+        destroy_ops: &[D],
+        repair_ops: &[RP],
+        config: &AlnsConfig,
+        cancel: Option<Arc<AtomicBool>>,
+        observer: &mut dyn Observer,
+    ) -> Result<AlnsResult<P::Solution>, String>
     where
         P: AlnsProblem,
         D: DestroyOperator<P::Solution>,
@@ -152,11 +192,18 @@ impl AlnsRunner {
             None => create_rng(rand::random()),
         };
 
+        // Costs are minimized internally and reported in the configured
+        // direction
+        let direction = config.direction;
+
         // Initialize
         let mut current = problem.initial_solution(&mut rng);
-        let mut current_cost = problem.cost(&current);
+        let mut current_cost = direction.cost(problem.cost(&current));
         let mut best = current.clone();
         let mut best_cost = current_cost;
+        let mut hall_of_fame = HallOfFame::new(config.hall_of_fame, config.hall_of_fame_distance);
+        let distance = |a: &P::Solution, b: &P::Solution| problem.distance(a, b);
+        hall_of_fame.offer_solution(&current, current_cost, distance);
 
         let mut destroy_stats: Vec<OperatorStats> =
             destroy_ops.iter().map(|_| OperatorStats::new()).collect();
@@ -183,6 +230,18 @@ impl AlnsRunner {
             // Select operators via roulette wheel
             let d_idx = roulette_select(&destroy_stats, &mut rng);
             let r_idx = roulette_select(&repair_stats, &mut rng);
+            observer.on_event(&Event::OperatorSelected {
+                iteration: iteration + 1,
+                kind: OperatorKind::Destroy,
+                index: d_idx,
+                name: Some(destroy_ops[d_idx].name()),
+            });
+            observer.on_event(&Event::OperatorSelected {
+                iteration: iteration + 1,
+                kind: OperatorKind::Repair,
+                index: r_idx,
+                name: Some(repair_ops[r_idx].name()),
+            });
 
             // Determine destroy degree
             let degree = rng.random_range(config.min_destroy_degree..config.max_destroy_degree);
@@ -190,7 +249,8 @@ impl AlnsRunner {
             // Destroy then repair
             let destroyed = destroy_ops[d_idx].destroy(&current, degree, &mut rng);
             let candidate = repair_ops[r_idx].repair(&destroyed, &mut rng);
-            let candidate_cost = problem.cost(&candidate);
+            let candidate_cost = direction.cost(problem.cost(&candidate));
+            hall_of_fame.offer_solution(&candidate, candidate_cost, distance);
 
             // Determine score and acceptance
             let (accepted, score) = if candidate_cost < best_cost {
@@ -198,6 +258,10 @@ impl AlnsRunner {
                 best = candidate.clone();
                 best_cost = candidate_cost;
                 improvements += 1;
+                observer.on_event(&Event::NewBest {
+                    iteration: iteration + 1,
+                    cost: direction.value(best_cost),
+                });
                 (true, config.score_new_best)
             } else if candidate_cost < current_cost {
                 // Better than current (sigma_2)
@@ -227,7 +291,14 @@ impl AlnsRunner {
             repair_stats[r_idx].record(score);
 
             // Cool down
+            let previous_temperature = temperature;
             temperature = (temperature * config.cooling_rate).max(config.min_temperature);
+            if temperature != previous_temperature {
+                observer.on_event(&Event::TemperatureChanged {
+                    iteration: iteration + 1,
+                    temperature,
+                });
+            }
 
             // End-of-segment weight update
             if (iteration + 1) % config.segment_length == 0 {
@@ -243,6 +314,12 @@ impl AlnsRunner {
             if (iteration + 1).is_multiple_of(history_interval) {
                 cost_history.push(best_cost);
             }
+
+            observer.on_event(&Event::IterationFinished {
+                iteration: iteration + 1,
+                current_cost: direction.value(current_cost),
+                best_cost: direction.value(best_cost),
+            });
         }
 
         // Final history entry
@@ -253,20 +330,35 @@ impl AlnsRunner {
             cost_history.push(best_cost);
         }
 
-        Ok(AlnsResult {
-            best,
-            best_cost,
-            iterations: if cancelled {
-                cost_history.len().saturating_sub(1) * history_interval
+        let iterations = if cancelled {
+            cost_history.len().saturating_sub(1) * history_interval
+        } else {
+            config.max_iterations
+        };
+        observer.on_event(&Event::Terminated {
+            iterations,
+            best_cost: direction.value(best_cost),
+            reason: if cancelled {
+                TerminationReason::Cancelled
             } else {
-                config.max_iterations
+                TerminationReason::MaxIterations
             },
+        });
+
+        Ok(AlnsResult {
+            best,
+            best_cost: direction.value(best_cost),
+            iterations,
             improvements,
             final_temperature: temperature,
             cancelled,
             destroy_weights: destroy_stats.iter().map(|s| s.weight).collect(),
             repair_weights: repair_stats.iter().map(|s| s.weight).collect(),
-            cost_history,
+            cost_history: cost_history
+                .into_iter()
+                .map(|c| direction.value(c))
+                .collect(),
+            hall_of_fame: hall_of_fame.into_values(direction),
         })
     }
 }
@@ -275,6 +367,7 @@ impl AlnsRunner {
 mod tests {
     use super::*;
     use crate::alns::AlnsConfig;
+    use crate::direction::Direction;
 
     // ---- Set Cover Minimization ----
     // Solution: Vec<bool> (which elements to include)
@@ -441,6 +534,59 @@ mod tests {
         assert_eq!(result.iterations, 500);
     }
 
+    /// Subset problem whose hall of fame tells apart subsets of equal size.
+    struct HammingSubset(SubsetProblem);
+
+    impl AlnsProblem for HammingSubset {
+        type Solution = Vec<bool>;
+
+        fn initial_solution<R: Rng>(&self, rng: &mut R) -> Vec<bool> {
+            self.0.initial_solution(rng)
+        }
+
+        fn cost(&self, solution: &Vec<bool>) -> f64 {
+            self.0.cost(solution)
+        }
+
+        fn distance(&self, a: &Vec<bool>, b: &Vec<bool>) -> Option<f64> {
+            Some(a.iter().zip(b).filter(|(x, y)| x != y).count() as f64)
+        }
+    }
+
+    #[test]
+    fn test_alns_hall_of_fame() {
+        let destroy_ops = [RandomDestroy];
+        let repair_ops = [GreedyRepair];
+        let config = AlnsConfig::default()
+            .with_max_iterations(300)
+            .with_hall_of_fame(4, 0.0)
+            .with_seed(42);
+
+        // The default distance keeps one subset per cost
+        let problem = SubsetProblem { n: 20 };
+        let result = AlnsRunner::run(&problem, &destroy_ops, &repair_ops, &config).unwrap();
+        let costs: Vec<f64> = result.hall_of_fame.iter().map(|&(_, c)| c).collect();
+        assert_eq!(costs.len(), 4);
+        assert_eq!(costs[0], result.best_cost);
+        assert!(costs.windows(2).all(|w| w[0] < w[1]));
+
+        // A Hamming distance keeps different subsets of equal cost
+        let problem = HammingSubset(SubsetProblem { n: 20 });
+        let result = AlnsRunner::run(&problem, &destroy_ops, &repair_ops, &config).unwrap();
+        assert_eq!(result.hall_of_fame.len(), 4);
+        assert_eq!(result.hall_of_fame[0].1, result.best_cost);
+        for (i, (a, cost)) in result.hall_of_fame.iter().enumerate() {
+            assert_eq!(problem.cost(a), *cost);
+            for (b, _) in &result.hall_of_fame[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+        assert!(
+            result.hall_of_fame.windows(2).any(|w| w[0].1 == w[1].1),
+            "subsets of equal size should share the hall of fame"
+        );
+    }
+
     #[test]
     fn test_alns_weight_adaptation() {
         let problem = SubsetProblem { n: 20 };
@@ -531,6 +677,45 @@ mod tests {
         assert!(result.improvements > 0, "expected at least one improvement");
     }
 
+    /// `SubsetProblem` as a maximization of the number of true bits.
+    struct CountProblem {
+        n: usize,
+    }
+
+    impl AlnsProblem for CountProblem {
+        type Solution = Vec<bool>;
+
+        fn initial_solution<R: Rng>(&self, rng: &mut R) -> Vec<bool> {
+            SubsetProblem { n: self.n }.initial_solution(rng)
+        }
+
+        fn cost(&self, solution: &Vec<bool>) -> f64 {
+            -SubsetProblem { n: self.n }.cost(solution)
+        }
+    }
+
+    #[test]
+    fn test_alns_maximize_mirrors_minimize() {
+        let destroy_ops = [RandomDestroy];
+        let repair_ops = [GreedyRepair];
+        let config = AlnsConfig::default().with_max_iterations(300).with_seed(42);
+
+        let min =
+            AlnsRunner::run(&SubsetProblem { n: 15 }, &destroy_ops, &repair_ops, &config).unwrap();
+        let max = AlnsRunner::run(
+            &CountProblem { n: 15 },
+            &destroy_ops,
+            &repair_ops,
+            &config.with_direction(Direction::Maximize),
+        )
+        .unwrap();
+
+        assert_eq!(max.best, min.best);
+        assert_eq!(max.best_cost, -min.best_cost);
+        assert!(max.cost_history.windows(2).all(|w| w[1] >= w[0]));
+        assert_eq!(max.improvements, min.improvements);
+    }
+
     // ---- Continuous minimization: f(x) = sum(x_i^2) ----
 
     struct ContinuousProblem {
@@ -602,4 +787,46 @@ mod tests {
             result.best_cost
         );
     }
+
+    #[test]
+    fn test_alns_observer_reports_operator_names() {
+        let problem = SubsetProblem { n: 20 };
+        let destroy_ops = [
+            TestDestroy::Random(RandomDestroy),
+            TestDestroy::Worst(WorstDestroy),
+        ];
+        let repair_ops = [
+            TestRepair::Greedy(GreedyRepair),
+            TestRepair::Full(FullRepair),
+        ];
+        let config = AlnsConfig::default().with_max_iterations(200).with_seed(42);
+
+        let mut selections = Vec::new();
+        let mut iterations = 0;
+        let mut terminated = None;
+        let result = AlnsRunner::run_observed(
+            &problem,
+            &destroy_ops,
+            &repair_ops,
+            &config,
+            None,
+            &mut |event: &Event<'_>| match *event {
+                Event::OperatorSelected { kind, name, .. } => {
+                    selections.push((kind, name.unwrap().to_string()))
+                }
+                Event::IterationFinished { .. } => iterations += 1,
+                Event::Terminated { reason, .. } => terminated = Some(reason),
+                _ => {}
+            },
+        )
+        .unwrap();
+
+        assert_eq!(iterations, result.iterations);
+        assert_eq!(selections.len(), 2 * result.iterations);
+        assert_eq!(selections[0].0, OperatorKind::Destroy);
+        assert_eq!(selections[1].0, OperatorKind::Repair);
+        let names = [destroy_ops[0].name(), destroy_ops[1].name()];
+        assert!(names.contains(&selections[0].1.as_str()));
+        assert_eq!(terminated, Some(TerminationReason::MaxIterations));
+    }
 }
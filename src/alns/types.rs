@@ -83,6 +83,20 @@ pub trait AlnsProblem: Send + Sync {
     /// Creates a random initial solution.
     fn initial_solution<R: Rng>(&self, rng: &mut R) -> Self::Solution;
 
-    /// Computes the cost of a solution. Lower is better.
+    /// Computes the cost of a solution. Lower is better unless
+    /// [`AlnsConfig::direction`](super::AlnsConfig::direction) maximizes.
     fn cost(&self, solution: &Self::Solution) -> f64;
+
+    /// Returns the distance between two solutions, used to keep the hall
+    /// of fame diverse (see
+    /// [`AlnsConfig::hall_of_fame`](super::AlnsConfig::hall_of_fame)).
+    ///
+    /// It should be symmetric, non-negative and 0 for identical solutions.
+    /// The default returns `None`, which uses the absolute difference of
+    /// the costs already computed and treats solutions of equal cost as
+    /// identical. Override it when solutions of equal cost should be kept
+    /// apart.
+    fn distance(&self, _a: &Self::Solution, _b: &Self::Solution) -> Option<f64> {
+        None
+    }
 }
@@ -1,5 +1,7 @@
 //! BRKGA configuration.
 
+use crate::direction::Direction;
+
 /// Configuration for the BRKGA algorithm.
 ///
 /// # Parameters
@@ -44,12 +46,56 @@ pub struct BrkgaConfig {
     /// Maximum number of generations.
     pub max_generations: usize,
 
-    /// Generations with no improvement before stopping (0 to disable).
+    /// Generations with no improvement before stopping, or restarting if
+    /// `restart` is set (0 to disable).
     pub stagnation_limit: usize,
 
+    /// Restart instead of stopping when the stagnation limit is reached
+    /// (`None` by default).
+    pub restart: Option<BrkgaRestart>,
+
     /// Whether to decode chromosomes in parallel using rayon.
     pub parallel: bool,
 
+    /// Number of chromosomes passed to each call of
+    /// [`BrkgaDecoder::decode_batch`](super::BrkgaDecoder::decode_batch)
+    /// (0 decodes them one at a time).
+    ///
+    /// With `parallel`, several batches are decoded concurrently. Cached
+    /// chromosomes are left out of the batches.
+    pub batch_size: usize,
+
+    /// Maximum number of memoized decodings (0 disables the cache).
+    ///
+    /// Chromosomes are looked up by their keys quantized to
+    /// `cache_resolution` levels, so nearly identical chromosomes share an
+    /// entry. Least recently used entries are evicted first.
+    pub cache_capacity: usize,
+
+    /// Number of quantization levels per key in `[0, 1)` for cache lookups.
+    ///
+    /// Keys closer than `1 / cache_resolution` may share a cached cost; use
+    /// a coarser value only if the decoder is insensitive to such changes.
+    pub cache_resolution: u32,
+
+    /// Generations between checkpoints passed to
+    /// [`BrkgaDecoder::on_checkpoint`](super::BrkgaDecoder::on_checkpoint)
+    /// (0 disables checkpointing).
+    pub checkpoint_interval: usize,
+
+    /// Number of best distinct chromosomes kept in
+    /// [`BrkgaResult::hall_of_fame`](super::BrkgaResult::hall_of_fame)
+    /// (0, the default, disables it).
+    pub hall_of_fame: usize,
+
+    /// Minimum [`BrkgaDecoder::distance`](super::BrkgaDecoder::distance)
+    /// between hall of fame entries. 0 only rejects duplicates.
+    pub hall_of_fame_distance: f64,
+
+    /// Whether [`BrkgaDecoder::decode`](super::BrkgaDecoder::decode) costs
+    /// are minimized (the default) or maximized.
+    pub direction: Direction,
+
     /// Random seed for reproducibility.
     pub seed: Option<u64>,
 }
@@ -65,7 +111,15 @@ impl BrkgaConfig {
             elite_inheritance_prob: 0.70,
             max_generations: 500,
             stagnation_limit: 50,
+            restart: None,
             parallel: true,
+            batch_size: 0,
+            cache_capacity: 0,
+            cache_resolution: 1_000_000,
+            checkpoint_interval: 0,
+            hall_of_fame: 0,
+            hall_of_fame_distance: 0.0,
+            direction: Direction::Minimize,
             seed: None,
         }
     }
@@ -100,11 +154,47 @@ impl BrkgaConfig {
         self
     }
 
+    pub fn with_restart(mut self, restart: BrkgaRestart) -> Self {
+        self.restart = Some(restart);
+        self
+    }
+
     pub fn with_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
         self
     }
 
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache_capacity = capacity;
+        self
+    }
+
+    pub fn with_cache_resolution(mut self, levels: u32) -> Self {
+        self.cache_resolution = levels;
+        self
+    }
+
+    pub fn with_checkpoint_interval(mut self, generations: usize) -> Self {
+        self.checkpoint_interval = generations;
+        self
+    }
+
+    pub fn with_hall_of_fame(mut self, size: usize, min_distance: f64) -> Self {
+        self.hall_of_fame = size;
+        self.hall_of_fame_distance = min_distance;
+        self
+    }
+
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     pub fn with_seed(mut self, seed: u64) -> Self {
         self.seed = Some(seed);
         self
@@ -134,10 +224,67 @@ impl BrkgaConfig {
         if self.max_generations == 0 {
             return Err("max_generations must be at least 1".into());
         }
+        if self.cache_resolution == 0 {
+            return Err("cache_resolution must be at least 1".into());
+        }
+        if self.hall_of_fame_distance.is_nan() || self.hall_of_fame_distance < 0.0 {
+            return Err("hall_of_fame_distance must be non-negative".into());
+        }
+        if let Some(restart) = &self.restart {
+            if restart.keep == 0 || restart.keep >= self.population_size {
+                return Err(format!(
+                    "restart keep must be in 1..population_size ({}), got {}",
+                    self.population_size, restart.keep
+                ));
+            }
+            if !(restart.perturbation > 0.0 && restart.perturbation <= 1.0) {
+                return Err("restart perturbation must be in (0, 1]".into());
+            }
+            if restart.max_restarts == 0 {
+                return Err("max_restarts must be at least 1".into());
+            }
+        }
         Ok(())
     }
 }
 
+/// Restart of a stagnated BRKGA population.
+///
+/// The `keep` best chromosomes survive. Each of the others is a copy of a
+/// random survivor with every key redrawn with probability
+/// `perturbation`; at 1.0 the chromosomes are drawn afresh, which is the
+/// BRKGA reset operator. `max_generations` remains the budget of the whole
+/// run, restarts included.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::brkga::{BrkgaConfig, BrkgaRestart};
+///
+/// let config = BrkgaConfig::new(50)
+///     .with_stagnation_limit(30)
+///     .with_restart(BrkgaRestart {
+///         keep: 1,
+///         perturbation: 1.0,
+///         max_restarts: 5,
+///     });
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BrkgaRestart {
+    /// Number of best chromosomes that survive, at least 1 and less than
+    /// the population size.
+    pub keep: usize,
+
+    /// Probability in `(0, 1]` that a key of a surviving chromosome's copy
+    /// is redrawn.
+    pub perturbation: f64,
+
+    /// Maximum number of restarts, at least 1. Once they are used up, the
+    /// next stagnation ends the run.
+    pub max_restarts: usize,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -171,6 +318,43 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_restart() {
+        let restart = BrkgaRestart {
+            keep: 1,
+            perturbation: 1.0,
+            max_restarts: 3,
+        };
+        let config = BrkgaConfig::new(10).with_population_size(20);
+        assert!(config.clone().with_restart(restart).validate().is_ok());
+        for invalid in [
+            BrkgaRestart {
+                keep: 20,
+                ..restart
+            },
+            BrkgaRestart {
+                perturbation: 0.0,
+                ..restart
+            },
+            BrkgaRestart {
+                perturbation: f64::NAN,
+                ..restart
+            },
+            BrkgaRestart {
+                max_restarts: 0,
+                ..restart
+            },
+        ] {
+            assert!(config.clone().with_restart(invalid).validate().is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_hall_of_fame_distance() {
+        let config = BrkgaConfig::new(10).with_hall_of_fame(5, -1.0);
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_clamp_inheritance() {
         let config = BrkgaConfig::new(10).with_elite_inheritance_prob(0.3);
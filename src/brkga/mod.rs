@@ -17,6 +17,6 @@ mod config;
 mod runner;
 mod types;
 
-pub use config::BrkgaConfig;
-pub use runner::{BrkgaResult, BrkgaRunner};
+pub use config::{BrkgaConfig, BrkgaRestart};
+pub use runner::{BrkgaResult, BrkgaRunner, BrkgaState};
 pub use types::BrkgaDecoder;
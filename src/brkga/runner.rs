@@ -1,18 +1,24 @@
 //! BRKGA evolutionary loop.
 
-use super::config::BrkgaConfig;
+use super::config::{BrkgaConfig, BrkgaRestart};
 use super::types::BrkgaDecoder;
+use crate::cache::LruCache;
+use crate::direction::Direction;
+use crate::ga::GenerationStats;
+use crate::hall_of_fame::HallOfFame;
+use crate::observer::{Event, NoopObserver, Observer, TerminationReason};
+use crate::rng::{create_checkpoint_rng, CheckpointRng, RngState};
 use rand::Rng;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use u_numflow::random::create_rng;
 
 /// A chromosome in the BRKGA population.
 #[derive(Debug, Clone)]
 struct Chromosome {
     keys: Vec<f64>,
+    /// Decoded cost, negated when maximizing so that lower is better.
     cost: f64,
 }
 
@@ -25,9 +31,17 @@ pub struct BrkgaResult {
     /// Cost of the best solution.
     pub best_cost: f64,
 
+    /// The best distinct chromosomes decoded, with their costs, best first.
+    ///
+    /// Empty unless [`BrkgaConfig::hall_of_fame`] is set.
+    pub hall_of_fame: Vec<(Vec<f64>, f64)>,
+
     /// Number of generations executed.
     pub generations: usize,
 
+    /// Number of restarts on stagnation (see [`BrkgaConfig::restart`]).
+    pub restarts: usize,
+
     /// Whether terminated due to stagnation.
     pub stagnated: bool,
 
@@ -36,6 +50,59 @@ pub struct BrkgaResult {
 
     /// Best cost at the end of each generation.
     pub cost_history: Vec<f64>,
+
+    /// Decodings answered by the cache (0 unless `cache_capacity > 0`).
+    pub cache_hits: usize,
+
+    /// Decodings that missed the cache and called `decode`.
+    pub cache_misses: usize,
+}
+
+/// Snapshot of a BRKGA run between two generations.
+///
+/// Delivered to [`BrkgaDecoder::on_checkpoint`] every
+/// [`BrkgaConfig::checkpoint_interval`] generations; pass it to
+/// [`BrkgaRunner::resume_from`] to continue the run. Serializable with the
+/// `serde` feature (infinite costs need a format that can represent them,
+/// which excludes JSON).
+///
+/// A resumed run reproduces the uninterrupted one exactly, except that the
+/// decoding cache and its hit/miss counters start empty. Taking checkpoints
+/// does not change the course of a run.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BrkgaState {
+    /// Keys of the current population, sorted from best to worst cost.
+    pub population: Vec<Vec<f64>>,
+
+    /// Cost of each chromosome in `population`.
+    pub costs: Vec<f64>,
+
+    /// The best random-key chromosome found so far.
+    pub best_keys: Vec<f64>,
+
+    /// Cost of the best chromosome.
+    pub best_cost: f64,
+
+    /// Hall of fame chromosomes and their costs so far, best first.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hall_of_fame: Vec<(Vec<f64>, f64)>,
+
+    /// Number of completed generations.
+    pub generation: usize,
+
+    /// Generations since the last improvement or restart.
+    pub stagnation_counter: usize,
+
+    /// Number of restarts so far.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub restarts: usize,
+
+    /// State of the random number generator.
+    pub rng: RngState,
+
+    /// Best cost at the end of each completed generation.
+    pub cost_history: Vec<f64>,
 }
 
 /// Executes the BRKGA algorithm.
@@ -58,22 +125,32 @@ impl BrkgaRunner {
         decoder: &D,
         config: &BrkgaConfig,
         cancel: Option<Arc<AtomicBool>>,
+    ) -> Result<BrkgaResult, String> {
+        Self::run_observed(decoder, config, cancel, &mut NoopObserver)
+    }
+
+    /// Runs BRKGA, reporting progress events to `observer`.
+    ///
+    /// Emits [`Event::NewBest`], [`Event::GenerationFinished`] (statistics
+    /// over chromosome costs) and [`Event::Terminated`].
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid.
+    pub fn run_observed<D: BrkgaDecoder>(
+        decoder: &D,
+        config: &BrkgaConfig,
+        cancel: Option<Arc<AtomicBool>>,
+        observer: &mut dyn Observer,
     ) -> Result<BrkgaResult, String> {
         config.validate()?;
 
-        let mut rng = match config.seed {
-            Some(seed) => create_rng(seed),
-            None => create_rng(rand::random()),
-        };
+        let mut rng = create_checkpoint_rng(config.seed);
 
         let n = config.chromosome_length;
-        let pop_size = config.population_size;
-        let elite_count = (pop_size as f64 * config.elite_fraction) as usize;
-        let mutant_count = (pop_size as f64 * config.mutant_fraction) as usize;
-        let crossover_count = pop_size - elite_count - mutant_count;
+        let cache = LruCache::new(config.cache_capacity);
 
         // Initialize population
-        let mut population: Vec<Chromosome> = (0..pop_size)
+        let mut population: Vec<Chromosome> = (0..config.population_size)
             .map(|_| {
                 let keys = match decoder.seed_chromosome(&mut rng) {
                     Some(k) if k.len() == n => k,
@@ -87,137 +164,508 @@ impl BrkgaRunner {
             .collect();
 
         // Evaluate initial population
-        decode_population(decoder, &mut population, config.parallel);
+        decode_population(decoder, &mut population, config, &cache);
 
         // Sort by cost (ascending)
-        population.sort_by(|a, b| {
-            a.cost
-                .partial_cmp(&b.cost)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+        sort_by_cost(&mut population);
+
+        let mut hall_of_fame = HallOfFame::new(config.hall_of_fame, config.hall_of_fame_distance);
+        record_hall_of_fame(decoder, &mut hall_of_fame, &population);
+        let best = population[0].clone();
+        let mut cost_history = Vec::with_capacity(config.max_generations + 1);
+        cost_history.push(config.direction.value(best.cost));
+
+        let run = Run {
+            population,
+            best,
+            hall_of_fame,
+            generation: 0,
+            stagnation_counter: 0,
+            restarts: 0,
+            cost_history,
+        };
+        Ok(evolve(decoder, config, run, rng, &cache, cancel, observer))
+    }
 
-        let mut best = population[0].clone();
-        let mut cost_history = Vec::with_capacity(config.max_generations);
-        cost_history.push(best.cost);
+    /// Continues a run from a checkpoint taken by
+    /// [`BrkgaDecoder::on_checkpoint`].
+    ///
+    /// `config` should be the configuration of the interrupted run;
+    /// `max_generations` may be raised to extend it.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid or the state does
+    /// not match `population_size` and `chromosome_length`.
+    pub fn resume_from<D: BrkgaDecoder>(
+        decoder: &D,
+        config: &BrkgaConfig,
+        state: BrkgaState,
+        cancel: Option<Arc<AtomicBool>>,
+    ) -> Result<BrkgaResult, String> {
+        config.validate()?;
+        if state.population.len() != config.population_size
+            || state.costs.len() != config.population_size
+        {
+            return Err(format!(
+                "checkpoint population has {} chromosomes, expected population_size {}",
+                state.population.len(),
+                config.population_size
+            ));
+        }
+        let n = config.chromosome_length;
+        if state.best_keys.len() != n
+            || state.population.iter().any(|keys| keys.len() != n)
+            || state.hall_of_fame.iter().any(|(keys, _)| keys.len() != n)
+        {
+            return Err(format!(
+                "checkpoint chromosomes must have chromosome_length {n} keys"
+            ));
+        }
 
-        let mut stagnation_counter = 0usize;
-        let mut cancelled = false;
+        let rng = state.rng.restore();
+        let cache = LruCache::new(config.cache_capacity);
+        let direction = config.direction;
+        let run = Run {
+            population: state
+                .population
+                .into_iter()
+                .zip(state.costs)
+                .map(|(keys, cost)| Chromosome {
+                    keys,
+                    cost: direction.cost(cost),
+                })
+                .collect(),
+            best: Chromosome {
+                keys: state.best_keys,
+                cost: direction.cost(state.best_cost),
+            },
+            hall_of_fame: HallOfFame::new(config.hall_of_fame, config.hall_of_fame_distance)
+                .with_entries(
+                    state
+                        .hall_of_fame
+                        .into_iter()
+                        .map(|(keys, cost)| (keys, direction.cost(cost)))
+                        .collect(),
+                ),
+            generation: state.generation,
+            stagnation_counter: state.stagnation_counter,
+            restarts: state.restarts,
+            cost_history: state.cost_history,
+        };
+        Ok(evolve(
+            decoder,
+            config,
+            run,
+            rng,
+            &cache,
+            cancel,
+            &mut NoopObserver,
+        ))
+    }
+}
 
-        // Evolutionary loop
-        for _gen in 0..config.max_generations {
-            if let Some(ref flag) = cancel {
-                if flag.load(Ordering::Relaxed) {
-                    cancelled = true;
-                    break;
-                }
-            }
+/// Mutable state of a run between generations.
+///
+/// Chromosome costs are oriented so that lower is better; `cost_history`
+/// is in the configured direction.
+struct Run {
+    population: Vec<Chromosome>,
+    best: Chromosome,
+    hall_of_fame: HallOfFame<(Vec<f64>, f64)>,
+    generation: usize,
+    stagnation_counter: usize,
+    restarts: usize,
+    cost_history: Vec<f64>,
+}
 
-            let mut next_gen: Vec<Chromosome> = Vec::with_capacity(pop_size);
+impl Run {
+    fn snapshot(&self, rng: &CheckpointRng, direction: Direction) -> BrkgaState {
+        BrkgaState {
+            population: self.population.iter().map(|c| c.keys.clone()).collect(),
+            costs: self
+                .population
+                .iter()
+                .map(|c| direction.value(c.cost))
+                .collect(),
+            best_keys: self.best.keys.clone(),
+            best_cost: direction.value(self.best.cost),
+            hall_of_fame: self
+                .hall_of_fame
+                .entries()
+                .iter()
+                .map(|(keys, cost)| (keys.clone(), direction.value(*cost)))
+                .collect(),
+            generation: self.generation,
+            stagnation_counter: self.stagnation_counter,
+            restarts: self.restarts,
+            rng: RngState::capture(rng),
+            cost_history: self.cost_history.clone(),
+        }
+    }
+}
 
-            // Phase 1: Elite copy
-            for chr in population.iter().take(elite_count) {
-                next_gen.push(chr.clone());
+/// Runs the evolutionary loop from `run` until a termination condition.
+fn evolve<D: BrkgaDecoder>(
+    decoder: &D,
+    config: &BrkgaConfig,
+    mut run: Run,
+    mut rng: CheckpointRng,
+    cache: &LruCache<Vec<u32>, f64>,
+    cancel: Option<Arc<AtomicBool>>,
+    observer: &mut dyn Observer,
+) -> BrkgaResult {
+    let n = config.chromosome_length;
+    let pop_size = config.population_size;
+    let elite_count = (pop_size as f64 * config.elite_fraction) as usize;
+    let mutant_count = (pop_size as f64 * config.mutant_fraction) as usize;
+    let crossover_count = pop_size - elite_count - mutant_count;
+    let direction = config.direction;
+
+    let mut cancelled = false;
+
+    // Evolutionary loop
+    while run.generation < config.max_generations {
+        if let Some(ref flag) = cancel {
+            if flag.load(Ordering::Relaxed) {
+                cancelled = true;
+                break;
             }
+        }
 
-            // Phase 2: Mutant injection
-            for _ in 0..mutant_count {
-                let keys: Vec<f64> = (0..n).map(|_| rng.random_range(0.0..1.0)).collect();
-                next_gen.push(Chromosome {
-                    keys,
-                    cost: f64::INFINITY,
-                });
-            }
+        let population = &run.population;
+        let mut next_gen: Vec<Chromosome> = Vec::with_capacity(pop_size);
 
-            // Phase 3: Biased uniform crossover
-            for _ in 0..crossover_count {
-                // One parent from elite, one from non-elite
-                let elite_idx = rng.random_range(0..elite_count);
-                let nonelite_idx = rng.random_range(elite_count..pop_size);
+        // Phase 1: Elite copy
+        for chr in population.iter().take(elite_count) {
+            next_gen.push(chr.clone());
+        }
 
-                let keys: Vec<f64> = (0..n)
-                    .map(|j| {
-                        if rng.random_range(0.0..1.0) < config.elite_inheritance_prob {
-                            population[elite_idx].keys[j]
-                        } else {
-                            population[nonelite_idx].keys[j]
-                        }
-                    })
-                    .collect();
+        // Phase 2: Mutant injection
+        for _ in 0..mutant_count {
+            let keys: Vec<f64> = (0..n).map(|_| rng.random_range(0.0..1.0)).collect();
+            next_gen.push(Chromosome {
+                keys,
+                cost: f64::INFINITY,
+            });
+        }
 
-                next_gen.push(Chromosome {
-                    keys,
-                    cost: f64::INFINITY,
-                });
-            }
+        // Phase 3: Biased uniform crossover
+        for _ in 0..crossover_count {
+            // One parent from elite, one from non-elite
+            let elite_idx = rng.random_range(0..elite_count);
+            let nonelite_idx = rng.random_range(elite_count..pop_size);
+
+            let keys: Vec<f64> = (0..n)
+                .map(|j| {
+                    if rng.random_range(0.0..1.0) < config.elite_inheritance_prob {
+                        population[elite_idx].keys[j]
+                    } else {
+                        population[nonelite_idx].keys[j]
+                    }
+                })
+                .collect();
+
+            next_gen.push(Chromosome {
+                keys,
+                cost: f64::INFINITY,
+            });
+        }
 
-            // Decode non-elite individuals
-            decode_population(decoder, &mut next_gen[elite_count..], config.parallel);
+        // Decode non-elite individuals
+        decode_population(decoder, &mut next_gen[elite_count..], config, cache);
+        record_hall_of_fame(decoder, &mut run.hall_of_fame, &next_gen[elite_count..]);
 
-            // Sort
-            next_gen.sort_by(|a, b| {
-                a.cost
-                    .partial_cmp(&b.cost)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
+        // Sort
+        sort_by_cost(&mut next_gen);
 
-            population = next_gen;
+        run.population = next_gen;
+        run.generation += 1;
 
-            // Update best
-            if population[0].cost < best.cost {
-                best = population[0].clone();
-                stagnation_counter = 0;
-            } else {
-                stagnation_counter += 1;
-            }
+        // Update best
+        if run.population[0].cost < run.best.cost {
+            run.best = run.population[0].clone();
+            run.stagnation_counter = 0;
+            observer.on_event(&Event::NewBest {
+                iteration: run.generation,
+                cost: direction.value(run.best.cost),
+            });
+        } else {
+            run.stagnation_counter += 1;
+        }
+
+        run.cost_history.push(direction.value(run.best.cost));
+        let costs: Vec<f64> = run
+            .population
+            .iter()
+            .map(|c| direction.value(c.cost))
+            .collect();
+        observer.on_event(&Event::GenerationFinished {
+            stats: &GenerationStats::from_costs(&costs, run.generation, direction),
+        });
 
-            cost_history.push(best.cost);
-
-            // Stagnation check
-            if config.stagnation_limit > 0 && stagnation_counter >= config.stagnation_limit {
-                return Ok(BrkgaResult {
-                    best_keys: best.keys,
-                    best_cost: best.cost,
-                    generations: cost_history.len() - 1,
-                    stagnated: true,
-                    cancelled: false,
-                    cost_history,
+        // Stagnation check: restart while restarts and generations remain
+        let stagnated =
+            config.stagnation_limit > 0 && run.stagnation_counter >= config.stagnation_limit;
+        let restart = config.restart.filter(|restart| {
+            stagnated
+                && run.restarts < restart.max_restarts
+                && run.generation < config.max_generations
+        });
+        if let Some(restart) = restart {
+            reset_population(
+                decoder,
+                &mut run.population,
+                &restart,
+                config,
+                cache,
+                &mut rng,
+            );
+            run.restarts += 1;
+            run.stagnation_counter = 0;
+            record_hall_of_fame(decoder, &mut run.hall_of_fame, &run.population);
+            if run.population[0].cost < run.best.cost {
+                run.best = run.population[0].clone();
+                observer.on_event(&Event::NewBest {
+                    iteration: run.generation,
+                    cost: direction.value(run.best.cost),
                 });
             }
         }
 
-        Ok(BrkgaResult {
-            best_keys: best.keys,
-            best_cost: best.cost,
-            generations: if cancelled {
-                cost_history.len().saturating_sub(1)
+        // Checkpoint
+        if config.checkpoint_interval > 0
+            && run.generation.is_multiple_of(config.checkpoint_interval)
+        {
+            decoder.on_checkpoint(&run.snapshot(&rng, direction));
+        }
+
+        if stagnated && restart.is_none() {
+            observer.on_event(&Event::Terminated {
+                iterations: run.generation,
+                best_cost: direction.value(run.best.cost),
+                reason: TerminationReason::Stagnated,
+            });
+            return BrkgaResult {
+                best_keys: run.best.keys,
+                best_cost: direction.value(run.best.cost),
+                hall_of_fame: run.hall_of_fame.into_values(direction),
+                generations: run.cost_history.len() - 1,
+                restarts: run.restarts,
+                stagnated: true,
+                cancelled: false,
+                cost_history: run.cost_history,
+                cache_hits: cache.hits(),
+                cache_misses: cache.misses(),
+            };
+        }
+    }
+
+    observer.on_event(&Event::Terminated {
+        iterations: run.generation,
+        best_cost: direction.value(run.best.cost),
+        reason: if cancelled {
+            TerminationReason::Cancelled
+        } else {
+            TerminationReason::MaxIterations
+        },
+    });
+
+    BrkgaResult {
+        best_keys: run.best.keys,
+        best_cost: direction.value(run.best.cost),
+        hall_of_fame: run.hall_of_fame.into_values(direction),
+        generations: if cancelled {
+            run.cost_history.len().saturating_sub(1)
+        } else {
+            config.max_generations.max(run.generation)
+        },
+        restarts: run.restarts,
+        stagnated: false,
+        cancelled,
+        cost_history: run.cost_history,
+        cache_hits: cache.hits(),
+        cache_misses: cache.misses(),
+    }
+}
+
+/// Keeps the `restart.keep` best chromosomes of the sorted `population`,
+/// replaces the others by perturbed copies of them (or fresh random keys),
+/// then decodes and sorts the population.
+fn reset_population<D: BrkgaDecoder, R: Rng>(
+    decoder: &D,
+    population: &mut Vec<Chromosome>,
+    restart: &BrkgaRestart,
+    config: &BrkgaConfig,
+    cache: &LruCache<Vec<u32>, f64>,
+    rng: &mut R,
+) {
+    let n = config.chromosome_length;
+    let keep = restart.keep.min(population.len());
+    let fresh: Vec<Chromosome> = (keep..population.len())
+        .map(|_| {
+            let keys = if restart.perturbation >= 1.0 {
+                (0..n).map(|_| rng.random_range(0.0..1.0)).collect()
             } else {
-                config.max_generations
-            },
-            stagnated: false,
-            cancelled,
-            cost_history,
+                let source = rng.random_range(0..keep);
+                population[source]
+                    .keys
+                    .iter()
+                    .map(|&k| {
+                        if rng.random_bool(restart.perturbation) {
+                            rng.random_range(0.0..1.0)
+                        } else {
+                            k
+                        }
+                    })
+                    .collect()
+            };
+            Chromosome {
+                keys,
+                cost: f64::INFINITY,
+            }
         })
+        .collect();
+    population.truncate(keep);
+    population.extend(fresh);
+
+    decode_population(decoder, &mut population[keep..], config, cache);
+    sort_by_cost(population);
+}
+
+/// Offers decoded `chromosomes` to the hall of fame.
+fn record_hall_of_fame<D: BrkgaDecoder>(
+    decoder: &D,
+    hall_of_fame: &mut HallOfFame<(Vec<f64>, f64)>,
+    chromosomes: &[Chromosome],
+) {
+    for chr in chromosomes {
+        hall_of_fame.offer_solution(&chr.keys, chr.cost, |a, b| decoder.distance(a, b));
     }
 }
 
-fn decode_population<D: BrkgaDecoder>(decoder: &D, population: &mut [Chromosome], parallel: bool) {
+fn sort_by_cost(population: &mut [Chromosome]) {
+    population.sort_by(|a, b| {
+        a.cost
+            .partial_cmp(&b.cost)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
+fn decode_population<D: BrkgaDecoder>(
+    decoder: &D,
+    population: &mut [Chromosome],
+    config: &BrkgaConfig,
+    cache: &LruCache<Vec<u32>, f64>,
+) {
+    let direction = config.direction;
+    if config.batch_size > 0 {
+        decode_batches(decoder, population, config, cache);
+        return;
+    }
     #[cfg(feature = "parallel")]
-    if parallel {
+    if config.parallel {
         population.par_iter_mut().for_each(|chr| {
-            chr.cost = decoder.decode(&chr.keys);
+            let cost = decode_cached(decoder, &chr.keys, config.cache_resolution, cache);
+            chr.cost = direction.cost(cost);
         });
         return;
     }
-    let _ = parallel;
     for chr in population.iter_mut() {
-        chr.cost = decoder.decode(&chr.keys);
+        let cost = decode_cached(decoder, &chr.keys, config.cache_resolution, cache);
+        chr.cost = direction.cost(cost);
+    }
+}
+
+/// Decodes the uncached chromosomes of `population` with
+/// [`BrkgaDecoder::decode_batch`].
+fn decode_batches<D: BrkgaDecoder>(
+    decoder: &D,
+    population: &mut [Chromosome],
+    config: &BrkgaConfig,
+    cache: &LruCache<Vec<u32>, f64>,
+) {
+    let mut misses = Vec::with_capacity(population.len());
+    for (i, chr) in population.iter_mut().enumerate() {
+        let quantized = cache
+            .is_enabled()
+            .then(|| quantize(&chr.keys, config.cache_resolution));
+        match quantized.as_ref().and_then(|q| cache.get(q)) {
+            Some(cost) => chr.cost = config.direction.cost(cost),
+            None => misses.push((i, quantized)),
+        }
+    }
+
+    let chromosomes: Vec<&[f64]> = misses
+        .iter()
+        .map(|&(i, _)| population[i].keys.as_slice())
+        .collect();
+    let costs = batch_costs(decoder, &chromosomes, config);
+    for ((i, quantized), cost) in misses.into_iter().zip(costs) {
+        population[i].cost = config.direction.cost(cost);
+        if let Some(quantized) = quantized {
+            cache.insert(quantized, cost);
+        }
+    }
+}
+
+/// Costs of `chromosomes`, `config.batch_size` at a time.
+fn batch_costs<D: BrkgaDecoder>(
+    decoder: &D,
+    chromosomes: &[&[f64]],
+    config: &BrkgaConfig,
+) -> Vec<f64> {
+    let decode = |batch: &[&[f64]]| {
+        let costs = decoder.decode_batch(batch);
+        assert_eq!(
+            costs.len(),
+            batch.len(),
+            "decode_batch must return one cost per chromosome"
+        );
+        costs
+    };
+    #[cfg(feature = "parallel")]
+    if config.parallel {
+        return chromosomes
+            .par_chunks(config.batch_size)
+            .flat_map_iter(decode)
+            .collect();
+    }
+    chromosomes
+        .chunks(config.batch_size)
+        .flat_map(decode)
+        .collect()
+}
+
+/// Keys quantized to `resolution` levels, the cache key of a chromosome.
+fn quantize(keys: &[f64], resolution: u32) -> Vec<u32> {
+    keys.iter()
+        .map(|&k| ((k.clamp(0.0, 1.0) * resolution as f64) as u32).min(resolution - 1))
+        .collect()
+}
+
+/// Decodes `keys`, memoizing the cost under the quantized keys.
+fn decode_cached<D: BrkgaDecoder>(
+    decoder: &D,
+    keys: &[f64],
+    resolution: u32,
+    cache: &LruCache<Vec<u32>, f64>,
+) -> f64 {
+    if !cache.is_enabled() {
+        return decoder.decode(keys);
     }
+    let quantized = quantize(keys, resolution);
+    if let Some(cost) = cache.get(&quantized) {
+        return cost;
+    }
+    let cost = decoder.decode(keys);
+    cache.insert(quantized, cost);
+    cost
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::brkga::BrkgaConfig;
+    use crate::brkga::{BrkgaConfig, BrkgaRestart};
 
     // ---- Permutation sorting: sort keys, cost = number of inversions ----
 
@@ -292,6 +740,32 @@ mod tests {
         );
     }
 
+    /// OneMax as a maximization of the number of keys above 0.5.
+    struct CountDecoder;
+
+    impl BrkgaDecoder for CountDecoder {
+        fn decode(&self, keys: &[f64]) -> f64 {
+            -OneMaxDecoder.decode(keys)
+        }
+    }
+
+    #[test]
+    fn test_brkga_maximize_mirrors_minimize() {
+        let config = BrkgaConfig::new(20)
+            .with_population_size(50)
+            .with_max_generations(50)
+            .with_seed(42)
+            .with_parallel(false);
+        let min = BrkgaRunner::run(&OneMaxDecoder, &config).unwrap();
+        let max =
+            BrkgaRunner::run(&CountDecoder, &config.with_direction(Direction::Maximize)).unwrap();
+
+        assert_eq!(max.best_keys, min.best_keys);
+        assert_eq!(max.best_cost, -min.best_cost);
+        let negated: Vec<f64> = min.cost_history.iter().map(|c| -c).collect();
+        assert_eq!(max.cost_history, negated);
+    }
+
     #[test]
     fn test_brkga_stagnation() {
         let decoder = OneMaxDecoder;
@@ -310,6 +784,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_brkga_restarts() {
+        let config = BrkgaConfig::new(5)
+            .with_population_size(30)
+            .with_max_generations(1000)
+            .with_stagnation_limit(10)
+            .with_seed(42)
+            .with_parallel(false);
+        let plain = BrkgaRunner::run(&OneMaxDecoder, &config).unwrap();
+        assert_eq!(plain.restarts, 0);
+
+        for perturbation in [1.0, 0.3] {
+            let restarted = config.clone().with_restart(BrkgaRestart {
+                keep: 2,
+                perturbation,
+                max_restarts: 2,
+            });
+            let result = BrkgaRunner::run(&OneMaxDecoder, &restarted).unwrap();
+
+            assert_eq!(result.restarts, 2);
+            assert!(result.stagnated);
+            // Identical up to the first stagnation, then 2 more stagnations
+            assert!(result.generations >= plain.generations + 20);
+            assert!(result.best_cost <= plain.best_cost);
+            assert!(result.cost_history.windows(2).all(|w| w[1] <= w[0]));
+        }
+    }
+
     #[test]
     fn test_brkga_cancellation() {
         let decoder = OneMaxDecoder;
@@ -353,6 +855,184 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_brkga_cache_preserves_results() {
+        let config = BrkgaConfig::new(12)
+            .with_population_size(40)
+            .with_max_generations(60)
+            .with_stagnation_limit(0)
+            .with_seed(42)
+            .with_parallel(false);
+        let cached_config = config
+            .clone()
+            .with_cache_capacity(1_000)
+            .with_cache_resolution(2); // OneMax only looks at k > 0.5
+
+        let plain = BrkgaRunner::run(&OneMaxDecoder, &config).unwrap();
+        let cached = BrkgaRunner::run(&OneMaxDecoder, &cached_config).unwrap();
+
+        assert_eq!(plain.cost_history, cached.cost_history);
+        assert_eq!(plain.cache_hits + plain.cache_misses, 0);
+        assert!(cached.cache_hits > 0, "duplicates should hit the cache");
+        // Initial population plus non-elites of every generation
+        assert_eq!(cached.cache_hits + cached.cache_misses, 40 + 60 * 32);
+    }
+
+    #[test]
+    fn test_brkga_hall_of_fame() {
+        let config = BrkgaConfig::new(20)
+            .with_population_size(30)
+            .with_max_generations(50)
+            .with_hall_of_fame(5, 0.0)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let result = BrkgaRunner::run(&OneMaxDecoder, &config).unwrap();
+        let hall_of_fame = &result.hall_of_fame;
+
+        assert_eq!(hall_of_fame.len(), 5);
+        assert_eq!(hall_of_fame[0].1, result.best_cost);
+        // The default distance keeps one chromosome per cost
+        assert!(hall_of_fame.windows(2).all(|w| w[0].1 < w[1].1));
+        for (keys, cost) in hall_of_fame {
+            assert_eq!(OneMaxDecoder.decode(keys), *cost);
+        }
+
+        let plain = BrkgaRunner::run(&OneMaxDecoder, &config.with_hall_of_fame(0, 0.0)).unwrap();
+        assert!(plain.hall_of_fame.is_empty());
+        assert_eq!(plain.cost_history, result.cost_history);
+    }
+
+    /// OneMax that records the size of every batch it decodes.
+    struct BatchedOneMax {
+        batches: std::sync::Mutex<Vec<usize>>,
+    }
+
+    impl BrkgaDecoder for BatchedOneMax {
+        fn decode(&self, keys: &[f64]) -> f64 {
+            OneMaxDecoder.decode(keys)
+        }
+
+        fn decode_batch(&self, chromosomes: &[&[f64]]) -> Vec<f64> {
+            self.batches.lock().unwrap().push(chromosomes.len());
+            chromosomes.iter().map(|keys| self.decode(keys)).collect()
+        }
+    }
+
+    #[test]
+    fn test_brkga_batch_decoding_matches_plain() {
+        let decoder = BatchedOneMax {
+            batches: std::sync::Mutex::new(Vec::new()),
+        };
+        let config = BrkgaConfig::new(12)
+            .with_population_size(40)
+            .with_max_generations(30)
+            .with_stagnation_limit(0)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let plain = BrkgaRunner::run(&decoder, &config).unwrap();
+        assert!(decoder.batches.lock().unwrap().is_empty());
+
+        let batched = BrkgaRunner::run(&decoder, &config.with_batch_size(16)).unwrap();
+        let batches = decoder.batches.lock().unwrap();
+
+        assert_eq!(plain.cost_history, batched.cost_history);
+        assert_eq!(batches[..3], [16, 16, 8], "initial population");
+        assert!(batches.iter().all(|&size| (1..=16).contains(&size)));
+    }
+
+    struct CheckpointedOneMax {
+        states: std::sync::Mutex<Vec<BrkgaState>>,
+    }
+
+    impl BrkgaDecoder for CheckpointedOneMax {
+        fn decode(&self, keys: &[f64]) -> f64 {
+            OneMaxDecoder.decode(keys)
+        }
+
+        fn on_checkpoint(&self, state: &BrkgaState) {
+            self.states.lock().unwrap().push(state.clone());
+        }
+    }
+
+    #[test]
+    fn test_brkga_resume_from_checkpoint() {
+        let decoder = CheckpointedOneMax {
+            states: std::sync::Mutex::new(Vec::new()),
+        };
+        let config = BrkgaConfig::new(30)
+            .with_population_size(40)
+            .with_max_generations(40)
+            .with_stagnation_limit(0)
+            .with_checkpoint_interval(15)
+            .with_hall_of_fame(3, 0.0)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let full = BrkgaRunner::run(&decoder, &config).unwrap();
+        let states = decoder.states.into_inner().unwrap();
+        assert_eq!(states.len(), 2);
+        assert_eq!(states[0].generation, 15);
+
+        let resumed =
+            BrkgaRunner::resume_from(&OneMaxDecoder, &config, states[0].clone(), None).unwrap();
+        assert_eq!(resumed.cost_history, full.cost_history);
+        assert_eq!(resumed.best_keys, full.best_keys);
+        assert_eq!(resumed.hall_of_fame, full.hall_of_fame);
+        assert_eq!(resumed.generations, 40);
+
+        let wrong = config.with_population_size(41);
+        assert!(BrkgaRunner::resume_from(&OneMaxDecoder, &wrong, states[1].clone(), None).is_err());
+    }
+
+    #[test]
+    fn test_brkga_checkpoints_do_not_change_the_run() {
+        let decoder = CheckpointedOneMax {
+            states: std::sync::Mutex::new(Vec::new()),
+        };
+        let config = BrkgaConfig::new(20)
+            .with_population_size(30)
+            .with_max_generations(25)
+            .with_stagnation_limit(0)
+            .with_seed(9)
+            .with_parallel(false);
+
+        let plain = BrkgaRunner::run(&OneMaxDecoder, &config).unwrap();
+        let checkpointed =
+            BrkgaRunner::run(&decoder, &config.clone().with_checkpoint_interval(4)).unwrap();
+        assert_eq!(checkpointed.cost_history, plain.cost_history);
+        assert_eq!(checkpointed.best_keys, plain.best_keys);
+        assert_eq!(decoder.states.into_inner().unwrap().len(), 6);
+    }
+
+    #[test]
+    fn test_brkga_resume_with_restarts() {
+        let decoder = CheckpointedOneMax {
+            states: std::sync::Mutex::new(Vec::new()),
+        };
+        let config = BrkgaConfig::new(5)
+            .with_population_size(20)
+            .with_max_generations(30)
+            .with_stagnation_limit(3)
+            .with_restart(BrkgaRestart {
+                keep: 1,
+                perturbation: 0.5,
+                max_restarts: 100,
+            })
+            .with_checkpoint_interval(1)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let full = BrkgaRunner::run(&decoder, &config).unwrap();
+        assert!(full.restarts > 0);
+        for state in decoder.states.into_inner().unwrap() {
+            let resumed = BrkgaRunner::resume_from(&OneMaxDecoder, &config, state, None).unwrap();
+            assert_eq!(resumed.cost_history, full.cost_history);
+            assert_eq!(resumed.restarts, full.restarts);
+        }
+    }
+
     #[test]
     fn test_brkga_parallel() {
         let decoder = OneMaxDecoder;
@@ -404,4 +1084,35 @@ mod tests {
             result.best_cost
         );
     }
+
+    #[test]
+    fn test_brkga_observer_events() {
+        let config = BrkgaConfig::new(10)
+            .with_population_size(30)
+            .with_max_generations(40)
+            .with_stagnation_limit(0)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let mut generations = Vec::new();
+        let mut bests = Vec::new();
+        let mut terminated = None;
+        let result =
+            BrkgaRunner::run_observed(&OneMaxDecoder, &config, None, &mut |event: &Event<'_>| {
+                match *event {
+                    Event::GenerationFinished { stats } => generations.push(stats.generation),
+                    Event::NewBest { cost, .. } => bests.push(cost),
+                    Event::Terminated { reason, .. } => terminated = Some(reason),
+                    _ => {}
+                }
+            })
+            .unwrap();
+
+        assert_eq!(generations, (1..=40).collect::<Vec<_>>());
+        assert!(bests.windows(2).all(|w| w[1] < w[0]));
+        if let Some(&last) = bests.last() {
+            assert_eq!(last, result.best_cost);
+        }
+        assert_eq!(terminated, Some(TerminationReason::MaxIterations));
+    }
 }
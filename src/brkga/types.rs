@@ -1,12 +1,14 @@
 //! Core trait for BRKGA.
 
+use super::runner::BrkgaState;
 use rand::Rng;
 
 /// Decoder trait for BRKGA.
 ///
 /// This is the **only** trait a user must implement to use BRKGA.
 /// It maps a random-key chromosome (a slice of `f64` in `[0, 1)`)
-/// to a cost value. Lower cost is better (minimization).
+/// to a cost value. Lower cost is better unless
+/// [`BrkgaConfig::direction`](super::BrkgaConfig::direction) maximizes.
 ///
 /// # Examples
 ///
@@ -34,9 +36,38 @@ pub trait BrkgaDecoder: Send + Sync {
     /// * `keys` - A slice of `f64` values in `[0.0, 1.0)`.
     ///   Length equals [`super::BrkgaConfig::chromosome_length`].
     ///
-    /// Lower cost is better (minimization).
+    /// Lower cost is better (minimization) unless the run maximizes, in
+    /// which case infeasible chromosomes should return `f64::NEG_INFINITY`
+    /// rather than `f64::INFINITY`.
     fn decode(&self, keys: &[f64]) -> f64;
 
+    /// Decodes several chromosomes at once, returning one cost per
+    /// chromosome in the same order.
+    ///
+    /// Called instead of [`decode`](BrkgaDecoder::decode) when
+    /// [`BrkgaConfig::batch_size`](super::BrkgaConfig::batch_size) is set.
+    /// The default decodes the chromosomes one by one.
+    ///
+    /// # Panics
+    /// The runner panics if the result has the wrong length.
+    fn decode_batch(&self, chromosomes: &[&[f64]]) -> Vec<f64> {
+        chromosomes.iter().map(|keys| self.decode(keys)).collect()
+    }
+
+    /// Returns the distance between two chromosomes, used to keep the hall
+    /// of fame diverse (see
+    /// [`BrkgaConfig::hall_of_fame`](super::BrkgaConfig::hall_of_fame)).
+    ///
+    /// It should be symmetric, non-negative and 0 for chromosomes that
+    /// decode to the same solution. The default returns `None`, which uses
+    /// the absolute difference of the decoded costs without decoding again
+    /// and treats solutions of equal cost as identical. Override it to
+    /// compare the decoded solutions, e.g. the number of positions at which
+    /// two decoded permutations differ.
+    fn distance(&self, _a: &[f64], _b: &[f64]) -> Option<f64> {
+        None
+    }
+
     /// Creates a custom initial chromosome.
     ///
     /// Override this to seed the population with domain-specific
@@ -44,4 +75,11 @@ pub trait BrkgaDecoder: Send + Sync {
     fn seed_chromosome<R: Rng>(&self, _rng: &mut R) -> Option<Vec<f64>> {
         None
     }
+
+    /// Called every [`super::BrkgaConfig::checkpoint_interval`] generations
+    /// with a snapshot of the run.
+    ///
+    /// Pass the snapshot to [`super::BrkgaRunner::resume_from`] to continue
+    /// an interrupted run. The default implementation is a no-op.
+    fn on_checkpoint(&self, _state: &BrkgaState) {}
 }
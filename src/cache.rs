@@ -0,0 +1,179 @@
+//! Bounded, thread-safe LRU cache for memoizing evaluations.
+//!
+//! Shared by the GA (keyed by a user-provided individual hash) and BRKGA
+//! (keyed by quantized random keys). A capacity of zero disables the cache:
+//! lookups always miss without being counted.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Least-recently-used cache with hit/miss counters.
+#[derive(Debug)]
+pub(crate) struct LruCache<K, V> {
+    capacity: usize,
+    inner: Mutex<Inner<K, V>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+#[derive(Debug)]
+struct Inner<K, V> {
+    /// Value and last-use tick per key.
+    entries: HashMap<K, (V, u64)>,
+    /// Keys ordered by last use (oldest first).
+    recency: BTreeMap<u64, K>,
+    tick: u64,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> LruCache<K, V> {
+    /// Creates a cache holding at most `capacity` entries (0 = disabled).
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(Inner {
+                entries: HashMap::new(),
+                recency: BTreeMap::new(),
+                tick: 0,
+            }),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        }
+    }
+
+    /// Whether the cache stores anything.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Looks up `key`, marking it as most recently used on a hit.
+    pub(crate) fn get(&self, key: &K) -> Option<V> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.tick += 1;
+        let tick = inner.tick;
+        let Inner {
+            entries, recency, ..
+        } = &mut *inner;
+        match entries.get_mut(key) {
+            Some((value, last_used)) => {
+                let key = recency
+                    .remove(last_used)
+                    .expect("every entry has a recency slot");
+                recency.insert(tick, key);
+                *last_used = tick;
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(value.clone())
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Stores `value` under `key`, evicting the least recently used entry
+    /// when full.
+    pub(crate) fn insert(&self, key: K, value: V) {
+        if !self.is_enabled() {
+            return;
+        }
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.tick += 1;
+        let tick = inner.tick;
+        let Inner {
+            entries, recency, ..
+        } = &mut *inner;
+        if let Some((_, last_used)) = entries.insert(key.clone(), (value, tick)) {
+            recency.remove(&last_used);
+        } else if entries.len() > self.capacity {
+            if let Some((_, oldest)) = recency.pop_first() {
+                entries.remove(&oldest);
+            }
+        }
+        recency.insert(tick, key);
+    }
+
+    /// Number of successful lookups.
+    pub(crate) fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Number of failed lookups.
+    pub(crate) fn misses(&self) -> usize {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hit_and_miss_counts() {
+        let cache = LruCache::new(4);
+        assert_eq!(cache.get(&1), None);
+        cache.insert(1, "a");
+        assert_eq!(cache.get(&1), Some("a"));
+        assert_eq!(cache.hits(), 1);
+        assert_eq!(cache.misses(), 1);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = LruCache::new(2);
+        cache.insert(1, 10);
+        cache.insert(2, 20);
+        cache.get(&1); // 2 is now the oldest
+        cache.insert(3, 30);
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&1), Some(10));
+        assert_eq!(cache.get(&3), Some(30));
+    }
+
+    #[test]
+    fn test_reinsert_updates_value() {
+        let cache = LruCache::new(2);
+        cache.insert(1, 10);
+        cache.insert(1, 11);
+        cache.insert(2, 20);
+        assert_eq!(cache.get(&1), Some(11));
+        assert_eq!(cache.get(&2), Some(20));
+    }
+
+    #[test]
+    fn test_disabled_cache() {
+        let cache = LruCache::new(0);
+        cache.insert(1, 10);
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.hits() + cache.misses(), 0);
+    }
+
+    #[test]
+    fn test_concurrent_access() {
+        let cache = std::sync::Arc::new(LruCache::new(64));
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let cache = cache.clone();
+                std::thread::spawn(move || {
+                    for i in 0..100u64 {
+                        if cache.get(&(i % 32)).is_none() {
+                            cache.insert(i % 32, i + t);
+                        }
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+        assert_eq!(cache.hits() + cache.misses(), 400);
+    }
+}
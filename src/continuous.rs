@@ -0,0 +1,247 @@
+//! Shared building blocks for continuous optimization over bounded real
+//! vectors.
+//!
+//! Used by the real-coded GA operators in
+//! [`ga::operators::real`](crate::ga::operators::real) and by the
+//! [`de`](crate::de) and [`pso`](crate::pso) runners.
+//!
+//! - [`Bounds`]: Per-variable `[lower, upper]` intervals
+//! - [`BoundHandling`]: Repair of values that leave their interval
+
+use rand::Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Per-variable `[lower, upper]` intervals of a real vector, e.g. the genes
+/// of a real-coded chromosome.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bounds {
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+}
+
+impl Bounds {
+    /// Creates bounds from per-gene lower and upper limits.
+    ///
+    /// # Panics
+    /// Panics if the vectors have different lengths or any `lower[i] > upper[i]`.
+    pub fn new(lower: Vec<f64>, upper: Vec<f64>) -> Self {
+        assert_eq!(
+            lower.len(),
+            upper.len(),
+            "lower and upper bounds must have equal length"
+        );
+        assert!(
+            lower.iter().zip(&upper).all(|(lo, hi)| lo <= hi),
+            "lower bounds must not exceed upper bounds"
+        );
+        Self { lower, upper }
+    }
+
+    /// Creates `dim` genes sharing the interval `[lower, upper]`.
+    ///
+    /// # Panics
+    /// Panics if `lower > upper`.
+    pub fn uniform(dim: usize, lower: f64, upper: f64) -> Self {
+        Self::new(vec![lower; dim], vec![upper; dim])
+    }
+
+    /// Number of genes.
+    pub fn len(&self) -> usize {
+        self.lower.len()
+    }
+
+    /// Returns `true` if there are no genes.
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+
+    /// Per-gene lower limits.
+    pub fn lower(&self) -> &[f64] {
+        &self.lower
+    }
+
+    /// Per-gene upper limits.
+    pub fn upper(&self) -> &[f64] {
+        &self.upper
+    }
+
+    /// Clamps every gene into its interval.
+    pub fn clamp(&self, genes: &mut [f64]) {
+        for ((x, &lo), &hi) in genes.iter_mut().zip(&self.lower).zip(&self.upper) {
+            *x = x.clamp(lo, hi);
+        }
+    }
+
+    /// Samples a chromosome uniformly within the bounds.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> Vec<f64> {
+        self.lower
+            .iter()
+            .zip(&self.upper)
+            .map(|(&lo, &hi)| lo + rng.random::<f64>() * (hi - lo))
+            .collect()
+    }
+
+    /// Checks that there is at least one gene and that every limit is
+    /// finite, as the DE and PSO runners require.
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if self.is_empty() {
+            return Err("bounds must have at least one variable".into());
+        }
+        if self.lower.iter().chain(&self.upper).any(|x| !x.is_finite()) {
+            return Err("bounds must be finite".into());
+        }
+        Ok(())
+    }
+
+    /// Brings every gene outside its interval back with `handling`.
+    ///
+    /// `reference` is a chromosome within the bounds, e.g. the parent of
+    /// `genes`, used by [`BoundHandling::Midpoint`].
+    ///
+    /// # Panics
+    /// Panics if `genes` or `reference` is shorter than the bounds.
+    pub fn repair<R: Rng>(
+        &self,
+        genes: &mut [f64],
+        reference: &[f64],
+        handling: BoundHandling,
+        rng: &mut R,
+    ) {
+        for (j, x) in genes.iter_mut().enumerate() {
+            *x = handling.repair(*x, reference[j], self.lower[j], self.upper[j], rng);
+        }
+    }
+}
+
+/// How a gene outside its `[lower, upper]` interval is brought back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BoundHandling {
+    /// Set the gene to the violated bound.
+    Clamp,
+
+    /// Mirror the gene at the violated bound, then clamp.
+    Reflect,
+
+    /// Draw the gene uniformly within its interval.
+    Reinitialize,
+
+    /// Move halfway between the violated bound and the reference gene, as
+    /// in JADE and SHADE. Keeps the search near the boundary without piling
+    /// genes onto it.
+    Midpoint,
+}
+
+impl BoundHandling {
+    /// Returns `x` if it lies within `[lower, upper]`, and its repaired
+    /// value otherwise.
+    ///
+    /// `reference` must lie within the interval; only
+    /// [`Midpoint`](Self::Midpoint) uses it.
+    pub fn repair<R: Rng>(
+        self,
+        x: f64,
+        reference: f64,
+        lower: f64,
+        upper: f64,
+        rng: &mut R,
+    ) -> f64 {
+        if (lower..=upper).contains(&x) {
+            return x;
+        }
+        let bound = if x < lower { lower } else { upper };
+        match self {
+            Self::Clamp => bound,
+            Self::Reflect => (2.0 * bound - x).clamp(lower, upper),
+            Self::Reinitialize => lower + rng.random::<f64>() * (upper - lower),
+            Self::Midpoint => (bound + reference) / 2.0,
+        }
+    }
+}
+
+/// Computes the cost of every vector, in parallel using rayon when
+/// `parallel` is set. Shared by the DE and PSO runners.
+pub(crate) fn evaluate_vectors(
+    vectors: &[Vec<f64>],
+    parallel: bool,
+    cost: impl Fn(&[f64]) -> f64 + Sync + Send,
+) -> Vec<f64> {
+    #[cfg(feature = "parallel")]
+    if parallel {
+        return vectors.par_iter().map(|x| cost(x)).collect();
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = parallel;
+    vectors.iter().map(|x| cost(x)).collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use u_numflow::random::create_rng;
+
+    fn within(genes: &[f64], bounds: &Bounds) -> bool {
+        genes
+            .iter()
+            .zip(bounds.lower())
+            .zip(bounds.upper())
+            .all(|((x, lo), hi)| lo <= x && x <= hi)
+    }
+
+    fn test_bounds() -> Bounds {
+        Bounds::new(vec![-5.0, 0.0, 10.0, -1.0], vec![5.0, 1.0, 20.0, -1.0])
+    }
+
+    #[test]
+    fn test_bounds_clamp_and_sample() {
+        let mut rng = create_rng(42);
+        let bounds = test_bounds();
+        assert_eq!(bounds.len(), 4);
+
+        let mut genes = vec![-9.0, 0.5, 25.0, 3.0];
+        bounds.clamp(&mut genes);
+        assert_eq!(genes, vec![-5.0, 0.5, 20.0, -1.0]);
+
+        for _ in 0..100 {
+            assert!(within(&bounds.sample(&mut rng), &bounds));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "lower bounds must not exceed upper bounds")]
+    fn test_bounds_reject_inverted_interval() {
+        Bounds::new(vec![1.0], vec![0.0]);
+    }
+
+    #[test]
+    fn test_bounds_validate() {
+        assert!(test_bounds().validate().is_ok());
+        assert!(Bounds::uniform(0, 0.0, 1.0).validate().is_err());
+        assert!(Bounds::uniform(2, 0.0, f64::INFINITY).validate().is_err());
+    }
+
+    #[test]
+    fn test_bounds_repair() {
+        let bounds = Bounds::uniform(3, 0.0, 1.0);
+        let reference = [0.2, 0.5, 0.5];
+        let repaired = |handling| {
+            let mut genes = [-1.0, 0.5, 3.0];
+            bounds.repair(&mut genes, &reference, handling, &mut create_rng(1));
+            genes
+        };
+
+        assert_eq!(repaired(BoundHandling::Clamp), [0.0, 0.5, 1.0]);
+        assert_eq!(repaired(BoundHandling::Reflect), [1.0, 0.5, 0.0]);
+        assert_eq!(repaired(BoundHandling::Midpoint), [0.1, 0.5, 0.75]);
+
+        let genes = repaired(BoundHandling::Reinitialize);
+        assert!(within(&genes, &bounds));
+        assert_eq!(genes[1], 0.5);
+    }
+}
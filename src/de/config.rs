@@ -0,0 +1,289 @@
+//! DE configuration, mutation strategies and parameter adaptation.
+
+use crate::continuous::BoundHandling;
+use crate::direction::Direction;
+
+/// How the mutant vector of each population member is built.
+///
+/// `F` is the scale factor and `r1`, `r2`, `r3` are distinct random
+/// members other than the target `x`. Every strategy is followed by
+/// binomial crossover with the target.
+///
+/// # References
+///
+/// - Rand1, Best1: Storn & Price (1997)
+/// - CurrentToPBest: Zhang & Sanderson (2009)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DeStrategy {
+    /// DE/rand/1/bin: `v = x_r1 + F·(x_r2 − x_r3)`.
+    ///
+    /// The classic strategy. Explores well but converges slowly.
+    #[default]
+    Rand1,
+
+    /// DE/best/1/bin: `v = x_best + F·(x_r1 − x_r2)`.
+    ///
+    /// Converges fast; prone to premature convergence on multimodal
+    /// problems.
+    Best1,
+
+    /// DE/current-to-pbest/1/bin (JADE):
+    /// `v = x + F·(x_pbest − x) + F·(x_r1 − x_r2)`.
+    ///
+    /// `x_pbest` is drawn from the best `p · population_size` members
+    /// (at least one). `x_r2` is drawn from the population together with an
+    /// archive of parents recently replaced by better trial vectors, which
+    /// holds at most `population_size` vectors.
+    CurrentToPBest {
+        /// Fraction of the population eligible as `x_pbest`, in (0, 1].
+        /// Typically 0.05–0.2.
+        p: f64,
+    },
+}
+
+/// How the scale factor `F` and crossover rate `CR` are chosen.
+///
+/// # References
+///
+/// - Shade: Tanabe & Fukunaga (2013)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DeAdaptation {
+    /// Every trial vector uses [`DeConfig::f`] and [`DeConfig::cr`].
+    #[default]
+    Fixed,
+
+    /// Success-history based adaptation (SHADE).
+    ///
+    /// A memory of `memory_size` (F, CR) pairs starts at
+    /// ([`DeConfig::f`], [`DeConfig::cr`]). Each trial vector picks a random
+    /// slot and draws `CR ~ N(M_CR, 0.1)` clipped to [0, 1] and
+    /// `F ~ Cauchy(M_F, 0.1)`, redrawn while non-positive and truncated to 1.
+    /// After each generation, one slot in turn is overwritten with the
+    /// means of the parameters of the trial vectors that beat their
+    /// target, weighted by the cost improvement (Lehmer mean for F,
+    /// arithmetic mean for CR).
+    Shade {
+        /// Number of memory slots (`H`). Typically the problem dimension
+        /// or 5–100.
+        memory_size: usize,
+    },
+}
+
+/// Configuration for Differential Evolution.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::de::{DeAdaptation, DeConfig, DeStrategy};
+///
+/// let config = DeConfig::default()
+///     .with_population_size(60)
+///     .with_max_generations(500)
+///     .with_strategy(DeStrategy::CurrentToPBest { p: 0.1 })
+///     .with_adaptation(DeAdaptation::Shade { memory_size: 10 });
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DeConfig {
+    /// Number of population members (`NP`). Typically 5–10 times the
+    /// number of variables.
+    pub population_size: usize,
+
+    /// Maximum number of generations.
+    pub max_generations: usize,
+
+    /// Mutation strategy.
+    pub strategy: DeStrategy,
+
+    /// Scale factor `F` in (0, 2]; the initial memory value under
+    /// [`DeAdaptation::Shade`]. Typically 0.5.
+    pub f: f64,
+
+    /// Crossover rate `CR` in [0, 1]; the initial memory value under
+    /// [`DeAdaptation::Shade`]. Typically 0.9, or 0.1 for separable
+    /// problems.
+    pub cr: f64,
+
+    /// Control parameter adaptation.
+    pub adaptation: DeAdaptation,
+
+    /// Repair of trial vectors that leave the bounds, with the target
+    /// vector as reference. Defaults to [`BoundHandling::Midpoint`].
+    pub bound_handling: BoundHandling,
+
+    /// Whether to evaluate trial vectors in parallel using rayon.
+    pub parallel: bool,
+
+    /// Whether [`DeProblem::cost`](super::DeProblem::cost) is minimized
+    /// (the default) or maximized.
+    pub direction: Direction,
+
+    /// Random seed for reproducibility.
+    pub seed: Option<u64>,
+
+    /// Wall-clock time limit in milliseconds, checked between generations.
+    ///
+    /// `None` disables time-based termination (the default).
+    pub time_limit_ms: Option<u64>,
+}
+
+impl Default for DeConfig {
+    fn default() -> Self {
+        Self {
+            population_size: 50,
+            max_generations: 1000,
+            strategy: DeStrategy::default(),
+            f: 0.5,
+            cr: 0.9,
+            adaptation: DeAdaptation::default(),
+            bound_handling: BoundHandling::Midpoint,
+            parallel: true,
+            direction: Direction::Minimize,
+            seed: None,
+            time_limit_ms: None,
+        }
+    }
+}
+
+impl DeConfig {
+    /// Sets the population size.
+    pub fn with_population_size(mut self, size: usize) -> Self {
+        self.population_size = size;
+        self
+    }
+
+    /// Sets the maximum number of generations.
+    pub fn with_max_generations(mut self, n: usize) -> Self {
+        self.max_generations = n;
+        self
+    }
+
+    /// Sets the mutation strategy.
+    pub fn with_strategy(mut self, strategy: DeStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets the scale factor `F`.
+    pub fn with_f(mut self, f: f64) -> Self {
+        self.f = f;
+        self
+    }
+
+    /// Sets the crossover rate `CR`.
+    pub fn with_cr(mut self, cr: f64) -> Self {
+        self.cr = cr;
+        self
+    }
+
+    /// Sets the control parameter adaptation.
+    pub fn with_adaptation(mut self, adaptation: DeAdaptation) -> Self {
+        self.adaptation = adaptation;
+        self
+    }
+
+    /// Sets how out-of-bounds components are repaired.
+    pub fn with_bound_handling(mut self, handling: BoundHandling) -> Self {
+        self.bound_handling = handling;
+        self
+    }
+
+    /// Enables or disables parallel evaluation.
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Sets whether costs are minimized or maximized.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the random seed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the wall-clock time limit in milliseconds.
+    pub fn with_time_limit_ms(mut self, ms: u64) -> Self {
+        self.time_limit_ms = Some(ms);
+        self
+    }
+
+    /// Validates the configuration.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.population_size < 4 {
+            return Err("population_size must be at least 4".into());
+        }
+        if self.max_generations == 0 {
+            return Err("max_generations must be at least 1".into());
+        }
+        if self.f.is_nan() || self.f <= 0.0 || self.f > 2.0 {
+            return Err(format!("f must be in (0, 2], got {}", self.f));
+        }
+        if !(0.0..=1.0).contains(&self.cr) {
+            return Err(format!("cr must be in [0, 1], got {}", self.cr));
+        }
+        if let DeStrategy::CurrentToPBest { p } = self.strategy {
+            if p.is_nan() || p <= 0.0 || p > 1.0 {
+                return Err(format!("current-to-pbest p must be in (0, 1], got {p}"));
+            }
+        }
+        if let DeAdaptation::Shade { memory_size: 0 } = self.adaptation {
+            return Err("shade memory_size must be at least 1".into());
+        }
+        if self.time_limit_ms == Some(0) {
+            return Err("time_limit_ms must be positive or None".into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = DeConfig::default();
+        assert_eq!(config.population_size, 50);
+        assert_eq!(config.strategy, DeStrategy::Rand1);
+        assert_eq!(config.adaptation, DeAdaptation::Fixed);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_small_population() {
+        let config = DeConfig::default().with_population_size(3);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_bad_parameters() {
+        assert!(DeConfig::default().with_f(0.0).validate().is_err());
+        assert!(DeConfig::default().with_f(f64::NAN).validate().is_err());
+        assert!(DeConfig::default().with_cr(1.5).validate().is_err());
+        assert!(DeConfig::default()
+            .with_max_generations(0)
+            .validate()
+            .is_err());
+        assert!(DeConfig::default()
+            .with_time_limit_ms(0)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_strategy_and_adaptation() {
+        let config = DeConfig::default().with_strategy(DeStrategy::CurrentToPBest { p: 0.0 });
+        assert!(config.validate().is_err());
+        let config = DeConfig::default().with_adaptation(DeAdaptation::Shade { memory_size: 0 });
+        assert!(config.validate().is_err());
+        let config = DeConfig::default()
+            .with_strategy(DeStrategy::CurrentToPBest { p: 0.1 })
+            .with_adaptation(DeAdaptation::Shade { memory_size: 5 });
+        assert!(config.validate().is_ok());
+    }
+}
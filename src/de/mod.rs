@@ -0,0 +1,29 @@
+//! Differential Evolution (DE).
+//!
+//! A population-based method for continuous optimization over a box of
+//! real-valued variables. Each generation builds one trial vector per
+//! member by adding scaled differences of other members (mutation),
+//! mixing it with the member (binomial crossover) and keeping whichever of
+//! the two costs less.
+//!
+//! The user implements only [`DeProblem`]: the variable bounds and the
+//! cost function. Mutation strategies, control parameter adaptation and
+//! bound repair ([`BoundHandling`](crate::continuous::BoundHandling))
+//! are chosen in [`DeConfig`].
+//!
+//! # References
+//!
+//! - Storn & Price (1997), "Differential Evolution – A Simple and Efficient
+//!   Heuristic for Global Optimization over Continuous Spaces"
+//! - Zhang & Sanderson (2009), "JADE: Adaptive Differential Evolution With
+//!   Optional External Archive"
+//! - Tanabe & Fukunaga (2013), "Success-History Based Parameter Adaptation
+//!   for Differential Evolution"
+
+mod config;
+mod runner;
+mod types;
+
+pub use config::{DeAdaptation, DeConfig, DeStrategy};
+pub use runner::{DeResult, DeRunner};
+pub use types::DeProblem;
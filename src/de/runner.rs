@@ -0,0 +1,669 @@
+//! Differential Evolution loop.
+
+use super::config::{DeAdaptation, DeConfig, DeStrategy};
+use super::types::DeProblem;
+use crate::continuous::evaluate_vectors;
+use crate::ga::operators::real::standard_normal;
+use crate::ga::GenerationStats;
+use crate::observer::{Event, NoopObserver, Observer, TerminationReason};
+use rand::Rng;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+use u_numflow::random::create_rng;
+
+/// Scale of the distributions SHADE samples `F` and `CR` from.
+const SHADE_SCALE: f64 = 0.1;
+
+/// Result of a Differential Evolution run.
+#[derive(Debug, Clone)]
+pub struct DeResult {
+    /// The best vector found.
+    pub best: Vec<f64>,
+
+    /// Cost of the best vector.
+    pub best_cost: f64,
+
+    /// Number of generations executed.
+    pub generations: usize,
+
+    /// Number of cost evaluations, including the initial population.
+    pub evaluations: usize,
+
+    /// Whether cancelled externally.
+    pub cancelled: bool,
+
+    /// Whether the run stopped because `time_limit_ms` elapsed.
+    pub timed_out: bool,
+
+    /// Best cost at the start and at the end of each generation.
+    pub cost_history: Vec<f64>,
+}
+
+/// Executes the Differential Evolution algorithm.
+pub struct DeRunner;
+
+impl DeRunner {
+    /// Runs DE optimization.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration or the problem's bounds are
+    /// invalid.
+    pub fn run<P: DeProblem>(problem: &P, config: &DeConfig) -> Result<DeResult, String> {
+        Self::run_with_cancel(problem, config, None)
+    }
+
+    /// Runs DE with an optional cancellation token.
+    ///
+    /// If `cancel` is `Some` and the flag is set to `true`, the run stops
+    /// at the end of the current generation and returns the best vector
+    /// found so far.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration or the problem's bounds are
+    /// invalid.
+    pub fn run_with_cancel<P: DeProblem>(
+        problem: &P,
+        config: &DeConfig,
+        cancel: Option<Arc<AtomicBool>>,
+    ) -> Result<DeResult, String> {
+        Self::run_observed(problem, config, cancel, &mut NoopObserver)
+    }
+
+    /// Runs DE, reporting progress events to `observer`.
+    ///
+    /// Emits [`Event::NewBest`], [`Event::GenerationFinished`] (statistics
+    /// over member costs) and [`Event::Terminated`].
+    ///
+    /// # Errors
+    /// Returns an error if the configuration or the problem's bounds are
+    /// invalid.
+    pub fn run_observed<P: DeProblem>(
+        problem: &P,
+        config: &DeConfig,
+        cancel: Option<Arc<AtomicBool>>,
+        observer: &mut dyn Observer,
+    ) -> Result<DeResult, String> {
+        config.validate()?;
+        let bounds = problem.bounds();
+        bounds.validate()?;
+
+        let mut rng = match config.seed {
+            Some(seed) => create_rng(seed),
+            None => create_rng(rand::random()),
+        };
+        let direction = config.direction;
+        let cost = |x: &[f64]| direction.cost(problem.cost(x));
+        let np = config.population_size;
+
+        // Initialize and evaluate population
+        let mut population: Vec<Vec<f64>> = (0..np).map(|_| bounds.sample(&mut rng)).collect();
+        let mut costs = evaluate_vectors(&population, config.parallel, cost);
+        let mut evaluations = np;
+        let mut order = rank(&costs);
+
+        let mut memory = Memory::new(config);
+        let mut archive: Vec<Vec<f64>> = Vec::new();
+        let archive_capacity = match config.strategy {
+            DeStrategy::CurrentToPBest { .. } => np,
+            DeStrategy::Rand1 | DeStrategy::Best1 => 0,
+        };
+
+        let mut cost_history = Vec::with_capacity(config.max_generations + 1);
+        cost_history.push(direction.value(costs[order[0]]));
+
+        let mut generation = 0;
+        let mut cancelled = false;
+        #[allow(unused_mut)]
+        let mut timed_out = false;
+        #[cfg(not(target_arch = "wasm32"))]
+        let start_time = Instant::now();
+
+        while generation < config.max_generations {
+            if let Some(ref flag) = cancel {
+                if flag.load(Ordering::Relaxed) {
+                    cancelled = true;
+                    break;
+                }
+            }
+
+            // Time limit (not available on WASM — no std::time::Instant)
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(limit_ms) = config.time_limit_ms {
+                if start_time.elapsed().as_millis() as u64 >= limit_ms {
+                    timed_out = true;
+                    break;
+                }
+            }
+
+            // Mutation, crossover and repair
+            let mut parameters = Vec::with_capacity(np);
+            let mut trials = Vec::with_capacity(np);
+            for (i, target) in population.iter().enumerate() {
+                let (f, cr) = memory.sample(&mut rng);
+                let mutant = mutant(
+                    config.strategy,
+                    &population,
+                    &archive,
+                    &order,
+                    i,
+                    f,
+                    &mut rng,
+                );
+                let mut trial = binomial_crossover(target, &mutant, cr, &mut rng);
+                bounds.repair(&mut trial, target, config.bound_handling, &mut rng);
+                parameters.push((f, cr));
+                trials.push(trial);
+            }
+
+            let trial_costs = evaluate_vectors(&trials, config.parallel, cost);
+            evaluations += np;
+
+            // Selection: a trial replaces its target unless it is worse
+            let previous_best = costs[order[0]];
+            let mut successes = Vec::new();
+            for (i, (trial, cost)) in trials.into_iter().zip(trial_costs).enumerate() {
+                if cost > costs[i] || cost.is_nan() {
+                    continue;
+                }
+                let parent = std::mem::replace(&mut population[i], trial);
+                if cost < costs[i] {
+                    let (f, cr) = parameters[i];
+                    successes.push(Success {
+                        f,
+                        cr,
+                        improvement: costs[i] - cost,
+                    });
+                    if archive_capacity > 0 {
+                        archive.push(parent);
+                    }
+                }
+                costs[i] = cost;
+            }
+            while archive.len() > archive_capacity {
+                let index = rng.random_range(0..archive.len());
+                archive.swap_remove(index);
+            }
+            memory.update(&successes);
+
+            order = rank(&costs);
+            generation += 1;
+
+            let best_cost = costs[order[0]];
+            if best_cost < previous_best {
+                observer.on_event(&Event::NewBest {
+                    iteration: generation,
+                    cost: direction.value(best_cost),
+                });
+            }
+            cost_history.push(direction.value(best_cost));
+            let values: Vec<f64> = costs.iter().map(|&c| direction.value(c)).collect();
+            observer.on_event(&Event::GenerationFinished {
+                stats: &GenerationStats::from_costs(&values, generation, direction),
+            });
+        }
+
+        let best = order[0];
+        observer.on_event(&Event::Terminated {
+            iterations: generation,
+            best_cost: direction.value(costs[best]),
+            reason: if cancelled {
+                TerminationReason::Cancelled
+            } else if timed_out {
+                TerminationReason::TimedOut
+            } else {
+                TerminationReason::MaxIterations
+            },
+        });
+
+        Ok(DeResult {
+            best_cost: direction.value(costs[best]),
+            best: population.swap_remove(best),
+            generations: generation,
+            evaluations,
+            cancelled,
+            timed_out,
+            cost_history,
+        })
+    }
+}
+
+/// A trial vector that beat its target, with the parameters that built it.
+struct Success {
+    f: f64,
+    cr: f64,
+    improvement: f64,
+}
+
+/// Source of the control parameters `F` and `CR`: the configured values,
+/// or the SHADE success-history memory.
+struct Memory {
+    adaptive: bool,
+    f: Vec<f64>,
+    cr: Vec<f64>,
+    /// Slot overwritten by the next update.
+    next: usize,
+}
+
+impl Memory {
+    fn new(config: &DeConfig) -> Self {
+        let (adaptive, size) = match config.adaptation {
+            DeAdaptation::Fixed => (false, 1),
+            DeAdaptation::Shade { memory_size } => (true, memory_size),
+        };
+        Self {
+            adaptive,
+            f: vec![config.f; size],
+            cr: vec![config.cr; size],
+            next: 0,
+        }
+    }
+
+    /// Draws `(F, CR)` for one trial vector.
+    fn sample<R: Rng>(&self, rng: &mut R) -> (f64, f64) {
+        if !self.adaptive {
+            return (self.f[0], self.cr[0]);
+        }
+        let k = rng.random_range(0..self.f.len());
+        let cr = (self.cr[k] + SHADE_SCALE * standard_normal(rng)).clamp(0.0, 1.0);
+        let f = loop {
+            let cauchy = (std::f64::consts::PI * (rng.random::<f64>() - 0.5)).tan();
+            let f = self.f[k] + SHADE_SCALE * cauchy;
+            if f > 0.0 {
+                break f.min(1.0);
+            }
+        };
+        (f, cr)
+    }
+
+    /// Overwrites the next slot with the improvement-weighted means of the
+    /// successful parameters (Tanabe & Fukunaga, 2013).
+    fn update(&mut self, successes: &[Success]) {
+        if !self.adaptive || successes.is_empty() {
+            return;
+        }
+        // Improvements over infinite costs are infinite; weigh equally then
+        let total: f64 = successes.iter().map(|s| s.improvement).sum();
+        let weight = |s: &Success| {
+            if total.is_finite() {
+                s.improvement / total
+            } else {
+                1.0 / successes.len() as f64
+            }
+        };
+
+        let f_squares: f64 = successes.iter().map(|s| weight(s) * s.f * s.f).sum();
+        let f_sum: f64 = successes.iter().map(|s| weight(s) * s.f).sum();
+        self.f[self.next] = f_squares / f_sum;
+        self.cr[self.next] = successes.iter().map(|s| weight(s) * s.cr).sum();
+        self.next = (self.next + 1) % self.f.len();
+    }
+}
+
+/// Builds the mutant vector for target `i`.
+///
+/// `order` lists member indices from best to worst.
+fn mutant<R: Rng>(
+    strategy: DeStrategy,
+    population: &[Vec<f64>],
+    archive: &[Vec<f64>],
+    order: &[usize],
+    i: usize,
+    f: f64,
+    rng: &mut R,
+) -> Vec<f64> {
+    let np = population.len();
+    let difference = |base: &[f64], a: &[f64], b: &[f64]| -> Vec<f64> {
+        base.iter()
+            .zip(a)
+            .zip(b)
+            .map(|((&x, &a), &b)| x + f * (a - b))
+            .collect()
+    };
+    match strategy {
+        DeStrategy::Rand1 => {
+            let [r1, r2, r3] = distinct(np, i, rng);
+            difference(&population[r1], &population[r2], &population[r3])
+        }
+        DeStrategy::Best1 => {
+            let [r1, r2] = distinct(np, i, rng);
+            difference(&population[order[0]], &population[r1], &population[r2])
+        }
+        DeStrategy::CurrentToPBest { p } => {
+            let top = ((p * np as f64).round() as usize).clamp(1, np);
+            let pbest = &population[order[rng.random_range(0..top)]];
+            let [r1] = distinct(np, i, rng);
+            let r2 = loop {
+                let r = rng.random_range(0..np + archive.len());
+                if r != i && r != r1 {
+                    break r;
+                }
+            };
+            let x2 = population.get(r2).unwrap_or_else(|| &archive[r2 - np]);
+            let toward_pbest = difference(&population[i], pbest, &population[i]);
+            difference(&toward_pbest, &population[r1], x2)
+        }
+    }
+}
+
+/// Draws `K` distinct member indices other than `exclude`.
+///
+/// Requires `n > K`.
+fn distinct<const K: usize, R: Rng>(n: usize, exclude: usize, rng: &mut R) -> [usize; K] {
+    let mut picked = [exclude; K];
+    for k in 0..K {
+        picked[k] = loop {
+            let r = rng.random_range(0..n);
+            if r != exclude && !picked[..k].contains(&r) {
+                break r;
+            }
+        };
+    }
+    picked
+}
+
+/// Binomial crossover: each component comes from the mutant with
+/// probability `cr`, and at least one always does.
+fn binomial_crossover<R: Rng>(target: &[f64], mutant: &[f64], cr: f64, rng: &mut R) -> Vec<f64> {
+    let forced = rng.random_range(0..target.len());
+    target
+        .iter()
+        .zip(mutant)
+        .enumerate()
+        .map(|(j, (&x, &v))| {
+            if j == forced || rng.random::<f64>() < cr {
+                v
+            } else {
+                x
+            }
+        })
+        .collect()
+}
+
+/// Member indices from lowest to highest cost.
+fn rank(costs: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..costs.len()).collect();
+    order.sort_by(|&a, &b| {
+        costs[a]
+            .partial_cmp(&costs[b])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::continuous::{BoundHandling, Bounds};
+    use crate::direction::Direction;
+
+    struct Sphere {
+        dim: usize,
+    }
+
+    impl DeProblem for Sphere {
+        fn bounds(&self) -> Bounds {
+            Bounds::uniform(self.dim, -5.0, 5.0)
+        }
+
+        fn cost(&self, x: &[f64]) -> f64 {
+            x.iter().map(|v| v * v).sum()
+        }
+    }
+
+    /// Negated sphere, to be maximized.
+    struct NegatedSphere;
+
+    impl DeProblem for NegatedSphere {
+        fn bounds(&self) -> Bounds {
+            Bounds::uniform(4, -5.0, 5.0)
+        }
+
+        fn cost(&self, x: &[f64]) -> f64 {
+            -Sphere { dim: 4 }.cost(x)
+        }
+    }
+
+    /// Sum of the variables over [1, 2]^3: the optimum lies on the lower
+    /// bound. Panics on out-of-bounds vectors.
+    struct Corner;
+
+    impl DeProblem for Corner {
+        fn bounds(&self) -> Bounds {
+            Bounds::uniform(3, 1.0, 2.0)
+        }
+
+        fn cost(&self, x: &[f64]) -> f64 {
+            assert!(
+                x.iter().all(|v| (1.0..=2.0).contains(v)),
+                "out of bounds: {x:?}"
+            );
+            x.iter().sum()
+        }
+    }
+
+    fn sphere_config() -> DeConfig {
+        DeConfig::default()
+            .with_population_size(30)
+            .with_max_generations(300)
+            .with_seed(42)
+            .with_parallel(false)
+    }
+
+    #[test]
+    fn test_de_sphere_strategies() {
+        let strategies = [
+            DeStrategy::Rand1,
+            DeStrategy::Best1,
+            DeStrategy::CurrentToPBest { p: 0.1 },
+        ];
+        for strategy in strategies {
+            let config = sphere_config().with_strategy(strategy);
+            let result = DeRunner::run(&Sphere { dim: 5 }, &config).unwrap();
+            assert!(
+                result.best_cost < 1e-4,
+                "{strategy:?}: expected cost < 1e-4, got {}",
+                result.best_cost
+            );
+            assert_eq!(result.best.len(), 5);
+            assert_eq!(result.generations, 300);
+            assert_eq!(result.evaluations, 30 * 301);
+        }
+    }
+
+    #[test]
+    fn test_de_shade() {
+        let config = sphere_config()
+            .with_strategy(DeStrategy::CurrentToPBest { p: 0.1 })
+            .with_adaptation(DeAdaptation::Shade { memory_size: 5 });
+        let result = DeRunner::run(&Sphere { dim: 5 }, &config).unwrap();
+        assert!(
+            result.best_cost < 1e-4,
+            "expected cost < 1e-4, got {}",
+            result.best_cost
+        );
+    }
+
+    #[test]
+    fn test_de_bound_handling() {
+        let handlers = [
+            BoundHandling::Clamp,
+            BoundHandling::Reflect,
+            BoundHandling::Reinitialize,
+            BoundHandling::Midpoint,
+        ];
+        for handling in handlers {
+            for seed in 0..5 {
+                let config = DeConfig::default()
+                    .with_population_size(20)
+                    .with_max_generations(200)
+                    .with_bound_handling(handling)
+                    .with_seed(seed)
+                    .with_parallel(false);
+                let result = DeRunner::run(&Corner, &config).unwrap();
+                assert!(
+                    result.best_cost < 3.0 + 1e-2,
+                    "{handling:?}, seed {seed}: expected cost near 3, got {}",
+                    result.best_cost
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_de_shade_memory_update() {
+        let config = DeConfig::default()
+            .with_f(0.5)
+            .with_cr(0.5)
+            .with_adaptation(DeAdaptation::Shade { memory_size: 2 });
+        let mut memory = Memory::new(&config);
+        memory.update(&[
+            Success {
+                f: 0.5,
+                cr: 0.2,
+                improvement: 1.0,
+            },
+            Success {
+                f: 1.0,
+                cr: 0.6,
+                improvement: 1.0,
+            },
+        ]);
+
+        // Lehmer mean (0.25 + 1) / (0.5 + 1) and arithmetic mean of CR
+        assert!((memory.f[0] - 1.25 / 1.5).abs() < 1e-12);
+        assert!((memory.cr[0] - 0.4).abs() < 1e-12);
+        assert_eq!(memory.f[1], 0.5);
+        assert_eq!(memory.next, 1);
+
+        // No successes: the memory is unchanged
+        memory.update(&[]);
+        assert_eq!(memory.next, 1);
+
+        let mut rng = create_rng(3);
+        for _ in 0..1000 {
+            let (f, cr) = memory.sample(&mut rng);
+            assert!(f > 0.0 && f <= 1.0, "f out of range: {f}");
+            assert!((0.0..=1.0).contains(&cr), "cr out of range: {cr}");
+        }
+    }
+
+    #[test]
+    fn test_de_fixed_parameters() {
+        let config = DeConfig::default().with_f(0.7).with_cr(0.3);
+        let memory = Memory::new(&config);
+        assert_eq!(memory.sample(&mut create_rng(0)), (0.7, 0.3));
+    }
+
+    #[test]
+    fn test_de_distinct_indices() {
+        let mut rng = create_rng(5);
+        for _ in 0..100 {
+            let [a, b, c] = distinct(4, 2, &mut rng);
+            assert!(a != 2 && b != 2 && c != 2);
+            assert!(a != b && b != c && a != c);
+        }
+    }
+
+    #[test]
+    fn test_de_maximize_mirrors_minimize() {
+        let config = sphere_config().with_max_generations(50);
+        let min = DeRunner::run(&Sphere { dim: 4 }, &config).unwrap();
+        let max = DeRunner::run(
+            &NegatedSphere,
+            &config.clone().with_direction(Direction::Maximize),
+        )
+        .unwrap();
+        assert_eq!(max.best, min.best);
+        assert_eq!(max.best_cost, -min.best_cost);
+        assert!(max.cost_history.windows(2).all(|w| w[1] >= w[0]));
+    }
+
+    #[test]
+    fn test_de_seeded_runs_repeat() {
+        let config = sphere_config().with_max_generations(20).with_parallel(true);
+        let a = DeRunner::run(&Sphere { dim: 3 }, &config).unwrap();
+        let b = DeRunner::run(&Sphere { dim: 3 }, &config).unwrap();
+        assert_eq!(a.best, b.best);
+        assert_eq!(a.cost_history, b.cost_history);
+    }
+
+    #[test]
+    fn test_de_invalid_bounds() {
+        struct Unbounded;
+
+        impl DeProblem for Unbounded {
+            fn bounds(&self) -> Bounds {
+                Bounds::uniform(2, 0.0, f64::INFINITY)
+            }
+
+            fn cost(&self, x: &[f64]) -> f64 {
+                x.iter().sum()
+            }
+        }
+
+        assert!(DeRunner::run(&Unbounded, &DeConfig::default()).is_err());
+    }
+
+    #[test]
+    fn test_de_cancellation() {
+        let cancel = Arc::new(AtomicBool::new(true));
+        let result =
+            DeRunner::run_with_cancel(&Sphere { dim: 3 }, &sphere_config(), Some(cancel)).unwrap();
+        assert!(result.cancelled);
+        assert_eq!(result.generations, 0);
+        assert_eq!(result.evaluations, 30);
+        assert_eq!(result.cost_history.len(), 1);
+    }
+
+    #[test]
+    fn test_de_time_limit() {
+        struct Slow;
+
+        impl DeProblem for Slow {
+            fn bounds(&self) -> Bounds {
+                Bounds::uniform(2, -1.0, 1.0)
+            }
+
+            fn cost(&self, x: &[f64]) -> f64 {
+                std::thread::sleep(std::time::Duration::from_millis(1));
+                x.iter().map(|v| v * v).sum()
+            }
+        }
+
+        let config = DeConfig::default()
+            .with_population_size(4)
+            .with_max_generations(10_000)
+            .with_time_limit_ms(5)
+            .with_seed(42)
+            .with_parallel(false);
+        let result = DeRunner::run(&Slow, &config).unwrap();
+        assert!(result.timed_out);
+        assert!(!result.cancelled);
+        assert!(result.generations < 10_000);
+    }
+
+    #[test]
+    fn test_de_observer_events() {
+        let config = sphere_config().with_max_generations(40);
+
+        let mut generations = Vec::new();
+        let mut bests = Vec::new();
+        let mut terminated = None;
+        let mut observer = |event: &Event<'_>| match *event {
+            Event::GenerationFinished { stats } => generations.push(stats.generation),
+            Event::NewBest { cost, .. } => bests.push(cost),
+            Event::Terminated { reason, .. } => terminated = Some(reason),
+            _ => {}
+        };
+        let result =
+            DeRunner::run_observed(&Sphere { dim: 3 }, &config, None, &mut observer).unwrap();
+
+        assert_eq!(generations, (1..=40).collect::<Vec<_>>());
+        assert!(bests.windows(2).all(|w| w[1] < w[0]));
+        assert_eq!(bests.last(), Some(&result.best_cost));
+        assert_eq!(terminated, Some(TerminationReason::MaxIterations));
+        assert!(result.cost_history.windows(2).all(|w| w[1] <= w[0]));
+    }
+}
@@ -0,0 +1,51 @@
+//! Core trait for Differential Evolution.
+
+use crate::continuous::Bounds;
+
+/// Defines a continuous optimization problem for Differential Evolution.
+///
+/// Solutions are vectors of `f64`, one value per variable, each within
+/// the interval given by [`bounds`](DeProblem::bounds). The DE framework
+/// handles the population, mutation, crossover and selection.
+///
+/// # Minimization
+///
+/// DE minimizes the cost function. To maximize it instead, set
+/// [`DeConfig::direction`](super::DeConfig::direction) to
+/// [`Direction::Maximize`](crate::direction::Direction::Maximize).
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::de::DeProblem;
+/// use u_metaheur::continuous::Bounds;
+///
+/// struct Sphere { dim: usize }
+///
+/// impl DeProblem for Sphere {
+///     fn bounds(&self) -> Bounds {
+///         Bounds::uniform(self.dim, -5.0, 5.0)
+///     }
+///
+///     fn cost(&self, x: &[f64]) -> f64 {
+///         x.iter().map(|v| v * v).sum()
+///     }
+/// }
+/// ```
+///
+/// # References
+///
+/// Storn & Price (1997)
+pub trait DeProblem: Send + Sync {
+    /// Lower and upper limit of each variable. The number of variables is
+    /// `bounds().len()`.
+    ///
+    /// Called once per run. The limits must be finite.
+    fn bounds(&self) -> Bounds;
+
+    /// Computes the cost of a vector. Lower is better unless the run
+    /// maximizes.
+    ///
+    /// `x` has one value per variable, each within its bounds.
+    fn cost(&self, x: &[f64]) -> f64;
+}
@@ -0,0 +1,79 @@
+//! Optimization direction shared by all runners.
+//!
+//! Every algorithm minimizes internally. With [`Direction::Maximize`] the
+//! runners flip their comparisons, so problems can return profits or
+//! scores as they are, and results, histories, statistics and observer
+//! events report values in the same sense.
+
+use std::cmp::Ordering;
+
+/// Whether lower or higher objective values are better.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::direction::Direction;
+/// use u_metaheur::sa::SaConfig;
+///
+/// let config = SaConfig::default().with_direction(Direction::Maximize);
+/// assert!(config.direction.is_maximize());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Lower values are better.
+    #[default]
+    Minimize,
+
+    /// Higher values are better.
+    Maximize,
+}
+
+impl Direction {
+    /// Whether higher values are better.
+    pub fn is_maximize(self) -> bool {
+        self == Direction::Maximize
+    }
+
+    /// Converts an objective value to the internal lower-is-better cost.
+    pub(crate) fn cost(self, value: f64) -> f64 {
+        match self {
+            Direction::Minimize => value,
+            Direction::Maximize => -value,
+        }
+    }
+
+    /// Converts an internal cost back to an objective value.
+    pub(crate) fn value(self, cost: f64) -> f64 {
+        self.cost(cost)
+    }
+
+    /// Orders `a` before `b` if it is better.
+    pub(crate) fn compare<T: PartialOrd>(self, a: &T, b: &T) -> Option<Ordering> {
+        match self {
+            Direction::Minimize => a.partial_cmp(b),
+            Direction::Maximize => b.partial_cmp(a),
+        }
+    }
+
+    /// Whether `a` is strictly better than `b`.
+    pub(crate) fn is_better<T: PartialOrd>(self, a: &T, b: &T) -> bool {
+        self.compare(a, b) == Some(Ordering::Less)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::default(), Direction::Minimize);
+        assert!(Direction::Minimize.is_better(&1.0, &2.0));
+        assert!(Direction::Maximize.is_better(&2.0, &1.0));
+        assert!(!Direction::Maximize.is_better(&1.0, &1.0));
+        assert_eq!(Direction::Maximize.cost(5.0), -5.0);
+        assert_eq!(Direction::Maximize.value(-5.0), 5.0);
+        assert_eq!(Direction::Minimize.cost(5.0), 5.0);
+    }
+}
@@ -0,0 +1,597 @@
+//! Adaptive control of GA operator rates and operator choice.
+//!
+//! By default the GA applies crossover and mutation with the fixed
+//! probabilities of [`GaConfig`]. Three independent mechanisms adapt them
+//! during the run:
+//!
+//! - [`RateControl::SrinivasPatnaik`]: per-pair crossover and mutation
+//!   probabilities from the parents' fitness relative to the population
+//! - [`OneFifthRule`]: Rechenberg's 1/5th success rule for the mutation
+//!   strength passed to [`GaProblem::mutate_with`]
+//! - [`OperatorSelection`]: probability matching or adaptive pursuit over
+//!   several crossover and mutation operators registered by the problem
+//!
+//! An offspring counts as a **success** when it is strictly better than
+//! the better of its parents (feasibility first, then fitness). Usage
+//! statistics are reported in [`GaResult`](super::GaResult).
+//!
+//! # References
+//!
+//! - Srinivas & Patnaik (1994), "Adaptive Probabilities of Crossover and
+//!   Mutation in Genetic Algorithms"
+//! - Rechenberg (1973), *Evolutionsstrategie*
+//! - Thierens (2005), "An Adaptive Pursuit Strategy for Allocating Operator
+//!   Probabilities"
+
+use super::config::GaConfig;
+use super::types::{Fitness, GaProblem, Individual};
+use crate::direction::Direction;
+use rand::Rng;
+
+/// How crossover and mutation probabilities are chosen for each pair of
+/// parents.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RateControl {
+    /// Use `crossover_rate` and `mutation_rate` for the whole run.
+    #[default]
+    Fixed,
+
+    /// Srinivas–Patnaik adaptive probabilities.
+    ///
+    /// With `f'` the fitness of the better parent and `f_best`, `f_mean`
+    /// the population's best and mean fitness (lower is better):
+    ///
+    /// - `f' <= f_mean`: `pc = k1 · (f' − f_best) / (f_mean − f_best)` and
+    ///   `pm = k2 · (f' − f_best) / (f_mean − f_best)`
+    /// - otherwise: `pc = k3` and `pm = k4`
+    ///
+    /// Good parents are disrupted less, and the best ones are kept intact.
+    /// A converged population (`f_mean = f_best`) uses `k3` and `k4`. The
+    /// paper recommends `k1 = k3 = 1.0` and `k2 = k4 = 0.5`.
+    SrinivasPatnaik {
+        /// Crossover scale for above-average parents.
+        k1: f64,
+        /// Mutation scale for above-average parents.
+        k2: f64,
+        /// Crossover probability for below-average parents.
+        k3: f64,
+        /// Mutation probability for below-average parents.
+        k4: f64,
+    },
+}
+
+impl RateControl {
+    /// Srinivas–Patnaik control with the recommended constants.
+    pub fn srinivas_patnaik() -> Self {
+        RateControl::SrinivasPatnaik {
+            k1: 1.0,
+            k2: 0.5,
+            k3: 1.0,
+            k4: 0.5,
+        }
+    }
+}
+
+/// Rechenberg's 1/5th success rule for the mutation strength.
+///
+/// The strength starts at `initial` and is passed to
+/// [`GaProblem::mutate_with`]. After each generation, if more than 1/5 of
+/// the mutated offspring were successes the strength is divided by
+/// `factor` (larger steps), if fewer it is multiplied by `factor`
+/// (smaller steps).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OneFifthRule {
+    /// Initial mutation strength, must be positive.
+    pub initial: f64,
+    /// Adjustment factor in `(0, 1)`; 0.82 is the classic choice.
+    pub factor: f64,
+}
+
+impl Default for OneFifthRule {
+    fn default() -> Self {
+        Self {
+            initial: 1.0,
+            factor: 0.82,
+        }
+    }
+}
+
+/// How one of several registered crossover or mutation operators is picked.
+///
+/// Only matters when [`GaProblem::crossover_operators`] or
+/// [`GaProblem::mutation_operators`] is greater than 1. Each operator `i`
+/// keeps a quality estimate `Qᵢ ← Qᵢ + α·(r − Qᵢ)` of its success rate,
+/// where the reward `r` is 1 for a success and 0 otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OperatorSelection {
+    /// Every operator is equally likely.
+    #[default]
+    Uniform,
+
+    /// Probability matching: `pᵢ = p_min + (1 − K·p_min) · Qᵢ / ΣQ` for
+    /// `K` operators.
+    ProbabilityMatching {
+        /// Minimum probability of every operator.
+        p_min: f64,
+        /// Learning rate of the quality estimates, in `(0, 1]`.
+        alpha: f64,
+    },
+
+    /// Adaptive pursuit (Thierens, 2005): the probability of the operator
+    /// with the highest quality moves toward `p_max = 1 − (K − 1)·p_min`
+    /// and the others toward `p_min`, at rate `beta`.
+    AdaptivePursuit {
+        /// Minimum probability of every operator.
+        p_min: f64,
+        /// Learning rate of the quality estimates, in `(0, 1]`.
+        alpha: f64,
+        /// Learning rate of the probabilities, in `(0, 1]`.
+        beta: f64,
+    },
+}
+
+/// Usage of one crossover or mutation operator over a run.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperatorUsage {
+    /// Offspring produced with the operator.
+    pub applications: usize,
+    /// Those offspring that were better than their better parent.
+    pub successes: usize,
+    /// Selection probability of the operator at the end of the run.
+    pub probability: f64,
+}
+
+impl RateControl {
+    pub(super) fn validate(&self) -> Result<(), String> {
+        match *self {
+            RateControl::SrinivasPatnaik { k1, k2, k3, k4 }
+                if [k1, k2, k3, k4].iter().any(|k| !(0.0..=1.0).contains(k)) =>
+            {
+                Err("Srinivas-Patnaik constants k1..k4 must be in [0, 1]".into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl OneFifthRule {
+    pub(super) fn validate(&self) -> Result<(), String> {
+        if self.initial <= 0.0 || self.factor <= 0.0 || self.factor >= 1.0 {
+            return Err("1/5th rule needs initial > 0 and factor in (0, 1)".into());
+        }
+        Ok(())
+    }
+}
+
+impl OperatorSelection {
+    pub(super) fn validate(&self) -> Result<(), String> {
+        let (p_min, rates) = match *self {
+            OperatorSelection::Uniform => return Ok(()),
+            OperatorSelection::ProbabilityMatching { p_min, alpha } => (p_min, [alpha, 1.0]),
+            OperatorSelection::AdaptivePursuit { p_min, alpha, beta } => (p_min, [alpha, beta]),
+        };
+        if !(0.0..1.0).contains(&p_min) {
+            return Err(format!("operator p_min must be in [0, 1), got {p_min}"));
+        }
+        if rates.iter().any(|r| *r <= 0.0 || *r > 1.0) {
+            return Err("operator learning rates must be in (0, 1]".into());
+        }
+        Ok(())
+    }
+}
+
+/// Where an offspring came from, for crediting its operators.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Provenance<F> {
+    /// Crossover operator applied, if any.
+    pub(super) crossover: Option<usize>,
+    /// Mutation operator applied, if any.
+    pub(super) mutation: Option<usize>,
+    /// `(violation, fitness)` of the better parent.
+    pub(super) parent: (f64, F),
+}
+
+/// Per-run state of the adaptive mechanisms.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(super) struct OperatorControl {
+    crossover: OperatorPool,
+    mutation: OperatorPool,
+    strength: f64,
+    mutation_trials: usize,
+    mutation_successes: usize,
+    /// Best and mean fitness of the population, for Srinivas–Patnaik.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    summary: (f64, f64),
+}
+
+/// Statistics and selection probabilities of one kind of operator.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct OperatorPool {
+    quality: Vec<f64>,
+    probability: Vec<f64>,
+    applications: Vec<usize>,
+    successes: Vec<usize>,
+}
+
+impl OperatorPool {
+    fn new(count: usize) -> Self {
+        Self {
+            quality: vec![1.0; count],
+            probability: vec![1.0 / count as f64; count],
+            applications: vec![0; count],
+            successes: vec![0; count],
+        }
+    }
+
+    fn choose<R: Rng>(&self, rng: &mut R) -> usize {
+        let k = self.probability.len();
+        if k == 1 {
+            return 0;
+        }
+
+        let threshold = rng.random_range(0.0..1.0);
+        let mut cumulative = 0.0;
+        for (i, &p) in self.probability.iter().enumerate() {
+            cumulative += p;
+            if cumulative > threshold {
+                return i;
+            }
+        }
+        k - 1 // floating-point fallback
+    }
+
+    fn record(&mut self, operator: usize, success: bool, selection: OperatorSelection) {
+        self.applications[operator] += 1;
+        if success {
+            self.successes[operator] += 1;
+        }
+
+        let k = self.probability.len() as f64;
+        let reward = if success { 1.0 } else { 0.0 };
+        match selection {
+            OperatorSelection::Uniform => {}
+            OperatorSelection::ProbabilityMatching { p_min, alpha } => {
+                self.quality[operator] += alpha * (reward - self.quality[operator]);
+                let total: f64 = self.quality.iter().sum();
+                for (p, q) in self.probability.iter_mut().zip(&self.quality) {
+                    *p = if total > 0.0 {
+                        p_min + (1.0 - k * p_min) * q / total
+                    } else {
+                        1.0 / k
+                    };
+                }
+            }
+            OperatorSelection::AdaptivePursuit { p_min, alpha, beta } => {
+                self.quality[operator] += alpha * (reward - self.quality[operator]);
+                let p_max = 1.0 - (k - 1.0) * p_min;
+                let mut best = 0;
+                for (i, &q) in self.quality.iter().enumerate() {
+                    if q > self.quality[best] {
+                        best = i;
+                    }
+                }
+                for (i, p) in self.probability.iter_mut().enumerate() {
+                    let target = if i == best { p_max } else { p_min };
+                    *p += beta * (target - *p);
+                }
+            }
+        }
+    }
+
+    fn usage(&self) -> Vec<OperatorUsage> {
+        (0..self.probability.len())
+            .map(|i| OperatorUsage {
+                applications: self.applications[i],
+                successes: self.successes[i],
+                probability: self.probability[i],
+            })
+            .collect()
+    }
+}
+
+impl OperatorControl {
+    /// Creates the state for `problem`'s registered operators.
+    ///
+    /// # Errors
+    /// Returns an error if the problem registers no crossover or mutation
+    /// operator, or `p_min` leaves no room for adaptation.
+    pub(super) fn new<P: GaProblem>(problem: &P, config: &GaConfig) -> Result<Self, String> {
+        let crossovers = problem.crossover_operators();
+        let mutations = problem.mutation_operators();
+        if crossovers == 0 || mutations == 0 {
+            return Err("the problem must register crossover and mutation operators".into());
+        }
+        let p_min = match config.operator_selection {
+            OperatorSelection::Uniform => 0.0,
+            OperatorSelection::ProbabilityMatching { p_min, .. }
+            | OperatorSelection::AdaptivePursuit { p_min, .. } => p_min,
+        };
+        if p_min * crossovers.max(mutations) as f64 > 1.0 {
+            return Err(format!(
+                "operator p_min {p_min} is too large for {} operators",
+                crossovers.max(mutations)
+            ));
+        }
+
+        Ok(Self {
+            crossover: OperatorPool::new(crossovers),
+            mutation: OperatorPool::new(mutations),
+            strength: config.one_fifth_rule.map_or(1.0, |rule| rule.initial),
+            mutation_trials: 0,
+            mutation_successes: 0,
+            summary: (0.0, 0.0),
+        })
+    }
+
+    /// Whether this state was created for `problem`'s operators.
+    pub(super) fn matches<P: GaProblem>(&self, problem: &P) -> bool {
+        self.crossover.probability.len() == problem.crossover_operators()
+            && self.mutation.probability.len() == problem.mutation_operators()
+    }
+
+    /// Records the population's best and mean fitness for the rates of
+    /// the coming offspring. Only needed for Srinivas–Patnaik control.
+    pub(super) fn observe<I: Individual>(&mut self, population: &[I], config: &GaConfig) {
+        if config.rate_control == RateControl::Fixed || population.is_empty() {
+            return;
+        }
+        let fitnesses = population
+            .iter()
+            .map(|ind| config.direction.cost(ind.fitness().to_f64()));
+        let best = fitnesses.clone().fold(f64::INFINITY, f64::min);
+        let mean = fitnesses.sum::<f64>() / population.len() as f64;
+        self.summary = (best, mean);
+    }
+
+    /// Crossover and mutation probabilities for offspring of parents whose
+    /// better fitness is `parent_fitness`.
+    pub(super) fn rates(&self, config: &GaConfig, parent_fitness: f64) -> (f64, f64) {
+        match config.rate_control {
+            RateControl::Fixed => (config.crossover_rate, config.mutation_rate),
+            RateControl::SrinivasPatnaik { k1, k2, k3, k4 } => {
+                let (best, mean) = self.summary;
+                let spread = mean - best;
+                if spread > f64::EPSILON * mean.abs().max(1.0) && parent_fitness <= mean {
+                    let scale = ((parent_fitness - best) / spread).clamp(0.0, 1.0);
+                    (k1 * scale, k2 * scale)
+                } else {
+                    (k3, k4)
+                }
+            }
+        }
+    }
+
+    /// Picks a crossover operator; draws no random number if there is only one.
+    pub(super) fn choose_crossover<R: Rng>(&self, rng: &mut R) -> usize {
+        self.crossover.choose(rng)
+    }
+
+    /// Picks a mutation operator; draws no random number if there is only one.
+    pub(super) fn choose_mutation<R: Rng>(&self, rng: &mut R) -> usize {
+        self.mutation.choose(rng)
+    }
+
+    /// Current mutation strength (1.0 without the 1/5th rule).
+    pub(super) fn strength(&self) -> f64 {
+        self.strength
+    }
+
+    /// Credits the operators that produced the evaluated `child`.
+    pub(super) fn credit<I: Individual>(
+        &mut self,
+        child: &I,
+        provenance: &Provenance<I::Fitness>,
+        config: &GaConfig,
+    ) {
+        let (violation, fitness) = provenance.parent;
+        let success = child.violation() < violation
+            || (child.violation() == violation
+                && config.direction.is_better(&child.fitness(), &fitness));
+        if let Some(op) = provenance.crossover {
+            self.crossover
+                .record(op, success, config.operator_selection);
+        }
+        if let Some(op) = provenance.mutation {
+            self.mutation.record(op, success, config.operator_selection);
+            self.mutation_trials += 1;
+            if success {
+                self.mutation_successes += 1;
+            }
+        }
+    }
+
+    /// Applies the 1/5th success rule to the generation that just ended.
+    pub(super) fn end_generation(&mut self, config: &GaConfig) {
+        if let Some(rule) = config.one_fifth_rule {
+            if self.mutation_trials > 0 {
+                let ratio = self.mutation_successes as f64 / self.mutation_trials as f64;
+                if ratio > 0.2 {
+                    self.strength /= rule.factor;
+                } else if ratio < 0.2 {
+                    self.strength *= rule.factor;
+                }
+            }
+        }
+        self.mutation_trials = 0;
+        self.mutation_successes = 0;
+    }
+
+    /// Usage of the crossover operators.
+    pub(super) fn crossover_usage(&self) -> Vec<OperatorUsage> {
+        self.crossover.usage()
+    }
+
+    /// Usage of the mutation operators.
+    pub(super) fn mutation_usage(&self) -> Vec<OperatorUsage> {
+        self.mutation.usage()
+    }
+}
+
+/// `(violation, fitness)` of the better of two parents.
+pub(super) fn better_parent<I: Individual>(
+    p1: &I,
+    p2: &I,
+    direction: Direction,
+) -> (f64, I::Fitness) {
+    let a = (p1.violation(), p1.fitness());
+    let b = (p2.violation(), p2.fitness());
+    if b.0 < a.0 || (b.0 == a.0 && direction.is_better(&b.1, &a.1)) {
+        b
+    } else {
+        a
+    }
+}
+
+/// Sums the usage of several runs (e.g. islands); probabilities are
+/// averaged.
+pub(super) fn merge_usage(runs: &[Vec<OperatorUsage>]) -> Vec<OperatorUsage> {
+    let Some(first) = runs.first() else {
+        return Vec::new();
+    };
+    let mut merged = vec![OperatorUsage::default(); first.len()];
+    for usage in runs {
+        for (total, u) in merged.iter_mut().zip(usage) {
+            total.applications += u.applications;
+            total.successes += u.successes;
+            total.probability += u.probability / runs.len() as f64;
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use u_numflow::random::create_rng;
+
+    #[test]
+    fn test_srinivas_patnaik_rates() {
+        let config = GaConfig::default().with_rate_control(RateControl::srinivas_patnaik());
+        let mut control = OperatorControl {
+            crossover: OperatorPool::new(1),
+            mutation: OperatorPool::new(1),
+            strength: 1.0,
+            mutation_trials: 0,
+            mutation_successes: 0,
+            summary: (0.0, 10.0),
+        };
+
+        // Best parent is preserved, average parent gets k1/k2
+        assert_eq!(control.rates(&config, 0.0), (0.0, 0.0));
+        assert_eq!(control.rates(&config, 5.0), (0.5, 0.25));
+        assert_eq!(control.rates(&config, 10.0), (1.0, 0.5));
+        // Worse than average: k3/k4
+        assert_eq!(control.rates(&config, 20.0), (1.0, 0.5));
+
+        // Converged population falls back to k3/k4
+        control.summary = (3.0, 3.0);
+        assert_eq!(control.rates(&config, 3.0), (1.0, 0.5));
+    }
+
+    #[test]
+    fn test_one_fifth_rule_adjusts_strength() {
+        let config = GaConfig::default().with_one_fifth_rule(OneFifthRule {
+            initial: 1.0,
+            factor: 0.5,
+        });
+        let mut control = OperatorControl {
+            crossover: OperatorPool::new(1),
+            mutation: OperatorPool::new(1),
+            strength: 1.0,
+            mutation_trials: 10,
+            mutation_successes: 5,
+            summary: (0.0, 0.0),
+        };
+
+        control.end_generation(&config);
+        assert_eq!(control.strength(), 2.0, "many successes widen the steps");
+
+        control.mutation_trials = 10;
+        control.mutation_successes = 1;
+        control.end_generation(&config);
+        assert_eq!(control.strength(), 1.0, "few successes narrow the steps");
+    }
+
+    #[test]
+    fn test_probability_matching_favors_successful_operator() {
+        let selection = OperatorSelection::ProbabilityMatching {
+            p_min: 0.1,
+            alpha: 0.3,
+        };
+        let mut pool = OperatorPool::new(3);
+        for _ in 0..50 {
+            pool.record(0, true, selection);
+            pool.record(1, false, selection);
+            pool.record(2, false, selection);
+        }
+        assert!(pool.probability[0] > 0.75, "{:?}", pool.probability);
+        assert!(pool.probability[1] >= 0.1 && pool.probability[2] >= 0.1);
+        let total: f64 = pool.probability.iter().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_adaptive_pursuit_converges_to_p_max() {
+        let selection = OperatorSelection::AdaptivePursuit {
+            p_min: 0.05,
+            alpha: 0.5,
+            beta: 0.5,
+        };
+        let mut pool = OperatorPool::new(4);
+        for _ in 0..100 {
+            for op in 0..4 {
+                pool.record(op, op == 2, selection);
+            }
+        }
+        // p_max = 1 - 3 · 0.05 = 0.85
+        assert!((pool.probability[2] - 0.85).abs() < 1e-6);
+        assert!((pool.probability[0] - 0.05).abs() < 1e-6);
+        assert_eq!(pool.usage()[2].successes, 100);
+        assert_eq!(pool.usage()[0].applications, 100);
+    }
+
+    #[test]
+    fn test_choose_follows_probabilities() {
+        let mut rng = create_rng(42);
+        let mut pool = OperatorPool::new(2);
+        pool.probability = vec![0.9, 0.1];
+        let picks = (0..10_000).filter(|_| pool.choose(&mut rng) == 0).count();
+        assert!(
+            (8_700..=9_300).contains(&picks),
+            "picked op 0 {picks} times"
+        );
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(RateControl::srinivas_patnaik().validate().is_ok());
+        assert!(RateControl::SrinivasPatnaik {
+            k1: 1.5,
+            k2: 0.5,
+            k3: 1.0,
+            k4: 0.5
+        }
+        .validate()
+        .is_err());
+        assert!(OneFifthRule::default().validate().is_ok());
+        assert!(OneFifthRule {
+            initial: 1.0,
+            factor: 1.0
+        }
+        .validate()
+        .is_err());
+        assert!(OperatorSelection::AdaptivePursuit {
+            p_min: 1.0,
+            alpha: 0.5,
+            beta: 0.5
+        }
+        .validate()
+        .is_err());
+    }
+}
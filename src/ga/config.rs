@@ -2,7 +2,14 @@
 //!
 //! [`GaConfig`] holds all parameters that control the evolutionary loop.
 
+use super::adaptive::{OneFifthRule, OperatorSelection, RateControl};
+use super::constraint::ConstraintHandling;
+use super::memetic::LocalSearch;
+use super::niching::Niching;
+use super::replacement::Replacement;
+use super::restart::Restart;
 use super::selection::Selection;
+use crate::direction::Direction;
 
 /// Configuration for the Genetic Algorithm.
 ///
@@ -44,6 +51,40 @@ pub struct GaConfig {
     /// Selection strategy for choosing parents.
     pub selection: Selection,
 
+    /// How offspring replace the current population.
+    ///
+    /// `elite_ratio` only applies to [`Replacement::Generational`].
+    pub replacement: Replacement,
+
+    /// How constraint violations reported by
+    /// [`GaProblem::constraint_violation`](super::GaProblem::constraint_violation)
+    /// affect selection and survival.
+    pub constraint_handling: ConstraintHandling,
+
+    /// Niching method for keeping the population diverse.
+    ///
+    /// [`Niching::DeterministicCrowding`] and
+    /// [`Niching::RestrictedTournament`] take over the population update and
+    /// require [`Replacement::Generational`].
+    pub niching: Niching,
+
+    /// Whether to replace duplicate individuals with random ones after each
+    /// generation.
+    ///
+    /// Duplicates are detected with
+    /// [`GaProblem::individual_hash`](super::GaProblem::individual_hash)
+    /// when available, otherwise with
+    /// [`GaProblem::distance`](super::GaProblem::distance).
+    pub eliminate_duplicates: bool,
+
+    /// Whether to compute
+    /// [`GenerationStats::diversity`](super::GenerationStats::diversity)
+    /// without a niching method.
+    ///
+    /// Diversity takes `O(n²)` distance computations per generation, so by
+    /// default it is only computed when [`niching`](Self::niching) is set.
+    pub diversity_stats: bool,
+
     /// Fraction of the population preserved as elites (0.0–1.0).
     ///
     /// Elite individuals are copied unchanged to the next generation.
@@ -58,16 +99,48 @@ pub struct GaConfig {
     /// Probability of applying mutation to an offspring (0.0–1.0).
     pub mutation_rate: f64,
 
-    /// Number of generations with no significant improvement before stopping.
+    /// How crossover and mutation probabilities are chosen.
+    ///
+    /// [`RateControl::Fixed`] (the default) uses `crossover_rate` and
+    /// `mutation_rate` throughout; other variants ignore them.
+    pub rate_control: RateControl,
+
+    /// Local search applied to a fraction of the offspring, turning the GA
+    /// into a memetic algorithm.
+    ///
+    /// `None` (the default) disables it. See
+    /// [`GaProblem::improve`](super::GaProblem::improve).
+    pub local_search: Option<LocalSearch>,
+
+    /// 1/5th success rule for the mutation strength passed to
+    /// [`GaProblem::mutate_with`](super::GaProblem::mutate_with).
+    ///
+    /// `None` (the default) keeps the strength at 1.0.
+    pub one_fifth_rule: Option<OneFifthRule>,
+
+    /// How one of several registered crossover and mutation operators is
+    /// picked for each offspring.
+    pub operator_selection: OperatorSelection,
+
+    /// Number of generations with no significant improvement before stopping,
+    /// or restarting if [`restart`](Self::restart) is set.
     ///
     /// Set to 0 to disable stagnation-based termination.
     pub stagnation_limit: usize,
 
+    /// Restart instead of stopping when the stagnation limit is reached.
+    ///
+    /// `None` (the default) ends the run at the first stagnation.
+    pub restart: Option<Restart>,
+
     /// Minimum relative improvement to reset the stagnation counter.
     ///
     /// When a new best fitness is found, the improvement ratio is computed as
     /// `|old - new| / |old|`. If this ratio is below `convergence_threshold`,
-    /// the generation is still counted as stagnating.
+    /// the generation is still counted as stagnating. Fitness types can
+    /// refine this through [`Fitness::improves_on`](super::Fitness::improves_on);
+    /// [`Lexicographic`](super::Lexicographic) fitness uses the first
+    /// component that differs.
     ///
     /// Set to 0.0 to count any improvement (the default).
     /// A typical value for production scheduling is 0.001 (0.1%).
@@ -76,6 +149,44 @@ pub struct GaConfig {
     /// Whether to evaluate individuals in parallel using rayon.
     pub parallel: bool,
 
+    /// Number of individuals passed to each call of
+    /// [`GaProblem::evaluate_batch`](super::GaProblem::evaluate_batch)
+    /// (0, the default, evaluates them one at a time with
+    /// [`GaProblem::evaluate`](super::GaProblem::evaluate)).
+    ///
+    /// With `parallel`, several batches are evaluated concurrently. Cached
+    /// individuals are left out of the batches.
+    pub batch_size: usize,
+
+    /// Maximum number of memoized evaluations (0 disables the cache).
+    ///
+    /// Only used when the problem implements
+    /// [`GaProblem::individual_hash`](super::GaProblem::individual_hash).
+    /// Least recently used entries are evicted first.
+    pub cache_capacity: usize,
+
+    /// Generations between checkpoints passed to
+    /// [`GaProblem::on_checkpoint`](super::GaProblem::on_checkpoint)
+    /// (0 disables checkpointing).
+    pub checkpoint_interval: usize,
+
+    /// Number of best distinct individuals kept in
+    /// [`GaResult::hall_of_fame`](super::GaResult::hall_of_fame)
+    /// (0, the default, disables it).
+    pub hall_of_fame: usize,
+
+    /// Minimum [`GaProblem::distance`](super::GaProblem::distance) between
+    /// hall of fame entries: individuals within this distance of a better
+    /// entry are left out. 0 only rejects duplicates.
+    pub hall_of_fame_distance: f64,
+
+    /// Whether fitness is minimized (the default) or maximized.
+    ///
+    /// Individuals, histories and statistics keep the fitness returned by
+    /// [`GaProblem::evaluate`](super::GaProblem::evaluate); only the
+    /// comparisons are reversed.
+    pub direction: Direction,
+
     /// Random seed for reproducibility.
     ///
     /// `None` uses a random seed.
@@ -98,12 +209,28 @@ impl Default for GaConfig {
             population_size: 100,
             max_generations: 500,
             selection: Selection::default(),
+            replacement: Replacement::default(),
+            constraint_handling: ConstraintHandling::default(),
+            niching: Niching::default(),
+            eliminate_duplicates: false,
+            diversity_stats: false,
             elite_ratio: 0.1,
             crossover_rate: 0.9,
             mutation_rate: 0.1,
+            rate_control: RateControl::default(),
+            local_search: None,
+            one_fifth_rule: None,
+            operator_selection: OperatorSelection::default(),
             stagnation_limit: 50,
+            restart: None,
             convergence_threshold: 0.0,
             parallel: true,
+            batch_size: 0,
+            cache_capacity: 0,
+            checkpoint_interval: 0,
+            hall_of_fame: 0,
+            hall_of_fame_distance: 0.0,
+            direction: Direction::Minimize,
             seed: None,
             time_limit_ms: None,
         }
@@ -129,6 +256,36 @@ impl GaConfig {
         self
     }
 
+    /// Sets the replacement strategy.
+    pub fn with_replacement(mut self, replacement: Replacement) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    /// Sets the constraint handling method.
+    pub fn with_constraint_handling(mut self, handling: ConstraintHandling) -> Self {
+        self.constraint_handling = handling;
+        self
+    }
+
+    /// Sets the niching method.
+    pub fn with_niching(mut self, niching: Niching) -> Self {
+        self.niching = niching;
+        self
+    }
+
+    /// Enables or disables duplicate elimination.
+    pub fn with_duplicate_elimination(mut self, enabled: bool) -> Self {
+        self.eliminate_duplicates = enabled;
+        self
+    }
+
+    /// Enables or disables diversity statistics without niching.
+    pub fn with_diversity_stats(mut self, enabled: bool) -> Self {
+        self.diversity_stats = enabled;
+        self
+    }
+
     /// Sets the elite ratio.
     pub fn with_elite_ratio(mut self, ratio: f64) -> Self {
         self.elite_ratio = ratio.clamp(0.0, 1.0);
@@ -147,12 +304,42 @@ impl GaConfig {
         self
     }
 
+    /// Sets the crossover and mutation rate control.
+    pub fn with_rate_control(mut self, control: RateControl) -> Self {
+        self.rate_control = control;
+        self
+    }
+
+    /// Applies local search to offspring.
+    pub fn with_local_search(mut self, local_search: LocalSearch) -> Self {
+        self.local_search = Some(local_search);
+        self
+    }
+
+    /// Adapts the mutation strength with the 1/5th success rule.
+    pub fn with_one_fifth_rule(mut self, rule: OneFifthRule) -> Self {
+        self.one_fifth_rule = Some(rule);
+        self
+    }
+
+    /// Sets how registered crossover and mutation operators are picked.
+    pub fn with_operator_selection(mut self, selection: OperatorSelection) -> Self {
+        self.operator_selection = selection;
+        self
+    }
+
     /// Sets the stagnation limit (0 to disable).
     pub fn with_stagnation_limit(mut self, limit: usize) -> Self {
         self.stagnation_limit = limit;
         self
     }
 
+    /// Restarts the population on stagnation, see [`Restart`].
+    pub fn with_restart(mut self, restart: Restart) -> Self {
+        self.restart = Some(restart);
+        self
+    }
+
     /// Sets the convergence threshold.
     ///
     /// The stagnation counter is only reset when the relative improvement
@@ -170,6 +357,38 @@ impl GaConfig {
         self
     }
 
+    /// Sets the evaluation batch size (0 evaluates one at a time).
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Sets the fitness cache capacity (0 disables it).
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache_capacity = capacity;
+        self
+    }
+
+    /// Sets the checkpoint interval in generations (0 disables it).
+    pub fn with_checkpoint_interval(mut self, generations: usize) -> Self {
+        self.checkpoint_interval = generations;
+        self
+    }
+
+    /// Keeps the `size` best individuals that are more than `min_distance`
+    /// apart in the result's hall of fame.
+    pub fn with_hall_of_fame(mut self, size: usize, min_distance: f64) -> Self {
+        self.hall_of_fame = size;
+        self.hall_of_fame_distance = min_distance;
+        self
+    }
+
+    /// Sets whether fitness is minimized or maximized.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Sets the random seed for reproducibility.
     pub fn with_seed(mut self, seed: u64) -> Self {
         self.seed = Some(seed);
@@ -278,9 +497,46 @@ impl GaConfig {
         if elite_count >= self.population_size {
             return Err("elite_ratio too high: elites fill entire population".into());
         }
+        match self.replacement {
+            Replacement::MuPlusLambda(0) => {
+                return Err("(μ+λ) replacement needs at least 1 offspring".into());
+            }
+            Replacement::MuCommaLambda(lambda) if lambda < self.population_size => {
+                return Err(format!(
+                    "(μ,λ) replacement needs λ ≥ population_size, got {lambda}"
+                ));
+            }
+            Replacement::Asynchronous { workers: 0, .. } => {
+                return Err("asynchronous replacement needs at least 1 worker".into());
+            }
+            _ => {}
+        }
+        self.selection.validate()?;
+        self.constraint_handling.validate()?;
+        self.niching.validate()?;
+        if self.niching.replaces_population() && self.replacement != Replacement::Generational {
+            return Err(format!(
+                "{:?} needs generational replacement, got {:?}",
+                self.niching, self.replacement
+            ));
+        }
+        self.rate_control.validate()?;
+        if let Some(local_search) = &self.local_search {
+            local_search.validate()?;
+        }
+        if let Some(rule) = &self.one_fifth_rule {
+            rule.validate()?;
+        }
+        self.operator_selection.validate()?;
+        if let Some(restart) = &self.restart {
+            restart.validate(self.population_size)?;
+        }
         if self.convergence_threshold < 0.0 {
             return Err("convergence_threshold must be non-negative".into());
         }
+        if self.hall_of_fame_distance.is_nan() || self.hall_of_fame_distance < 0.0 {
+            return Err("hall_of_fame_distance must be non-negative".into());
+        }
         if self.time_limit_ms == Some(0) {
             return Err("time_limit_ms must be positive or None".into());
         }
@@ -291,6 +547,7 @@ impl GaConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ga::{LearningMode, SteadyStatePolicy};
 
     #[test]
     fn test_default_config() {
@@ -395,12 +652,87 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn test_validate_hall_of_fame_distance() {
+        assert!(GaConfig::default()
+            .with_hall_of_fame(5, 2.0)
+            .validate()
+            .is_ok());
+        assert!(GaConfig::default()
+            .with_hall_of_fame(5, -1.0)
+            .validate()
+            .is_err());
+        assert!(GaConfig::default()
+            .with_hall_of_fame(5, f64::NAN)
+            .validate()
+            .is_err());
+    }
+
     #[test]
     fn test_validate_positive_time_limit() {
         let config = GaConfig::default().with_time_limit_ms(1);
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_validate_replacement() {
+        let config = GaConfig::default().with_population_size(10);
+        assert!(config
+            .clone()
+            .with_replacement(Replacement::MuPlusLambda(0))
+            .validate()
+            .is_err());
+        assert!(config
+            .clone()
+            .with_replacement(Replacement::MuCommaLambda(9))
+            .validate()
+            .is_err());
+        assert!(config
+            .clone()
+            .with_replacement(Replacement::Asynchronous {
+                workers: 0,
+                policy: SteadyStatePolicy::ReplaceWorst,
+            })
+            .validate()
+            .is_err());
+        assert!(config
+            .with_replacement(Replacement::MuCommaLambda(10))
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_niching() {
+        let crowding = GaConfig::default().with_niching(Niching::DeterministicCrowding);
+        assert!(crowding.validate().is_ok());
+        assert!(crowding
+            .with_replacement(Replacement::MuPlusLambda(10))
+            .validate()
+            .is_err());
+        assert!(GaConfig::default()
+            .with_niching(Niching::FitnessSharing {
+                sigma: 2.0,
+                alpha: 1.0
+            })
+            .with_replacement(Replacement::MuPlusLambda(10))
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_validate_local_search() {
+        let local_search = |rate| {
+            GaConfig::default().with_local_search(LocalSearch {
+                rate,
+                mode: LearningMode::Lamarckian,
+            })
+        };
+        assert!(local_search(1.0).validate().is_ok());
+        for rate in [0.0, 1.5, f64::NAN] {
+            assert!(local_search(rate).validate().is_err(), "rate {rate}");
+        }
+    }
+
     // ---- Convergence threshold ----
 
     #[test]
@@ -0,0 +1,611 @@
+//! Constraint handling for the GA.
+//!
+//! Problems report a constraint violation per individual through
+//! [`GaProblem::constraint_violation`](super::GaProblem::constraint_violation)
+//! (`0.0` = feasible). [`ConstraintHandling`] decides how that violation
+//! combines with fitness when parents are selected and survivors are chosen.
+//!
+//! Regardless of the handler, the reported best solution follows the
+//! feasibility rules: a feasible solution always beats an infeasible one.
+//!
+//! # References
+//!
+//! - Deb (2000), "An Efficient Constraint Handling Method for Genetic
+//!   Algorithms", Comput. Methods Appl. Mech. Eng. 186, 311-338
+//! - Joines & Houck (1994), "On the Use of Non-Stationary Penalty Functions
+//!   to Solve Nonlinear Constrained Optimization Problems with GA's"
+//! - Hadj-Alouane & Bean (1997), "A Genetic Algorithm for the
+//!   Multiple-Choice Integer Program" (adaptive penalty)
+//! - Takahama & Sakai (2006), "Constrained Optimization by the ε Constrained
+//!   Differential Evolution with Gradient-Based Mutation and Feasible Elites"
+//! - Runarsson & Yao (2000), "Stochastic Ranking for Constrained Evolutionary
+//!   Optimization", IEEE Trans. Evol. Comput. 4(3), 284-294
+
+use super::selection::{fitness_keys, fitnesses};
+use super::types::Individual;
+use crate::direction::Direction;
+use rand::Rng;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+/// How constraint violation affects selection and survival.
+///
+/// Only matters for problems that override
+/// [`GaProblem::constraint_violation`](super::GaProblem::constraint_violation);
+/// when every individual is feasible all handlers reduce to plain fitness.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::ga::{ConstraintHandling, GaConfig};
+///
+/// let config = GaConfig::default()
+///     .with_constraint_handling(ConstraintHandling::DynamicPenalty { c: 0.5, alpha: 2.0 });
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConstraintHandling {
+    /// Deb's feasibility rules: feasible beats infeasible, two feasible
+    /// solutions compare by fitness, two infeasible ones by violation.
+    ///
+    /// Parameter-free; the default.
+    #[default]
+    FeasibilityRules,
+
+    /// Static penalty: `f + coefficient · v`.
+    StaticPenalty(f64),
+
+    /// Dynamic penalty growing with the generation `t`:
+    /// `f + (c · t)^alpha · v`. Joines & Houck use `c = 0.5`, `alpha = 2`.
+    DynamicPenalty {
+        /// Penalty scale per generation.
+        c: f64,
+        /// Growth exponent.
+        alpha: f64,
+    },
+
+    /// Adaptive penalty `f + λ · v`: λ is multiplied by `beta` when the best
+    /// individual was infeasible for the last `window` generations, and
+    /// divided by `beta` when it was feasible for all of them.
+    AdaptivePenalty {
+        /// Starting value of λ.
+        initial: f64,
+        /// Adjustment factor (> 1).
+        beta: f64,
+        /// Number of generations observed before adjusting.
+        window: usize,
+    },
+
+    /// ε-constrained method: violations up to ε(t) count as feasible, then
+    /// the feasibility rules apply. ε shrinks from `epsilon` to 0 as
+    /// `ε(t) = epsilon · (1 − t/control_generations)^exponent`.
+    EpsilonConstrained {
+        /// Initial tolerance ε(0).
+        epsilon: f64,
+        /// Shrink exponent (Takahama & Sakai use 2–10).
+        exponent: f64,
+        /// Generation after which ε is 0.
+        control_generations: usize,
+    },
+
+    /// Stochastic ranking with comparison probability `pf` (typically
+    /// 0.45): adjacent infeasible individuals are compared by fitness with
+    /// probability `pf` and by violation otherwise. Selection uses the
+    /// resulting rank.
+    StochasticRanking(f64),
+}
+
+impl ConstraintHandling {
+    /// Validates the handler parameters.
+    pub(super) fn validate(&self) -> Result<(), String> {
+        match *self {
+            ConstraintHandling::StaticPenalty(c) if c < 0.0 => {
+                Err("penalty coefficient must be non-negative".into())
+            }
+            ConstraintHandling::DynamicPenalty { c, alpha } if c < 0.0 || alpha < 0.0 => {
+                Err("dynamic penalty c and alpha must be non-negative".into())
+            }
+            ConstraintHandling::AdaptivePenalty {
+                initial,
+                beta,
+                window,
+            } if initial <= 0.0 || beta <= 1.0 || window == 0 => {
+                Err("adaptive penalty needs initial > 0, beta > 1 and window >= 1".into())
+            }
+            ConstraintHandling::EpsilonConstrained {
+                epsilon, exponent, ..
+            } if epsilon < 0.0 || exponent < 0.0 => {
+                Err("epsilon and exponent must be non-negative".into())
+            }
+            ConstraintHandling::StochasticRanking(pf) if !(0.0..=1.0).contains(&pf) => {
+                Err(format!("stochastic ranking pf must be in [0, 1], got {pf}"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Per-run state of a [`ConstraintHandling`] (generation counter, adaptive
+/// penalty weight).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(super) struct ConstraintState {
+    handling: ConstraintHandling,
+    /// Taken from the configuration, also on resume.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    direction: Direction,
+    generation: usize,
+    penalty: f64,
+    best_feasible: VecDeque<bool>,
+}
+
+impl ConstraintState {
+    pub(super) fn new(handling: ConstraintHandling, direction: Direction) -> Self {
+        let penalty = match handling {
+            ConstraintHandling::AdaptivePenalty { initial, .. } => initial,
+            _ => 0.0,
+        };
+        Self {
+            handling,
+            direction,
+            generation: 0,
+            penalty,
+            best_feasible: VecDeque::new(),
+        }
+    }
+
+    /// Replaces the optimization direction of a restored state.
+    pub(super) fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Selection keys (lower is better) for `population`, or `None` when
+    /// every individual is feasible and raw fitness should be minimized.
+    pub(super) fn keys<I: Individual, R: Rng>(
+        &self,
+        population: &[I],
+        rng: &mut R,
+    ) -> Option<Vec<f64>> {
+        if population.iter().all(|ind| ind.violation() <= 0.0) {
+            return match self.direction {
+                Direction::Minimize => None,
+                Direction::Maximize => Some(fitness_keys(&fitnesses(population), self.direction)),
+            };
+        }
+        Some(match self.handling {
+            ConstraintHandling::FeasibilityRules => {
+                feasibility_keys(population, 0.0, self.direction)
+            }
+            ConstraintHandling::EpsilonConstrained { .. } => {
+                feasibility_keys(population, self.epsilon(), self.direction)
+            }
+            ConstraintHandling::StochasticRanking(pf) => {
+                stochastic_ranks(population, pf, self.direction, rng)
+            }
+            _ => {
+                let lambda = self.penalty_weight();
+                fitness_keys(&fitnesses(population), self.direction)
+                    .into_iter()
+                    .zip(population)
+                    .map(|(key, ind)| key + lambda * ind.violation())
+                    .collect()
+            }
+        })
+    }
+
+    /// Whether `a` is strictly better than `b` under this handler.
+    ///
+    /// Stochastic ranking has no pairwise form and falls back to the
+    /// feasibility rules.
+    pub(super) fn less<I: Individual>(&self, a: &I, b: &I) -> bool {
+        match self.handling {
+            ConstraintHandling::FeasibilityRules | ConstraintHandling::StochasticRanking(_) => {
+                feasibility_cmp(a, b, 0.0, self.direction) == Ordering::Less
+            }
+            ConstraintHandling::EpsilonConstrained { .. } => {
+                feasibility_cmp(a, b, self.epsilon(), self.direction) == Ordering::Less
+            }
+            _ if a.violation() <= 0.0 && b.violation() <= 0.0 => {
+                self.direction.is_better(&a.fitness(), &b.fitness())
+            }
+            _ => {
+                let lambda = self.penalty_weight();
+                let keys = fitness_keys(&[a.fitness(), b.fitness()], self.direction);
+                keys[0] + lambda * a.violation() < keys[1] + lambda * b.violation()
+            }
+        }
+    }
+
+    /// Number of completed generations.
+    pub(super) fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Advances the generation counter and adapts the penalty weight.
+    pub(super) fn end_generation<I: Individual>(&mut self, population: &[I]) {
+        self.generation += 1;
+
+        if let ConstraintHandling::AdaptivePenalty { beta, window, .. } = self.handling {
+            let best = population
+                .iter()
+                .reduce(|a, b| if self.less(b, a) { b } else { a })
+                .expect("population must not be empty");
+            self.best_feasible.push_back(best.violation() <= 0.0);
+            if self.best_feasible.len() > window {
+                self.best_feasible.pop_front();
+            }
+            if self.best_feasible.len() == window {
+                if self.best_feasible.iter().all(|&f| !f) {
+                    self.penalty *= beta;
+                } else if self.best_feasible.iter().all(|&f| f) {
+                    self.penalty /= beta;
+                }
+            }
+        }
+    }
+
+    fn penalty_weight(&self) -> f64 {
+        match self.handling {
+            ConstraintHandling::StaticPenalty(c) => c,
+            ConstraintHandling::DynamicPenalty { c, alpha } => {
+                (c * (self.generation + 1) as f64).powf(alpha)
+            }
+            _ => self.penalty,
+        }
+    }
+
+    /// Current ε tolerance; 0 for handlers other than ε-constrained.
+    fn epsilon(&self) -> f64 {
+        match self.handling {
+            ConstraintHandling::EpsilonConstrained {
+                epsilon,
+                exponent,
+                control_generations,
+            } if self.generation < control_generations => {
+                let progress = self.generation as f64 / control_generations as f64;
+                epsilon * (1.0 - progress).powf(exponent)
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// Feasibility-rule ordering, best first, with violations up to
+/// `tolerance` treated as feasible (ε-level comparison when
+/// `tolerance > 0`).
+pub(super) fn feasibility_cmp<I: Individual>(
+    a: &I,
+    b: &I,
+    tolerance: f64,
+    direction: Direction,
+) -> Ordering {
+    let va = effective_violation(a.violation(), tolerance);
+    let vb = effective_violation(b.violation(), tolerance);
+    if va == vb {
+        direction
+            .compare(&a.fitness(), &b.fitness())
+            .unwrap_or(Ordering::Equal)
+    } else {
+        va.partial_cmp(&vb).unwrap_or(Ordering::Equal)
+    }
+}
+
+fn effective_violation(v: f64, tolerance: f64) -> f64 {
+    if v <= tolerance {
+        0.0
+    } else {
+        v
+    }
+}
+
+/// Deb's parameter-free fitness: the fitness key (see [`fitness_keys`]) for
+/// feasible individuals, otherwise the worst feasible key plus the violation
+/// (or the violation alone if no individual is feasible).
+fn feasibility_keys<I: Individual>(
+    population: &[I],
+    tolerance: f64,
+    direction: Direction,
+) -> Vec<f64> {
+    let feasible: Vec<_> = population
+        .iter()
+        .filter(|ind| ind.violation() <= tolerance)
+        .map(Individual::fitness)
+        .collect();
+    let mut feasible_keys = fitness_keys(&feasible, direction).into_iter();
+    let worst_feasible = feasible_keys.clone().fold(f64::NEG_INFINITY, f64::max);
+    let offset = if worst_feasible.is_finite() {
+        worst_feasible
+    } else {
+        0.0
+    };
+    population
+        .iter()
+        .map(|ind| {
+            if ind.violation() <= tolerance {
+                feasible_keys
+                    .next()
+                    .expect("one key per feasible individual")
+            } else {
+                offset + ind.violation()
+            }
+        })
+        .collect()
+}
+
+/// Stochastic ranking: a bubble sort whose comparisons between pairs that
+/// are not both feasible use fitness with probability `pf`, violation
+/// otherwise. Returns each individual's rank (0 = best).
+fn stochastic_ranks<I: Individual, R: Rng>(
+    population: &[I],
+    pf: f64,
+    direction: Direction,
+    rng: &mut R,
+) -> Vec<f64> {
+    let n = population.len();
+    let mut order: Vec<usize> = (0..n).collect();
+    for _ in 0..n {
+        let mut swapped = false;
+        for j in 0..n.saturating_sub(1) {
+            let a = &population[order[j]];
+            let b = &population[order[j + 1]];
+            let by_fitness =
+                (a.violation() <= 0.0 && b.violation() <= 0.0) || rng.random_range(0.0..1.0) < pf;
+            let out_of_order = if by_fitness {
+                direction.is_better(&b.fitness(), &a.fitness())
+            } else {
+                a.violation() > b.violation()
+            };
+            if out_of_order {
+                order.swap(j, j + 1);
+                swapped = true;
+            }
+        }
+        if !swapped {
+            break;
+        }
+    }
+
+    let mut ranks = vec![0.0; n];
+    for (rank, &i) in order.iter().enumerate() {
+        ranks[i] = rank as f64;
+    }
+    ranks
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direction::Direction::{Maximize, Minimize};
+    use crate::ga::{Fitness, Lexicographic};
+    use u_numflow::random::create_rng;
+
+    #[derive(Clone, Debug)]
+    struct Ind {
+        f: f64,
+        v: f64,
+    }
+
+    impl Individual for Ind {
+        type Fitness = f64;
+        fn fitness(&self) -> f64 {
+            self.f
+        }
+        fn set_fitness(&mut self, f: f64) {
+            self.f = f;
+        }
+        fn violation(&self) -> f64 {
+            self.v
+        }
+        fn set_violation(&mut self, v: f64) {
+            self.v = v;
+        }
+    }
+
+    fn ind(f: f64, v: f64) -> Ind {
+        Ind { f, v }
+    }
+
+    #[test]
+    fn test_all_feasible_uses_raw_fitness() {
+        let state = ConstraintState::new(ConstraintHandling::StaticPenalty(10.0), Minimize);
+        let mut rng = create_rng(1);
+        assert!(state
+            .keys(&[ind(1.0, 0.0), ind(2.0, 0.0)], &mut rng)
+            .is_none());
+    }
+
+    #[test]
+    fn test_maximize_negates_keys() {
+        let mut rng = create_rng(1);
+        let state = ConstraintState::new(ConstraintHandling::StaticPenalty(10.0), Maximize);
+        assert_eq!(
+            state.keys(&[ind(1.0, 0.0), ind(2.0, 0.0)], &mut rng),
+            Some(vec![-1.0, -2.0])
+        );
+        assert_eq!(
+            state.keys(&[ind(1.0, 0.5), ind(2.0, 0.0)], &mut rng),
+            Some(vec![4.0, -2.0])
+        );
+        assert!(state.less(&ind(2.0, 0.0), &ind(1.0, 0.0)));
+
+        let population = [ind(5.0, 0.0), ind(1.0, 2.0), ind(3.0, 0.0)];
+        assert_eq!(
+            feasibility_keys(&population, 0.0, Maximize),
+            vec![-5.0, -1.0, -3.0]
+        );
+        assert_eq!(
+            feasibility_cmp(&ind(9.0, 0.0), &ind(1.0, 0.0), 0.0, Maximize),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_feasibility_keys_order() {
+        let population = [ind(5.0, 0.0), ind(1.0, 2.0), ind(3.0, 0.0), ind(0.0, 0.5)];
+        let keys = feasibility_keys(&population, 0.0, Minimize);
+        // Feasible by fitness, then infeasible by violation
+        assert_eq!(keys, vec![5.0, 7.0, 3.0, 5.5]);
+    }
+
+    #[derive(Clone, Debug)]
+    struct Ranked {
+        f: Lexicographic<2>,
+        v: f64,
+    }
+
+    impl Individual for Ranked {
+        type Fitness = Lexicographic<2>;
+        fn fitness(&self) -> Lexicographic<2> {
+            self.f
+        }
+        fn set_fitness(&mut self, f: Lexicographic<2>) {
+            self.f = f;
+        }
+        fn violation(&self) -> f64 {
+            self.v
+        }
+        fn set_violation(&mut self, v: f64) {
+            self.v = v;
+        }
+    }
+
+    #[test]
+    fn test_keys_follow_lexicographic_order_beyond_scale() {
+        // Second components above `SCALE` reverse the order of `to_f64`
+        let ranked = |first, second, v| Ranked {
+            f: Lexicographic([first, second]),
+            v,
+        };
+        let population = [
+            ranked(1.0, 5e6, 0.0),
+            ranked(2.0, 0.0, 0.0),
+            ranked(1.0, 5e6, 0.0),
+        ];
+        assert!(population[0].f.to_f64() > population[1].f.to_f64());
+        let mut rng = create_rng(1);
+
+        let maximize = ConstraintState::new(ConstraintHandling::FeasibilityRules, Maximize);
+        assert_eq!(
+            maximize.keys(&population, &mut rng),
+            Some(vec![1.0, 0.0, 1.0])
+        );
+
+        let mut population = population.to_vec();
+        population.push(ranked(0.0, 0.0, 0.5));
+        let minimize = ConstraintState::new(ConstraintHandling::FeasibilityRules, Minimize);
+        assert_eq!(
+            minimize.keys(&population, &mut rng),
+            Some(vec![0.0, 2.0, 0.0, 2.5])
+        );
+        let penalty = ConstraintState::new(ConstraintHandling::StaticPenalty(1.0), Minimize);
+        assert!(penalty.less(&population[0], &population[1]));
+    }
+
+    #[test]
+    fn test_feasibility_cmp() {
+        assert_eq!(
+            feasibility_cmp(&ind(9.0, 0.0), &ind(1.0, 0.1), 0.0, Minimize),
+            Ordering::Less
+        );
+        assert_eq!(
+            feasibility_cmp(&ind(9.0, 0.3), &ind(1.0, 0.5), 0.0, Minimize),
+            Ordering::Less
+        );
+        // Within tolerance both count as feasible
+        assert_eq!(
+            feasibility_cmp(&ind(9.0, 0.3), &ind(1.0, 0.5), 1.0, Minimize),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_penalties() {
+        let population = [ind(1.0, 2.0), ind(2.0, 0.0)];
+        let mut rng = create_rng(1);
+
+        let state = ConstraintState::new(ConstraintHandling::StaticPenalty(3.0), Minimize);
+        assert_eq!(state.keys(&population, &mut rng), Some(vec![7.0, 2.0]));
+
+        let mut state = ConstraintState::new(
+            ConstraintHandling::DynamicPenalty { c: 0.5, alpha: 2.0 },
+            Minimize,
+        );
+        state.generation = 3; // (0.5 · 4)² = 4
+        assert_eq!(state.keys(&population, &mut rng), Some(vec![9.0, 2.0]));
+    }
+
+    #[test]
+    fn test_adaptive_penalty_grows_and_shrinks() {
+        let handling = ConstraintHandling::AdaptivePenalty {
+            initial: 1.0,
+            beta: 2.0,
+            window: 2,
+        };
+        let mut state = ConstraintState::new(handling, Minimize);
+
+        let infeasible = [ind(0.0, 1.0), ind(5.0, 1.0)];
+        state.end_generation(&infeasible);
+        assert_eq!(state.penalty, 1.0);
+        state.end_generation(&infeasible);
+        assert_eq!(state.penalty, 2.0);
+
+        let feasible = [ind(0.0, 0.0)];
+        state.end_generation(&feasible);
+        assert_eq!(state.penalty, 2.0, "mixed window leaves λ unchanged");
+        state.end_generation(&feasible);
+        assert_eq!(state.penalty, 1.0);
+    }
+
+    #[test]
+    fn test_epsilon_schedule() {
+        let mut state = ConstraintState::new(
+            ConstraintHandling::EpsilonConstrained {
+                epsilon: 4.0,
+                exponent: 2.0,
+                control_generations: 2,
+            },
+            Minimize,
+        );
+        assert_eq!(state.epsilon(), 4.0);
+        state.generation = 1;
+        assert_eq!(state.epsilon(), 1.0);
+        state.generation = 2;
+        assert_eq!(state.epsilon(), 0.0);
+    }
+
+    #[test]
+    fn test_stochastic_ranking_extremes() {
+        let population = [ind(0.0, 3.0), ind(5.0, 0.0), ind(1.0, 1.0)];
+        let mut rng = create_rng(1);
+
+        // pf = 0: pure violation order among infeasible pairs
+        let ranks = stochastic_ranks(&population, 0.0, Minimize, &mut rng);
+        assert_eq!(ranks, vec![2.0, 0.0, 1.0]);
+
+        // pf = 1: pure fitness order
+        let ranks = stochastic_ranks(&population, 1.0, Minimize, &mut rng);
+        assert_eq!(ranks, vec![0.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(ConstraintHandling::default().validate().is_ok());
+        assert!(ConstraintHandling::StaticPenalty(-1.0).validate().is_err());
+        assert!(ConstraintHandling::StochasticRanking(1.5)
+            .validate()
+            .is_err());
+        assert!(ConstraintHandling::AdaptivePenalty {
+            initial: 1.0,
+            beta: 1.0,
+            window: 5
+        }
+        .validate()
+        .is_err());
+    }
+}
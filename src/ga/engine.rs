@@ -0,0 +1,688 @@
+//! Step-wise GA execution.
+//!
+//! [`GaEngine`] holds the state of a GA run and advances it one generation
+//! per [`step`](GaEngine::step). Between steps the caller can inspect the
+//! population, inject or replace individuals, or take a checkpoint. The
+//! engine is also an [`Iterator`] over per-generation statistics, so it can
+//! be driven from an external event loop or UI.
+//!
+//! [`GaRunner`](super::GaRunner) is a closed loop over the same engine that
+//! adds cancellation and a time limit.
+
+use super::adaptive::OperatorControl;
+use super::config::GaConfig;
+use super::constraint::ConstraintState;
+use super::restart::restart_population;
+use super::runner::{
+    compute_generation_stats, evaluate_population, extreme_index, find_best, initial_population,
+    next_generation, record_hall_of_fame, update_best, FitnessCache, GaResult, GenerationStats,
+};
+use super::types::{Fitness, GaProblem, Individual};
+use crate::hall_of_fame::HallOfFame;
+use crate::observer::{Event, Observer, TerminationReason};
+use crate::rng::{create_checkpoint_rng, CheckpointRng, RngState};
+
+/// Snapshot of a GA run between two generations.
+///
+/// Delivered to [`GaProblem::on_checkpoint`] every
+/// [`GaConfig::checkpoint_interval`] generations, or taken on demand with
+/// [`GaEngine::checkpoint`]. Pass it to
+/// [`GaRunner::resume_from`](super::GaRunner::resume_from) or
+/// [`GaEngine::resume_from`] to continue the run. With the `serde` feature
+/// the snapshot is serializable whenever the individual type is.
+///
+/// A resumed run reproduces the uninterrupted one exactly (given the same
+/// configuration and a deterministic problem), except that the fitness
+/// cache and its hit/miss counters start empty and the time limit restarts.
+/// Taking checkpoints does not change the course of a run.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GaState<I: Individual> {
+    /// Current (evaluated) population.
+    pub population: Vec<I>,
+
+    /// Best individual found so far.
+    pub best: I,
+
+    /// Hall of fame entries so far, best first.
+    #[cfg_attr(feature = "serde", serde(default = "Vec::new"))]
+    pub hall_of_fame: Vec<I>,
+
+    /// Number of completed generations.
+    pub generation: usize,
+
+    /// Generations since the last significant improvement or restart.
+    pub stagnation_counter: usize,
+
+    /// Number of restarts so far.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub restarts: usize,
+
+    /// State of the random number generator.
+    pub rng: RngState,
+
+    /// Best fitness at the end of each completed generation.
+    pub fitness_history: Vec<f64>,
+
+    /// Per-generation population statistics so far.
+    pub generation_stats: Vec<GenerationStats>,
+
+    /// Constraint handler state (generation counter, adaptive penalty).
+    constraints: ConstraintState,
+
+    /// Adaptive operator state (probabilities, mutation strength, usage).
+    operators: OperatorControl,
+}
+
+/// A GA run advanced one generation at a time.
+///
+/// # Usage
+///
+/// ```ignore
+/// let mut engine = GaEngine::new(&problem, &config)?;
+/// while let Some(stats) = engine.step() {
+///     println!("gen {}: best {}", stats.generation, stats.best_fitness);
+///     if stats.generation % 50 == 0 {
+///         engine.inject(heuristic_solutions());
+///     }
+/// }
+/// let result = engine.into_result();
+/// ```
+pub struct GaEngine<'a, P: GaProblem> {
+    problem: &'a P,
+    config: GaConfig,
+    population: Vec<P::Individual>,
+    best: P::Individual,
+    hall_of_fame: HallOfFame<P::Individual>,
+    generation: usize,
+    stagnation_counter: usize,
+    restarts: usize,
+    fitness_history: Vec<f64>,
+    generation_stats: Vec<GenerationStats>,
+    constraints: ConstraintState,
+    operators: OperatorControl,
+    cache: FitnessCache<P::Individual>,
+    rng: CheckpointRng,
+    observer: Option<&'a mut dyn Observer>,
+    terminated: bool,
+}
+
+impl<'a, P: GaProblem> GaEngine<'a, P> {
+    /// Creates an engine with a random, evaluated initial population.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid.
+    pub fn new(problem: &'a P, config: &GaConfig) -> Result<Self, String> {
+        Self::with_seeds(problem, config, Vec::new())
+    }
+
+    /// Creates an engine whose initial population starts with `seeds`.
+    ///
+    /// See [`GaRunner::run_with_seeds`](super::GaRunner::run_with_seeds)
+    /// for how seeds are combined with random individuals.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid.
+    pub fn with_seeds(
+        problem: &'a P,
+        config: &GaConfig,
+        seeds: Vec<P::Individual>,
+    ) -> Result<Self, String> {
+        config.validate()?;
+        let operators = OperatorControl::new(problem, config)?;
+
+        let mut rng = create_checkpoint_rng(config.seed);
+
+        let cache = FitnessCache::<P::Individual>::new(config.cache_capacity);
+
+        // 1. Initialize population
+        let mut population = initial_population(problem, config.population_size, seeds, &mut rng);
+
+        // 2. Evaluate initial population
+        evaluate_population(problem, &mut population, config, &cache);
+
+        // 3. Track best
+        let best = find_best(&population, config.direction).clone();
+        let mut hall_of_fame = HallOfFame::new(config.hall_of_fame, config.hall_of_fame_distance);
+        record_hall_of_fame(problem, &mut hall_of_fame, &population, config.direction);
+        let mut fitness_history = Vec::with_capacity(config.max_generations + 1);
+        fitness_history.push(best.fitness().to_f64());
+
+        // Record initial population stats
+        let mut generation_stats = Vec::with_capacity(config.max_generations + 1);
+        generation_stats.push(compute_generation_stats(problem, &population, 0, config));
+
+        Ok(Self {
+            problem,
+            config: config.clone(),
+            population,
+            best,
+            hall_of_fame,
+            generation: 0,
+            stagnation_counter: 0,
+            restarts: 0,
+            fitness_history,
+            generation_stats,
+            constraints: ConstraintState::new(config.constraint_handling, config.direction),
+            operators,
+            cache,
+            rng,
+            observer: None,
+            terminated: false,
+        })
+    }
+
+    /// Recreates the engine from a checkpoint.
+    ///
+    /// `config` should be the configuration of the interrupted run;
+    /// `max_generations` may be raised to extend it.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid, or the state's
+    /// population or operators do not match `population_size` and the
+    /// problem.
+    pub fn resume_from(
+        problem: &'a P,
+        config: &GaConfig,
+        state: GaState<P::Individual>,
+    ) -> Result<Self, String> {
+        config.validate()?;
+        if state.population.len() != config.population_size {
+            return Err(format!(
+                "checkpoint population has {} individuals, expected population_size {}",
+                state.population.len(),
+                config.population_size
+            ));
+        }
+        if !state.operators.matches(problem) {
+            return Err("checkpoint operator count does not match the problem".into());
+        }
+
+        Ok(Self {
+            problem,
+            config: config.clone(),
+            population: state.population,
+            best: state.best,
+            hall_of_fame: HallOfFame::new(config.hall_of_fame, config.hall_of_fame_distance)
+                .with_entries(state.hall_of_fame),
+            generation: state.generation,
+            stagnation_counter: state.stagnation_counter,
+            restarts: state.restarts,
+            fitness_history: state.fitness_history,
+            generation_stats: state.generation_stats,
+            constraints: state.constraints.with_direction(config.direction),
+            operators: state.operators,
+            cache: FitnessCache::<P::Individual>::new(config.cache_capacity),
+            rng: state.rng.restore(),
+            observer: None,
+            terminated: false,
+        })
+    }
+
+    /// Reports progress events to `observer`.
+    ///
+    /// The engine emits [`Event::NewBest`], [`Event::GenerationFinished`]
+    /// and, once it finishes, [`Event::Terminated`].
+    pub fn with_observer(mut self, observer: &'a mut dyn Observer) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Runs one generation and returns its population statistics.
+    ///
+    /// Calls [`GaProblem::on_generation`] and, every
+    /// `checkpoint_interval` generations, [`GaProblem::on_checkpoint`].
+    /// If the generation reaches the stagnation limit and
+    /// [`GaConfig::restart`] allows it, the population is restarted
+    /// afterwards; the returned statistics are those before the restart.
+    /// Returns `None` without doing anything once
+    /// [`is_finished`](Self::is_finished).
+    pub fn step(&mut self) -> Option<GenerationStats> {
+        if self.is_finished() {
+            return None;
+        }
+
+        next_generation(
+            self.problem,
+            &mut self.population,
+            &self.config,
+            &mut self.constraints,
+            &mut self.operators,
+            &self.cache,
+            &mut self.rng,
+        );
+        self.generation += 1;
+        self.record_hall_of_fame(0..self.population.len());
+
+        // Update best
+        let previous = (self.best.fitness(), self.best.violation());
+        if update_best(
+            &mut self.best,
+            find_best(&self.population, self.config.direction),
+            self.config.convergence_threshold,
+            self.config.direction,
+        ) {
+            self.stagnation_counter = 0;
+        } else {
+            self.stagnation_counter += 1;
+        }
+
+        let stats = compute_generation_stats(
+            self.problem,
+            &self.population,
+            self.generation,
+            &self.config,
+        );
+        self.fitness_history.push(self.best.fitness().to_f64());
+        self.generation_stats.push(stats.clone());
+
+        // Callbacks
+        if (self.best.fitness(), self.best.violation()) != previous {
+            self.notify_new_best();
+        }
+        self.notify(&Event::GenerationFinished { stats: &stats });
+        self.problem
+            .on_generation(self.generation, self.best.fitness());
+        self.try_restart();
+        let interval = self.config.checkpoint_interval;
+        if interval > 0 && self.generation.is_multiple_of(interval) {
+            let state = self.checkpoint();
+            self.problem.on_checkpoint(&state);
+        }
+        if self.is_finished() {
+            self.terminate(self.finish_reason());
+        }
+
+        Some(stats)
+    }
+
+    /// Whether the run has reached `max_generations`, or the stagnation
+    /// limit with no restarts left.
+    pub fn is_finished(&self) -> bool {
+        self.generation >= self.config.max_generations || self.is_stagnated()
+    }
+
+    fn is_stagnated(&self) -> bool {
+        self.config.stagnation_limit > 0 && self.stagnation_counter >= self.config.stagnation_limit
+    }
+
+    /// Restarts the population if it has stagnated and restarts and
+    /// generations remain.
+    fn try_restart(&mut self) {
+        let Some(restart) = self.config.restart else {
+            return;
+        };
+        if !self.is_stagnated()
+            || self.restarts >= restart.max_restarts
+            || self.generation >= self.config.max_generations
+        {
+            return;
+        }
+        restart_population(
+            self.problem,
+            &mut self.population,
+            &restart,
+            &self.config,
+            &self.cache,
+            &mut self.rng,
+        );
+        self.restarts += 1;
+        self.stagnation_counter = 0;
+        self.record_hall_of_fame(0..self.population.len());
+
+        let previous = (self.best.fitness(), self.best.violation());
+        update_best(
+            &mut self.best,
+            find_best(&self.population, self.config.direction),
+            self.config.convergence_threshold,
+            self.config.direction,
+        );
+        if (self.best.fitness(), self.best.violation()) != previous {
+            self.notify_new_best();
+        }
+    }
+
+    /// Why the run stops if it stops now of its own accord.
+    pub(super) fn finish_reason(&self) -> TerminationReason {
+        if self.is_stagnated() {
+            TerminationReason::Stagnated
+        } else {
+            TerminationReason::MaxIterations
+        }
+    }
+
+    /// Emits [`Event::Terminated`] unless it was already emitted.
+    pub(super) fn terminate(&mut self, reason: TerminationReason) {
+        if !self.terminated {
+            self.terminated = true;
+            self.notify(&Event::Terminated {
+                iterations: self.generation,
+                best_cost: self.best.fitness().to_f64(),
+                reason,
+            });
+        }
+    }
+
+    fn notify(&mut self, event: &Event<'_>) {
+        if let Some(observer) = self.observer.as_deref_mut() {
+            observer.on_event(event);
+        }
+    }
+
+    fn notify_new_best(&mut self) {
+        self.notify(&Event::NewBest {
+            iteration: self.generation,
+            cost: self.best.fitness().to_f64(),
+        });
+    }
+
+    /// The current population.
+    pub fn population(&self) -> &[P::Individual] {
+        &self.population
+    }
+
+    /// The best individual found so far.
+    pub fn best(&self) -> &P::Individual {
+        &self.best
+    }
+
+    /// Number of completed generations.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Number of restarts so far.
+    pub fn restarts(&self) -> usize {
+        self.restarts
+    }
+
+    /// Best fitness at the end of each completed generation.
+    pub fn fitness_history(&self) -> &[f64] {
+        &self.fitness_history
+    }
+
+    /// Evaluates `individuals` and lets each replace the current worst
+    /// member of the population.
+    ///
+    /// A better injected individual becomes the best and resets the
+    /// stagnation counter. Individuals beyond the population size replace
+    /// earlier injections.
+    pub fn inject(&mut self, mut individuals: Vec<P::Individual>) {
+        evaluate_population(self.problem, &mut individuals, &self.config, &self.cache);
+        for ind in individuals {
+            let constraints = &self.constraints;
+            let worst = extreme_index(&self.population, |a, b| constraints.less(b, a));
+            self.population[worst] = ind;
+            self.track_best(worst);
+        }
+    }
+
+    /// Evaluates `individual` and puts it at `index` in the population.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn replace(&mut self, index: usize, mut individual: P::Individual) {
+        evaluate_population(
+            self.problem,
+            std::slice::from_mut(&mut individual),
+            &self.config,
+            &self.cache,
+        );
+        self.population[index] = individual;
+        self.track_best(index);
+    }
+
+    /// Offers `population[range]` to the hall of fame.
+    fn record_hall_of_fame(&mut self, range: std::ops::Range<usize>) {
+        record_hall_of_fame(
+            self.problem,
+            &mut self.hall_of_fame,
+            &self.population[range],
+            self.config.direction,
+        );
+    }
+
+    /// Updates the best individual and the hall of fame after
+    /// `population[index]` changed.
+    fn track_best(&mut self, index: usize) {
+        self.record_hall_of_fame(index..index + 1);
+        let previous = (self.best.fitness(), self.best.violation());
+        if update_best(
+            &mut self.best,
+            &self.population[index],
+            self.config.convergence_threshold,
+            self.config.direction,
+        ) {
+            self.stagnation_counter = 0;
+        }
+        if (self.best.fitness(), self.best.violation()) != previous {
+            self.notify_new_best();
+        }
+    }
+
+    /// Takes a snapshot from which [`resume_from`](Self::resume_from)
+    /// continues the run.
+    ///
+    /// Taking a snapshot does not change the course of this engine's run.
+    pub fn checkpoint(&self) -> GaState<P::Individual> {
+        GaState {
+            population: self.population.clone(),
+            best: self.best.clone(),
+            hall_of_fame: self.hall_of_fame.entries().to_vec(),
+            generation: self.generation,
+            stagnation_counter: self.stagnation_counter,
+            restarts: self.restarts,
+            rng: RngState::capture(&self.rng),
+            fitness_history: self.fitness_history.clone(),
+            generation_stats: self.generation_stats.clone(),
+            constraints: self.constraints.clone(),
+            operators: self.operators.clone(),
+        }
+    }
+
+    /// Finishes the run and returns its result.
+    ///
+    /// `cancelled` and `timed_out` are `false`; `stagnated` reflects the
+    /// stagnation limit.
+    pub fn into_result(self) -> GaResult<P::Individual> {
+        GaResult {
+            best_fitness: self.best.fitness(),
+            stagnated: self.is_stagnated(),
+            best: self.best,
+            hall_of_fame: self.hall_of_fame.into_entries(),
+            generations: self.generation,
+            restarts: self.restarts,
+            cancelled: false,
+            timed_out: false,
+            fitness_history: self.fitness_history,
+            generation_stats: self.generation_stats,
+            cache_hits: self.cache.hits(),
+            cache_misses: self.cache.misses(),
+            crossover_usage: self.operators.crossover_usage(),
+            mutation_usage: self.operators.mutation_usage(),
+        }
+    }
+}
+
+impl<P: GaProblem> Iterator for GaEngine<'_, P> {
+    type Item = GenerationStats;
+
+    fn next(&mut self) -> Option<GenerationStats> {
+        self.step()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ga::GaRunner;
+    use rand::Rng;
+
+    // ---- OneMax: minimize the number of zero bits ----
+
+    #[derive(Clone, Debug)]
+    struct Bits {
+        bits: Vec<bool>,
+        fitness: f64,
+    }
+
+    impl Individual for Bits {
+        type Fitness = f64;
+        fn fitness(&self) -> f64 {
+            self.fitness
+        }
+        fn set_fitness(&mut self, f: f64) {
+            self.fitness = f;
+        }
+    }
+
+    struct OneMax {
+        n: usize,
+    }
+
+    impl GaProblem for OneMax {
+        type Individual = Bits;
+
+        fn create_individual<R: Rng>(&self, rng: &mut R) -> Bits {
+            Bits {
+                bits: (0..self.n).map(|_| rng.random_bool(0.5)).collect(),
+                fitness: f64::INFINITY,
+            }
+        }
+
+        fn evaluate(&self, ind: &Bits) -> f64 {
+            ind.bits.iter().filter(|&&b| !b).count() as f64
+        }
+
+        fn crossover<R: Rng>(&self, p1: &Bits, p2: &Bits, rng: &mut R) -> Vec<Bits> {
+            let bits = p1
+                .bits
+                .iter()
+                .zip(&p2.bits)
+                .map(|(&a, &b)| if rng.random_bool(0.5) { a } else { b })
+                .collect();
+            vec![Bits {
+                bits,
+                fitness: f64::INFINITY,
+            }]
+        }
+
+        fn mutate<R: Rng>(&self, ind: &mut Bits, rng: &mut R) {
+            let i = rng.random_range(0..self.n);
+            ind.bits[i] = !ind.bits[i];
+        }
+    }
+
+    fn ones(n: usize) -> Bits {
+        Bits {
+            bits: vec![true; n],
+            fitness: f64::INFINITY,
+        }
+    }
+
+    fn config() -> GaConfig {
+        GaConfig::default()
+            .with_population_size(20)
+            .with_max_generations(25)
+            .with_stagnation_limit(0)
+            .with_seed(42)
+            .with_parallel(false)
+    }
+
+    #[test]
+    fn test_iterator_matches_runner() {
+        let problem = OneMax { n: 30 };
+        let mut engine = GaEngine::new(&problem, &config()).unwrap();
+
+        let stats: Vec<GenerationStats> = engine.by_ref().collect();
+        assert_eq!(stats.len(), 25);
+        assert_eq!(stats.last().unwrap().generation, 25);
+        assert!(engine.is_finished());
+        assert!(engine.step().is_none());
+
+        let stepped = engine.into_result();
+        let run = GaRunner::run(&problem, &config()).unwrap();
+        assert_eq!(stepped.generations, run.generations);
+        assert_eq!(stepped.fitness_history, run.fitness_history);
+        assert_eq!(stepped.best.bits, run.best.bits);
+    }
+
+    #[test]
+    fn test_inject_replaces_worst_and_updates_best() {
+        let problem = OneMax { n: 40 };
+        let mut engine = GaEngine::new(&problem, &config()).unwrap();
+        engine.step();
+
+        let worst_before = engine
+            .population()
+            .iter()
+            .map(|ind| ind.fitness)
+            .fold(f64::NEG_INFINITY, f64::max);
+        engine.inject(vec![ones(40)]);
+
+        assert_eq!(engine.best().fitness, 0.0);
+        assert_eq!(engine.population().len(), 20);
+        assert!(engine.population().iter().any(|ind| ind.fitness == 0.0));
+        let worst_after = engine
+            .population()
+            .iter()
+            .map(|ind| ind.fitness)
+            .fold(f64::NEG_INFINITY, f64::max);
+        assert!(worst_after <= worst_before);
+
+        engine.step();
+        assert_eq!(*engine.fitness_history().last().unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_replace_evaluates_individual() {
+        let problem = OneMax { n: 10 };
+        let mut engine = GaEngine::new(&problem, &config()).unwrap();
+
+        engine.replace(3, ones(10));
+
+        assert_eq!(engine.population()[3].fitness, 0.0);
+        assert_eq!(engine.best().fitness, 0.0);
+        assert_eq!(engine.generation(), 0);
+    }
+
+    #[test]
+    fn test_checkpoint_resumes_identically() {
+        let problem = OneMax { n: 30 };
+        let mut engine = GaEngine::new(&problem, &config()).unwrap();
+        engine.by_ref().take(10).for_each(drop);
+
+        let state = engine.checkpoint();
+        let mut resumed = GaEngine::resume_from(&problem, &config(), state).unwrap();
+        assert_eq!(resumed.generation(), 10);
+
+        let a = engine.into_result();
+        let b = {
+            resumed.by_ref().for_each(drop);
+            resumed.into_result()
+        };
+        assert_eq!(a.generations, 10);
+        assert_eq!(b.generations, 25);
+
+        let mut original = GaEngine::new(&problem, &config()).unwrap();
+        original.by_ref().take(10).for_each(drop);
+        original.checkpoint();
+        original.by_ref().for_each(drop);
+        assert_eq!(original.into_result().fitness_history, b.fitness_history);
+    }
+
+    #[test]
+    fn test_checkpoint_interval_does_not_change_the_run() {
+        let problem = OneMax { n: 30 };
+        let mut plain = GaEngine::new(&problem, &config()).unwrap();
+        let mut checkpointed =
+            GaEngine::new(&problem, &config().with_checkpoint_interval(3)).unwrap();
+        plain.by_ref().for_each(drop);
+        checkpointed.by_ref().for_each(drop);
+
+        let a = plain.into_result();
+        let b = checkpointed.into_result();
+        assert_eq!(a.fitness_history, b.fitness_history);
+        assert_eq!(a.best.bits, b.best.bits);
+    }
+}
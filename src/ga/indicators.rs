@@ -0,0 +1,509 @@
+//! Quality indicators for comparing Pareto front approximations.
+//!
+//! Every function takes fronts as slices of objective vectors (the format
+//! used by [`non_dominated_sort`](super::multi_objective::non_dominated_sort)
+//! and returned by
+//! [`ParetoArchive::objectives`](super::multi_objective::ParetoArchive::objectives)).
+//! All objectives are **minimized**.
+//!
+//! # Indicators
+//!
+//! - [`hypervolume`]: Exact dominated volume for 1–3 objectives (higher is better)
+//! - [`hypervolume_monte_carlo`]: Sampling estimate for any number of objectives
+//! - [`generational_distance`]: Mean distance from the front to a reference front
+//! - [`igd`]: Inverted generational distance (convergence and coverage)
+//! - [`igd_plus`]: IGD with dominance-compliant distances (Ishibuchi et al.)
+//! - [`spread`]: Generalized spread Δ (uniformity and extent)
+//! - [`spacing`]: Schott's spacing (uniformity only)
+//!
+//! Lower is better for every indicator except the hypervolume.
+//!
+//! # References
+//!
+//! - Zitzler & Thiele (1999), "Multiobjective Evolutionary Algorithms: A
+//!   Comparative Case Study and the Strength Pareto Approach"
+//! - Van Veldhuizen & Lamont (1998), "Evolutionary Computation and
+//!   Convergence to a Pareto Front" (GD)
+//! - Coello Coello & Reyes Sierra (2004), "A Study of the Parallelization of a
+//!   Coevolutionary Multi-objective Evolutionary Algorithm" (IGD)
+//! - Ishibuchi et al. (2015), "Modified Distance Calculation in Generational
+//!   Distance and Inverted Generational Distance" (IGD+)
+//! - Deb et al. (2002), NSGA-II (spread Δ); Zhou et al. (2006) (generalized Δ)
+//! - Schott (1995), "Fault Tolerant Design Using Single and Multicriteria
+//!   Genetic Algorithm Optimization" (spacing)
+
+use rand::Rng;
+
+/// Exact hypervolume dominated by `front` and bounded by `reference`.
+///
+/// Points that do not strictly dominate `reference` in every objective
+/// contribute nothing. Dominated points in `front` are allowed and do not
+/// change the result.
+///
+/// # Complexity
+///
+/// O(n log n) for two objectives, O(n² log n) for three.
+///
+/// # Panics
+///
+/// Panics if `reference` has more than 3 objectives (use
+/// [`hypervolume_monte_carlo`]) or if a point's length differs from
+/// `reference`.
+///
+/// # Example
+///
+/// ```
+/// use u_metaheur::ga::indicators::hypervolume;
+///
+/// let front = vec![vec![1.0, 3.0], vec![2.0, 2.0], vec![3.0, 1.0]];
+/// let hv = hypervolume(&front, &[4.0, 4.0]);
+/// assert!((hv - 6.0).abs() < 1e-12);
+/// ```
+pub fn hypervolume(front: &[Vec<f64>], reference: &[f64]) -> f64 {
+    let m = reference.len();
+    assert!(
+        (1..=3).contains(&m),
+        "exact hypervolume supports 1-3 objectives, got {m}"
+    );
+    let points = dominating_points(front, reference);
+    if points.is_empty() {
+        return 0.0;
+    }
+
+    match m {
+        1 => reference[0] - points.iter().map(|p| p[0]).fold(f64::INFINITY, f64::min),
+        2 => hypervolume_2d(points.iter().map(|p| (p[0], p[1])).collect(), reference),
+        _ => {
+            let mut points = points;
+            points.sort_by(|a, b| a[2].partial_cmp(&b[2]).unwrap_or(std::cmp::Ordering::Equal));
+            let mut volume = 0.0;
+            for k in 0..points.len() {
+                let next = points.get(k + 1).map_or(reference[2], |p| p[2]);
+                let height = next - points[k][2];
+                if height > 0.0 {
+                    let slice = points[..=k].iter().map(|p| (p[0], p[1])).collect();
+                    volume += hypervolume_2d(slice, reference) * height;
+                }
+            }
+            volume
+        }
+    }
+}
+
+/// Monte-Carlo estimate of the hypervolume for any number of objectives.
+///
+/// Samples `samples` points uniformly in the box spanned by the ideal point
+/// of `front` and `reference`, and scales the dominated fraction by the box
+/// volume. The standard error shrinks as `1/√samples`.
+///
+/// # Panics
+///
+/// Panics if `samples` is zero or if a point's length differs from
+/// `reference`.
+///
+/// # Example
+///
+/// ```
+/// use u_metaheur::ga::indicators::hypervolume_monte_carlo;
+/// use u_numflow::random::create_rng;
+///
+/// let front = vec![vec![0.5, 0.5, 0.5, 0.5]];
+/// let mut rng = create_rng(42);
+/// let hv = hypervolume_monte_carlo(&front, &[1.0; 4], 1_000, &mut rng);
+/// assert!((hv - 0.0625).abs() < 1e-12); // the sampling box is the dominated box
+/// ```
+pub fn hypervolume_monte_carlo<R: Rng>(
+    front: &[Vec<f64>],
+    reference: &[f64],
+    samples: usize,
+    rng: &mut R,
+) -> f64 {
+    assert!(samples > 0, "samples must be at least 1");
+    let points = dominating_points(front, reference);
+    if points.is_empty() {
+        return 0.0;
+    }
+
+    let lower: Vec<f64> = (0..reference.len())
+        .map(|j| points.iter().map(|p| p[j]).fold(f64::INFINITY, f64::min))
+        .collect();
+    let box_volume: f64 = lower.iter().zip(reference).map(|(l, r)| r - l).product();
+
+    let mut sample = vec![0.0; reference.len()];
+    let mut hits = 0usize;
+    for _ in 0..samples {
+        for ((s, &l), &r) in sample.iter_mut().zip(&lower).zip(reference) {
+            *s = l + rng.random_range(0.0..1.0) * (r - l);
+        }
+        if points
+            .iter()
+            .any(|p| p.iter().zip(&sample).all(|(pi, si)| pi <= si))
+        {
+            hits += 1;
+        }
+    }
+    box_volume * hits as f64 / samples as f64
+}
+
+/// Generational distance: mean Euclidean distance from each point of `front`
+/// to its nearest point in `reference_front`.
+///
+/// Measures convergence only; a single point on the true front scores 0.
+///
+/// # Panics
+///
+/// Panics if either front is empty.
+///
+/// # Example
+///
+/// ```
+/// use u_metaheur::ga::indicators::generational_distance;
+///
+/// let reference = vec![vec![0.0, 1.0], vec![1.0, 0.0]];
+/// let gd = generational_distance(&[vec![1.0, 1.0]], &reference);
+/// assert!((gd - 1.0).abs() < 1e-12);
+/// ```
+pub fn generational_distance(front: &[Vec<f64>], reference_front: &[Vec<f64>]) -> f64 {
+    mean_min_distance(front, reference_front, euclidean)
+}
+
+/// Inverted generational distance: mean Euclidean distance from each point
+/// of `reference_front` to its nearest point in `front`.
+///
+/// Rewards both convergence and coverage of the reference front.
+///
+/// # Panics
+///
+/// Panics if either front is empty.
+///
+/// # Example
+///
+/// ```
+/// use u_metaheur::ga::indicators::igd;
+///
+/// let reference = vec![vec![0.0, 1.0], vec![1.0, 0.0]];
+/// assert_eq!(igd(&reference, &reference), 0.0);
+/// assert!((igd(&[vec![0.0, 1.0]], &reference) - 2f64.sqrt() / 2.0).abs() < 1e-12);
+/// ```
+pub fn igd(front: &[Vec<f64>], reference_front: &[Vec<f64>]) -> f64 {
+    mean_min_distance(reference_front, front, euclidean)
+}
+
+/// IGD+: like [`igd`], but only counts how far a front point is *worse* than
+/// the reference point in each objective.
+///
+/// Unlike IGD, it is weakly Pareto-compliant: a front that dominates another
+/// never scores worse.
+///
+/// # Panics
+///
+/// Panics if either front is empty.
+///
+/// # Example
+///
+/// ```
+/// use u_metaheur::ga::indicators::igd_plus;
+///
+/// let reference = vec![vec![1.0, 1.0]];
+/// // Points better than the reference are not penalized
+/// assert_eq!(igd_plus(&[vec![0.5, 0.5]], &reference), 0.0);
+/// ```
+pub fn igd_plus(front: &[Vec<f64>], reference_front: &[Vec<f64>]) -> f64 {
+    mean_min_distance(reference_front, front, |z, a| {
+        z.iter()
+            .zip(a)
+            .map(|(zi, ai)| (ai - zi).max(0.0).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    })
+}
+
+/// Generalized spread Δ of `front` relative to the extremes of
+/// `reference_front`.
+///
+/// Combines the distance from the front to each extreme point of the
+/// reference front (one per objective) with the deviation of
+/// nearest-neighbor distances. 0 means a perfectly uniform front that
+/// reaches every extreme.
+///
+/// # Panics
+///
+/// Panics if `front` has fewer than 2 points or `reference_front` is empty.
+///
+/// # Example
+///
+/// ```
+/// use u_metaheur::ga::indicators::spread;
+///
+/// let front = vec![vec![0.0, 1.0], vec![0.5, 0.5], vec![1.0, 0.0]];
+/// assert!(spread(&front, &front) < 1e-12);
+/// ```
+pub fn spread(front: &[Vec<f64>], reference_front: &[Vec<f64>]) -> f64 {
+    assert!(front.len() >= 2, "spread needs at least 2 points");
+    assert!(
+        !reference_front.is_empty(),
+        "reference_front must not be empty"
+    );
+
+    let m = reference_front[0].len();
+    let extreme_sum: f64 = (0..m)
+        .map(|j| {
+            let extreme = reference_front
+                .iter()
+                .min_by(|a, b| a[j].partial_cmp(&b[j]).unwrap_or(std::cmp::Ordering::Equal))
+                .expect("reference_front is non-empty");
+            nearest(extreme, front, euclidean)
+        })
+        .sum();
+
+    let d = nearest_neighbor_distances(front, euclidean);
+    let mean = d.iter().sum::<f64>() / d.len() as f64;
+    let deviation: f64 = d.iter().map(|di| (di - mean).abs()).sum();
+
+    let denominator = extreme_sum + front.len() as f64 * mean;
+    if denominator == 0.0 {
+        return 0.0;
+    }
+    (extreme_sum + deviation) / denominator
+}
+
+/// Schott's spacing: standard deviation of each point's Manhattan distance
+/// to its nearest neighbor in `front`.
+///
+/// 0 means equally spaced points. Needs no reference front.
+///
+/// # Panics
+///
+/// Panics if `front` has fewer than 2 points.
+///
+/// # Example
+///
+/// ```
+/// use u_metaheur::ga::indicators::spacing;
+///
+/// let front = vec![vec![0.0, 1.0], vec![0.5, 0.5], vec![1.0, 0.0]];
+/// assert!(spacing(&front) < 1e-12);
+/// ```
+pub fn spacing(front: &[Vec<f64>]) -> f64 {
+    assert!(front.len() >= 2, "spacing needs at least 2 points");
+    let d = nearest_neighbor_distances(front, |a, b| {
+        a.iter().zip(b).map(|(x, y)| (x - y).abs()).sum()
+    });
+    let mean = d.iter().sum::<f64>() / d.len() as f64;
+    let variance = d.iter().map(|di| (di - mean).powi(2)).sum::<f64>() / (d.len() - 1) as f64;
+    variance.sqrt()
+}
+
+// ---- Helpers ----
+
+/// Points of `front` strictly better than `reference` in every objective.
+fn dominating_points(front: &[Vec<f64>], reference: &[f64]) -> Vec<Vec<f64>> {
+    front
+        .iter()
+        .inspect(|p| {
+            assert_eq!(
+                p.len(),
+                reference.len(),
+                "point and reference lengths differ"
+            )
+        })
+        .filter(|p| p.iter().zip(reference).all(|(pi, ri)| pi < ri))
+        .cloned()
+        .collect()
+}
+
+/// Two-objective hypervolume by a sweep over the first objective.
+fn hypervolume_2d(mut points: Vec<(f64, f64)>, reference: &[f64]) -> f64 {
+    points.sort_by(|a, b| {
+        a.0.partial_cmp(&b.0)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+    });
+    let mut volume = 0.0;
+    let mut ceiling = reference[1];
+    for (x, y) in points {
+        if y < ceiling {
+            volume += (reference[0] - x) * (ceiling - y);
+            ceiling = y;
+        }
+    }
+    volume
+}
+
+fn euclidean(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Distance from `point` to its nearest member of `set`.
+fn nearest(point: &[f64], set: &[Vec<f64>], distance: impl Fn(&[f64], &[f64]) -> f64) -> f64 {
+    set.iter()
+        .map(|q| distance(point, q))
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// Mean over `from` of the distance to the nearest member of `to`.
+fn mean_min_distance(
+    from: &[Vec<f64>],
+    to: &[Vec<f64>],
+    distance: impl Fn(&[f64], &[f64]) -> f64,
+) -> f64 {
+    assert!(
+        !from.is_empty() && !to.is_empty(),
+        "fronts must not be empty"
+    );
+    from.iter().map(|p| nearest(p, to, &distance)).sum::<f64>() / from.len() as f64
+}
+
+/// For each point, the distance to its nearest other point.
+fn nearest_neighbor_distances(
+    front: &[Vec<f64>],
+    distance: impl Fn(&[f64], &[f64]) -> f64,
+) -> Vec<f64> {
+    front
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            front
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, q)| distance(p, q))
+                .fold(f64::INFINITY, f64::min)
+        })
+        .collect()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use u_numflow::random::create_rng;
+
+    fn linear_front(n: usize) -> Vec<Vec<f64>> {
+        (0..n)
+            .map(|i| {
+                let x = i as f64 / (n - 1) as f64;
+                vec![x, 1.0 - x]
+            })
+            .collect()
+    }
+
+    // ---- Hypervolume ----
+
+    #[test]
+    fn test_hypervolume_1d() {
+        assert_eq!(hypervolume(&[vec![2.0], vec![3.0]], &[5.0]), 3.0);
+    }
+
+    #[test]
+    fn test_hypervolume_2d_ignores_dominated_and_outside() {
+        let front = vec![
+            vec![1.0, 3.0],
+            vec![3.0, 1.0],
+            vec![3.5, 3.5], // dominated
+            vec![0.5, 5.0], // outside reference
+        ];
+        // (4-1)*(4-3) + (4-3)*(3-1) = 3 + 2
+        assert!((hypervolume(&front, &[4.0, 4.0]) - 5.0).abs() < 1e-12);
+        assert_eq!(hypervolume(&[vec![5.0, 5.0]], &[4.0, 4.0]), 0.0);
+    }
+
+    #[test]
+    fn test_hypervolume_3d() {
+        // Single point: box volume
+        let hv = hypervolume(&[vec![0.0, 0.0, 0.0]], &[1.0, 2.0, 3.0]);
+        assert!((hv - 6.0).abs() < 1e-12);
+
+        // Two overlapping boxes: 1 + 1 - overlap 0.25
+        let front = vec![vec![0.0, 0.5, 0.0], vec![0.5, 0.0, 0.0]];
+        let hv = hypervolume(&front, &[1.0, 1.0, 1.0]);
+        assert!((hv - 0.75).abs() < 1e-12);
+
+        // Staircase in z
+        let front = vec![vec![0.5, 0.5, 0.0], vec![0.0, 0.0, 0.5]];
+        let hv = hypervolume(&front, &[1.0, 1.0, 1.0]);
+        assert!((hv - (0.25 * 0.5 + 1.0 * 0.5)).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "1-3 objectives")]
+    fn test_hypervolume_rejects_many_objectives() {
+        hypervolume(&[vec![0.0; 4]], &[1.0; 4]);
+    }
+
+    #[test]
+    fn test_hypervolume_monte_carlo_matches_exact() {
+        let front = vec![
+            vec![0.2, 0.6, 0.4],
+            vec![0.6, 0.2, 0.5],
+            vec![0.4, 0.4, 0.1],
+        ];
+        let reference = [1.0, 1.0, 1.0];
+        let exact = hypervolume(&front, &reference);
+        let mut rng = create_rng(42);
+        let estimate = hypervolume_monte_carlo(&front, &reference, 200_000, &mut rng);
+        assert!(
+            (estimate - exact).abs() < 0.01,
+            "exact {exact}, estimate {estimate}"
+        );
+    }
+
+    // ---- Distance indicators ----
+
+    #[test]
+    fn test_gd_and_igd_perfect_front() {
+        let reference = linear_front(11);
+        assert_eq!(generational_distance(&reference, &reference), 0.0);
+        assert_eq!(igd(&reference, &reference), 0.0);
+        assert_eq!(igd_plus(&reference, &reference), 0.0);
+    }
+
+    #[test]
+    fn test_igd_penalizes_poor_coverage_gd_does_not() {
+        let reference = linear_front(11);
+        let corner = vec![vec![0.0, 1.0]];
+        assert_eq!(generational_distance(&corner, &reference), 0.0);
+        assert!(igd(&corner, &reference) > 0.5);
+    }
+
+    #[test]
+    fn test_igd_plus_ignores_improvement_over_reference() {
+        let reference = vec![vec![1.0, 1.0]];
+        let better = vec![vec![0.0, 0.0]];
+        let worse = vec![vec![1.0, 2.0]];
+        // Plain IGD penalizes the dominating point more than the dominated one
+        assert!(igd(&better, &reference) > igd(&worse, &reference));
+        assert_eq!(igd_plus(&better, &reference), 0.0);
+        assert!((igd_plus(&worse, &reference) - 1.0).abs() < 1e-12);
+    }
+
+    // ---- Spread and spacing ----
+
+    #[test]
+    fn test_spread_uniform_vs_clustered() {
+        let reference = linear_front(21);
+        let uniform = linear_front(5);
+        let clustered = vec![
+            vec![0.0, 1.0],
+            vec![0.05, 0.95],
+            vec![0.1, 0.9],
+            vec![0.15, 0.85],
+        ];
+        assert!(spread(&uniform, &reference) < 1e-12);
+        assert!(spread(&clustered, &reference) > 0.5);
+    }
+
+    #[test]
+    fn test_spacing_detects_uneven_gaps() {
+        assert!(spacing(&linear_front(6)) < 1e-12);
+        let uneven = vec![vec![0.0, 1.0], vec![0.1, 0.9], vec![1.0, 0.0]];
+        assert!(spacing(&uneven) > 0.1);
+    }
+}
@@ -0,0 +1,801 @@
+//! Island-model (coarse-grained parallel) GA.
+//!
+//! [`IslandRunner`] evolves several independent sub-populations with the
+//! same generational step as [`GaRunner`](super::GaRunner). Every
+//! `migration_interval` generations, each island sends copies of some
+//! individuals to its neighbors in the [`MigrationTopology`]. Isolation
+//! keeps islands exploring different regions; migration spreads good
+//! building blocks between them.
+//!
+//! # References
+//!
+//! - Whitley, Rana & Heckendorn (1999), "The Island Model Genetic Algorithm:
+//!   On Separability, Population Size and Convergence"
+//! - Cantú-Paz (2000), *Efficient and Accurate Parallel Genetic Algorithms*
+
+use super::adaptive::{merge_usage, OperatorControl};
+use super::config::GaConfig;
+use super::constraint::{feasibility_cmp, ConstraintState};
+use super::restart::restart_population;
+use super::runner::{
+    compute_generation_stats, evaluate_population, find_best, initial_population, next_generation,
+    record_hall_of_fame, update_best, FitnessCache, GaResult, GenerationStats,
+};
+use super::types::{Fitness, GaProblem, Individual};
+use crate::direction::Direction;
+use crate::hall_of_fame::HallOfFame;
+use rand::Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+use u_numflow::random::{create_rng, shuffle};
+
+/// Which islands exchange migrants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MigrationTopology {
+    /// Island `i` sends to island `i + 1` (wrapping around).
+    #[default]
+    Ring,
+
+    /// Every island sends to every other island.
+    FullyConnected,
+
+    /// Each island sends to one uniformly chosen other island, redrawn at
+    /// every migration.
+    Random,
+}
+
+/// Which individuals leave an island.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmigrantPolicy {
+    /// Copies of the island's best individuals.
+    #[default]
+    Best,
+
+    /// Copies of uniformly chosen individuals.
+    Random,
+}
+
+/// Which residents arriving migrants replace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImmigrantPolicy {
+    /// The island's worst individuals.
+    #[default]
+    ReplaceWorst,
+
+    /// Uniformly chosen individuals, never the island's best.
+    ReplaceRandom,
+}
+
+/// Configuration for the island-model GA.
+///
+/// Per-island evolution (population size, operators, selection, elitism)
+/// and global termination (generations, stagnation, time limit, seed) come
+/// from the wrapped [`GaConfig`]; `population_size` is the size of **each**
+/// island.
+/// A [`GaConfig::restart`] on stagnation of the overall best restarts every
+/// island, each keeping its own best individuals.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::ga::{GaConfig, IslandConfig, MigrationTopology};
+///
+/// let config = IslandConfig::default()
+///     .with_ga_config(GaConfig::default().with_population_size(50).with_seed(42))
+///     .with_island_count(8)
+///     .with_migration_interval(20)
+///     .with_migration_size(3)
+///     .with_topology(MigrationTopology::FullyConnected);
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct IslandConfig {
+    /// GA parameters shared by every island.
+    pub ga: GaConfig,
+
+    /// Number of islands.
+    pub island_count: usize,
+
+    /// Generations between migrations.
+    pub migration_interval: usize,
+
+    /// Number of individuals each island sends to each neighbor.
+    pub migration_size: usize,
+
+    /// Which islands exchange migrants.
+    pub topology: MigrationTopology,
+
+    /// Which individuals leave an island.
+    pub emigrant_policy: EmigrantPolicy,
+
+    /// Which residents arriving migrants replace.
+    pub immigrant_policy: ImmigrantPolicy,
+
+    /// Whether to evolve islands concurrently using rayon.
+    ///
+    /// Requires the `parallel` feature; ignored otherwise.
+    pub parallel_islands: bool,
+}
+
+impl Default for IslandConfig {
+    fn default() -> Self {
+        Self {
+            ga: GaConfig::default(),
+            island_count: 4,
+            migration_interval: 10,
+            migration_size: 2,
+            topology: MigrationTopology::default(),
+            emigrant_policy: EmigrantPolicy::default(),
+            immigrant_policy: ImmigrantPolicy::default(),
+            parallel_islands: true,
+        }
+    }
+}
+
+impl IslandConfig {
+    /// Sets the GA parameters shared by every island.
+    pub fn with_ga_config(mut self, ga: GaConfig) -> Self {
+        self.ga = ga;
+        self
+    }
+
+    /// Sets the number of islands.
+    pub fn with_island_count(mut self, n: usize) -> Self {
+        self.island_count = n;
+        self
+    }
+
+    /// Sets the number of generations between migrations.
+    pub fn with_migration_interval(mut self, n: usize) -> Self {
+        self.migration_interval = n;
+        self
+    }
+
+    /// Sets how many individuals each island sends to each neighbor.
+    pub fn with_migration_size(mut self, n: usize) -> Self {
+        self.migration_size = n;
+        self
+    }
+
+    /// Sets the migration topology.
+    pub fn with_topology(mut self, topology: MigrationTopology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Sets the emigrant selection policy.
+    pub fn with_emigrant_policy(mut self, policy: EmigrantPolicy) -> Self {
+        self.emigrant_policy = policy;
+        self
+    }
+
+    /// Sets the immigrant replacement policy.
+    pub fn with_immigrant_policy(mut self, policy: ImmigrantPolicy) -> Self {
+        self.immigrant_policy = policy;
+        self
+    }
+
+    /// Enables or disables concurrent island evolution.
+    pub fn with_parallel_islands(mut self, parallel: bool) -> Self {
+        self.parallel_islands = parallel;
+        self
+    }
+
+    /// Validates the configuration.
+    ///
+    /// Returns `Err` with a description if any parameter is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        self.ga.validate()?;
+        if self.island_count == 0 {
+            return Err("island_count must be at least 1".into());
+        }
+        if self.migration_interval == 0 {
+            return Err("migration_interval must be at least 1".into());
+        }
+        if self.migration_size >= self.ga.population_size {
+            return Err(format!(
+                "migration_size ({}) must be smaller than population_size ({})",
+                self.migration_size, self.ga.population_size
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Result of an island-model GA run.
+#[derive(Debug, Clone)]
+pub struct IslandResult<I: Individual> {
+    /// Overall result across all islands.
+    ///
+    /// `fitness_history` and `generation_stats` describe the union of all
+    /// islands at each generation.
+    pub result: GaResult<I>,
+
+    /// Best fitness of each island at the end of each generation
+    /// (`island_histories[island][generation]`, generation 0 = initial).
+    pub island_histories: Vec<Vec<f64>>,
+
+    /// Number of migration events performed.
+    pub migrations: usize,
+}
+
+/// Executes the island-model GA.
+///
+/// # Usage
+///
+/// ```ignore
+/// let config = IslandConfig::default()
+///     .with_ga_config(GaConfig::default().with_seed(42))
+///     .with_island_count(4);
+/// let result = IslandRunner::run(&problem, &config)?;
+/// println!("Best fitness: {:?}", result.result.best_fitness);
+/// ```
+pub struct IslandRunner;
+
+impl IslandRunner {
+    /// Runs the island-model GA.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid.
+    pub fn run<P: GaProblem>(
+        problem: &P,
+        config: &IslandConfig,
+    ) -> Result<IslandResult<P::Individual>, String> {
+        Self::run_with_cancel(problem, config, None)
+    }
+
+    /// Runs the island-model GA with an optional cancellation token.
+    ///
+    /// The flag is checked once per generation, between island steps.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid.
+    pub fn run_with_cancel<P: GaProblem>(
+        problem: &P,
+        config: &IslandConfig,
+        cancel: Option<Arc<AtomicBool>>,
+    ) -> Result<IslandResult<P::Individual>, String> {
+        config.validate()?;
+        let ga = &config.ga;
+
+        let mut rng = match ga.seed {
+            Some(seed) => create_rng(seed),
+            None => create_rng(rand::random()),
+        };
+
+        // One cache shared by all islands: migrants and converged islands
+        // often carry the same genotypes
+        let cache = FitnessCache::<P::Individual>::new(ga.cache_capacity);
+        let operators = OperatorControl::new(problem, ga)?;
+
+        // Each island owns an RNG so islands can evolve concurrently and
+        // still be reproducible
+        let mut islands: Vec<_> = (0..config.island_count)
+            .map(|_| {
+                let mut island_rng = create_rng(rng.random());
+                let mut population =
+                    initial_population(problem, ga.population_size, Vec::new(), &mut island_rng);
+                evaluate_population(problem, &mut population, ga, &cache);
+                Island {
+                    population,
+                    rng: island_rng,
+                    constraints: ConstraintState::new(ga.constraint_handling, ga.direction),
+                    operators: operators.clone(),
+                }
+            })
+            .collect();
+
+        let mut island_histories: Vec<Vec<f64>> = islands
+            .iter()
+            .map(|island| {
+                let best = find_best(&island.population, ga.direction);
+                vec![best.fitness().to_f64()]
+            })
+            .collect();
+        let mut best = overall_best(&islands, ga.direction).clone();
+        let mut hall_of_fame = HallOfFame::new(ga.hall_of_fame, ga.hall_of_fame_distance);
+        for island in &islands {
+            record_hall_of_fame(problem, &mut hall_of_fame, &island.population, ga.direction);
+        }
+
+        let mut fitness_history = Vec::with_capacity(ga.max_generations + 1);
+        fitness_history.push(best.fitness().to_f64());
+        let mut generation_stats = Vec::with_capacity(ga.max_generations + 1);
+        generation_stats.push(union_stats(problem, &islands, 0, ga));
+
+        let mut migrations = 0;
+        let mut generations = 0;
+        let mut stagnation_counter = 0usize;
+        let mut restarts = 0;
+        let mut stagnated = false;
+        let mut cancelled = false;
+        #[allow(unused_mut)]
+        let mut timed_out = false;
+        #[cfg(not(target_arch = "wasm32"))]
+        let start_time = Instant::now();
+
+        for gen in 0..ga.max_generations {
+            if let Some(ref flag) = cancel {
+                if flag.load(Ordering::Relaxed) {
+                    cancelled = true;
+                    break;
+                }
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(limit_ms) = ga.time_limit_ms {
+                if start_time.elapsed().as_millis() as u64 >= limit_ms {
+                    timed_out = true;
+                    break;
+                }
+            }
+
+            step_islands(problem, &mut islands, ga, &cache, config.parallel_islands);
+
+            if config.island_count > 1 && (gen + 1) % config.migration_interval == 0 {
+                migrate(&mut islands, config, ga.direction, &mut rng);
+                migrations += 1;
+            }
+
+            // Update best
+            for (island, history) in islands.iter().zip(&mut island_histories) {
+                let island_best = find_best(&island.population, ga.direction);
+                history.push(island_best.fitness().to_f64());
+                record_hall_of_fame(problem, &mut hall_of_fame, &island.population, ga.direction);
+            }
+            if update_best(
+                &mut best,
+                overall_best(&islands, ga.direction),
+                ga.convergence_threshold,
+                ga.direction,
+            ) {
+                stagnation_counter = 0;
+            } else {
+                stagnation_counter += 1;
+            }
+
+            generations = gen + 1;
+            fitness_history.push(best.fitness().to_f64());
+            generation_stats.push(union_stats(problem, &islands, generations, ga));
+
+            problem.on_generation(generations, best.fitness());
+
+            if ga.stagnation_limit > 0 && stagnation_counter >= ga.stagnation_limit {
+                match ga.restart {
+                    Some(restart)
+                        if restarts < restart.max_restarts && generations < ga.max_generations =>
+                    {
+                        for island in &mut islands {
+                            restart_population(
+                                problem,
+                                &mut island.population,
+                                &restart,
+                                ga,
+                                &cache,
+                                &mut island.rng,
+                            );
+                            record_hall_of_fame(
+                                problem,
+                                &mut hall_of_fame,
+                                &island.population,
+                                ga.direction,
+                            );
+                        }
+                        restarts += 1;
+                        stagnation_counter = 0;
+                        update_best(
+                            &mut best,
+                            overall_best(&islands, ga.direction),
+                            ga.convergence_threshold,
+                            ga.direction,
+                        );
+                    }
+                    _ => {
+                        stagnated = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(IslandResult {
+            result: GaResult {
+                best_fitness: best.fitness(),
+                best,
+                hall_of_fame: hall_of_fame.into_entries(),
+                generations,
+                restarts,
+                stagnated,
+                cancelled,
+                timed_out,
+                fitness_history,
+                generation_stats,
+                cache_hits: cache.hits(),
+                cache_misses: cache.misses(),
+                crossover_usage: merge_usage(
+                    &islands
+                        .iter()
+                        .map(|island| island.operators.crossover_usage())
+                        .collect::<Vec<_>>(),
+                ),
+                mutation_usage: merge_usage(
+                    &islands
+                        .iter()
+                        .map(|island| island.operators.mutation_usage())
+                        .collect::<Vec<_>>(),
+                ),
+            },
+            island_histories,
+            migrations,
+        })
+    }
+}
+
+/// One sub-population with its own RNG, constraint-handler and adaptive
+/// operator state.
+struct Island<I, R> {
+    population: Vec<I>,
+    rng: R,
+    constraints: ConstraintState,
+    operators: OperatorControl,
+}
+
+/// Best individual across all islands.
+fn overall_best<I: Individual, R>(islands: &[Island<I, R>], direction: Direction) -> &I {
+    islands
+        .iter()
+        .map(|island| find_best(&island.population, direction))
+        .min_by(|a, b| feasibility_cmp(*a, *b, 0.0, direction))
+        .expect("island_count is at least 1")
+}
+
+/// Advances every island by one generation.
+fn step_islands<P: GaProblem, R: Rng + Send>(
+    problem: &P,
+    islands: &mut [Island<P::Individual, R>],
+    config: &GaConfig,
+    cache: &FitnessCache<P::Individual>,
+    parallel: bool,
+) {
+    #[cfg(feature = "parallel")]
+    if parallel {
+        islands.par_iter_mut().for_each(|island| {
+            next_generation(
+                problem,
+                &mut island.population,
+                config,
+                &mut island.constraints,
+                &mut island.operators,
+                cache,
+                &mut island.rng,
+            );
+        });
+        return;
+    }
+    let _ = parallel;
+    for island in islands.iter_mut() {
+        next_generation(
+            problem,
+            &mut island.population,
+            config,
+            &mut island.constraints,
+            &mut island.operators,
+            cache,
+            &mut island.rng,
+        );
+    }
+}
+
+/// Sends emigrants along the topology and inserts them into their
+/// destination islands.
+fn migrate<I: Individual, S, R: Rng>(
+    islands: &mut [Island<I, S>],
+    config: &IslandConfig,
+    direction: Direction,
+    rng: &mut R,
+) {
+    let n = islands.len();
+    let k = config.migration_size;
+
+    let emigrants: Vec<Vec<I>> = islands
+        .iter()
+        .map(|island| {
+            select_emigrants(
+                &island.population,
+                k,
+                config.emigrant_policy,
+                direction,
+                rng,
+            )
+        })
+        .collect();
+
+    let mut arrivals: Vec<Vec<I>> = vec![Vec::new(); n];
+    for (source, group) in emigrants.into_iter().enumerate() {
+        match config.topology {
+            MigrationTopology::Ring => arrivals[(source + 1) % n].extend(group),
+            MigrationTopology::FullyConnected => {
+                for (dest, arrived) in arrivals.iter_mut().enumerate() {
+                    if dest != source {
+                        arrived.extend(group.iter().cloned());
+                    }
+                }
+            }
+            MigrationTopology::Random => {
+                let offset = rng.random_range(1..n);
+                arrivals[(source + offset) % n].extend(group);
+            }
+        }
+    }
+
+    for (island, immigrants) in islands.iter_mut().zip(arrivals) {
+        insert_immigrants(
+            &mut island.population,
+            immigrants,
+            config.immigrant_policy,
+            direction,
+            rng,
+        );
+    }
+}
+
+fn select_emigrants<I: Individual, R: Rng>(
+    population: &[I],
+    k: usize,
+    policy: EmigrantPolicy,
+    direction: Direction,
+    rng: &mut R,
+) -> Vec<I> {
+    let mut order: Vec<usize> = (0..population.len()).collect();
+    match policy {
+        EmigrantPolicy::Best => sort_by_fitness(population, &mut order, direction),
+        EmigrantPolicy::Random => shuffle(&mut order, rng),
+    }
+    order[..k].iter().map(|&i| population[i].clone()).collect()
+}
+
+/// Replaces residents with `immigrants`. At most `population.len() - 1`
+/// residents are replaced, preferring the best immigrants, so the island's
+/// best resident always survives.
+fn insert_immigrants<I: Individual, R: Rng>(
+    population: &mut [I],
+    mut immigrants: Vec<I>,
+    policy: ImmigrantPolicy,
+    direction: Direction,
+    rng: &mut R,
+) {
+    if immigrants.is_empty() {
+        return;
+    }
+    immigrants.sort_by(|a, b| feasibility_cmp(a, b, 0.0, direction));
+    immigrants.truncate(population.len() - 1);
+
+    let mut order: Vec<usize> = (0..population.len()).collect();
+    sort_by_fitness(population, &mut order, direction);
+    let mut targets = order.split_off(1); // never the island's best
+    match policy {
+        ImmigrantPolicy::ReplaceWorst => targets.reverse(),
+        ImmigrantPolicy::ReplaceRandom => shuffle(&mut targets, rng),
+    }
+
+    for (target, immigrant) in targets.into_iter().zip(immigrants) {
+        population[target] = immigrant;
+    }
+}
+
+/// Sorts indices best first (feasible before infeasible).
+fn sort_by_fitness<I: Individual>(population: &[I], order: &mut [usize], direction: Direction) {
+    order.sort_by(|&a, &b| feasibility_cmp(&population[a], &population[b], 0.0, direction));
+}
+
+/// Statistics over the union of all island populations.
+fn union_stats<P: GaProblem, S>(
+    problem: &P,
+    islands: &[Island<P::Individual, S>],
+    generation: usize,
+    config: &GaConfig,
+) -> GenerationStats {
+    let all: Vec<&P::Individual> = islands
+        .iter()
+        .flat_map(|island| &island.population)
+        .collect();
+    compute_generation_stats(problem, &all, generation, config)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ---- OneMax: minimize the number of zero bits ----
+
+    #[derive(Clone, Debug)]
+    struct Bits {
+        bits: Vec<bool>,
+        fitness: f64,
+    }
+
+    impl Individual for Bits {
+        type Fitness = f64;
+        fn fitness(&self) -> f64 {
+            self.fitness
+        }
+        fn set_fitness(&mut self, f: f64) {
+            self.fitness = f;
+        }
+    }
+
+    struct OneMax {
+        n: usize,
+    }
+
+    impl GaProblem for OneMax {
+        type Individual = Bits;
+
+        fn create_individual<R: Rng>(&self, rng: &mut R) -> Bits {
+            Bits {
+                bits: (0..self.n).map(|_| rng.random_bool(0.5)).collect(),
+                fitness: f64::INFINITY,
+            }
+        }
+
+        fn evaluate(&self, ind: &Bits) -> f64 {
+            ind.bits.iter().filter(|&&b| !b).count() as f64
+        }
+
+        fn crossover<R: Rng>(&self, p1: &Bits, p2: &Bits, rng: &mut R) -> Vec<Bits> {
+            let bits = p1
+                .bits
+                .iter()
+                .zip(&p2.bits)
+                .map(|(&a, &b)| if rng.random_bool(0.5) { a } else { b })
+                .collect();
+            vec![Bits {
+                bits,
+                fitness: f64::INFINITY,
+            }]
+        }
+
+        fn mutate<R: Rng>(&self, ind: &mut Bits, rng: &mut R) {
+            let i = rng.random_range(0..self.n);
+            ind.bits[i] = !ind.bits[i];
+        }
+    }
+
+    fn bits(fitness: f64) -> Bits {
+        Bits {
+            bits: Vec::new(),
+            fitness,
+        }
+    }
+
+    fn config(topology: MigrationTopology) -> IslandConfig {
+        IslandConfig::default()
+            .with_ga_config(
+                GaConfig::default()
+                    .with_population_size(30)
+                    .with_max_generations(100)
+                    .with_mutation_rate(0.5)
+                    .with_seed(42),
+            )
+            .with_island_count(4)
+            .with_migration_interval(5)
+            .with_topology(topology)
+    }
+
+    #[test]
+    fn test_onemax_all_topologies() {
+        for topology in [
+            MigrationTopology::Ring,
+            MigrationTopology::FullyConnected,
+            MigrationTopology::Random,
+        ] {
+            let result = IslandRunner::run(&OneMax { n: 40 }, &config(topology)).unwrap();
+
+            assert_eq!(result.result.best_fitness, 0.0, "{topology:?}");
+            assert_eq!(result.island_histories.len(), 4);
+            assert_eq!(result.migrations, result.result.generations / 5);
+            for history in &result.island_histories {
+                assert_eq!(history.len(), result.result.generations + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_deterministic_with_parallel_islands() {
+        let sequential = config(MigrationTopology::Random).with_parallel_islands(false);
+        let parallel = config(MigrationTopology::Random).with_parallel_islands(true);
+
+        let a = IslandRunner::run(&OneMax { n: 30 }, &sequential).unwrap();
+        let b = IslandRunner::run(&OneMax { n: 30 }, &parallel).unwrap();
+
+        assert_eq!(a.island_histories, b.island_histories);
+    }
+
+    #[test]
+    fn test_single_island_never_migrates() {
+        let config = config(MigrationTopology::Ring).with_island_count(1);
+        let result = IslandRunner::run(&OneMax { n: 20 }, &config).unwrap();
+        assert_eq!(result.migrations, 0);
+        assert_eq!(result.island_histories.len(), 1);
+    }
+
+    #[test]
+    fn test_emigrants_best() {
+        let population: Vec<Bits> = [5.0, 1.0, 3.0, 2.0].map(bits).to_vec();
+        let mut rng = create_rng(1);
+        let out = select_emigrants(
+            &population,
+            2,
+            EmigrantPolicy::Best,
+            Direction::Minimize,
+            &mut rng,
+        );
+        let f: Vec<f64> = out.iter().map(|b| b.fitness).collect();
+        assert_eq!(f, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_immigrants_replace_worst_and_keep_best() {
+        let mut population: Vec<Bits> = [5.0, 1.0, 3.0, 2.0].map(bits).to_vec();
+        let mut rng = create_rng(1);
+        insert_immigrants(
+            &mut population,
+            vec![bits(0.5), bits(0.7)],
+            ImmigrantPolicy::ReplaceWorst,
+            Direction::Minimize,
+            &mut rng,
+        );
+        let mut f: Vec<f64> = population.iter().map(|b| b.fitness).collect();
+        f.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(f, vec![0.5, 0.7, 1.0, 2.0]);
+
+        // Flood: more immigrants than residents still keeps the best resident
+        let mut population: Vec<Bits> = [5.0, 1.0, 3.0].map(bits).to_vec();
+        insert_immigrants(
+            &mut population,
+            vec![bits(9.0); 5],
+            ImmigrantPolicy::ReplaceRandom,
+            Direction::Minimize,
+            &mut rng,
+        );
+        assert!(population.iter().any(|b| b.fitness == 1.0));
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(IslandConfig::default().validate().is_ok());
+        assert!(IslandConfig::default()
+            .with_island_count(0)
+            .validate()
+            .is_err());
+        assert!(IslandConfig::default()
+            .with_migration_interval(0)
+            .validate()
+            .is_err());
+        assert!(IslandConfig::default()
+            .with_migration_size(100)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_cancellation() {
+        let cancel = Arc::new(AtomicBool::new(true));
+        let result = IslandRunner::run_with_cancel(
+            &OneMax { n: 20 },
+            &config(MigrationTopology::Ring),
+            Some(cancel),
+        )
+        .unwrap();
+        assert!(result.result.cancelled);
+        assert_eq!(result.result.generations, 0);
+    }
+}
@@ -0,0 +1,61 @@
+//! Memetic GA: local search on offspring.
+//!
+//! With [`GaConfig::local_search`](super::GaConfig::local_search) set, a
+//! random fraction of each generation's evaluated offspring is passed to
+//! [`GaProblem::improve`](super::GaProblem::improve), e.g. a 2-opt or
+//! insertion local search. The improved result is kept only if it is not
+//! worse than the original. How it is kept depends on the [`LearningMode`].
+//!
+//! # References
+//!
+//! - Moscato (1989), "On Evolution, Search, Optimization, Genetic Algorithms
+//!   and Martial Arts: Towards Memetic Algorithms"
+//! - Whitley, Gordon & Mathias (1994), "Lamarckian Evolution, the Baldwin
+//!   Effect and Function Optimization"
+
+/// How the result of local search enters the population.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LearningMode {
+    /// The improved genotype replaces the offspring.
+    #[default]
+    Lamarckian,
+
+    /// The offspring keeps its genotype but takes the improved fitness
+    /// and constraint violation.
+    Baldwinian,
+}
+
+/// Local search settings for a memetic GA.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::ga::{GaConfig, LearningMode, LocalSearch};
+///
+/// let config = GaConfig::default().with_local_search(LocalSearch {
+///     rate: 0.2,
+///     mode: LearningMode::Baldwinian,
+/// });
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalSearch {
+    /// Probability that an offspring is improved, in `(0, 1]`.
+    pub rate: f64,
+    /// How improvements are kept.
+    pub mode: LearningMode,
+}
+
+impl LocalSearch {
+    pub(super) fn validate(&self) -> Result<(), String> {
+        if !(self.rate > 0.0 && self.rate <= 1.0) {
+            return Err(format!(
+                "local search rate must be in (0, 1], got {}",
+                self.rate
+            ));
+        }
+        Ok(())
+    }
+}
@@ -8,17 +8,36 @@
 //!
 //! - [`Individual`]: A candidate solution with associated fitness type
 //! - [`GaProblem`]: Problem definition — initialization, evaluation, operators
+//! - [`MultiObjectiveProblem`]: Problem definition with a vector of objectives
 //!
 //! # Key Types
 //!
 //! - [`GaConfig`]: Algorithm parameters (population size, selection, presets)
+//! - [`RateControl`], [`OneFifthRule`], [`OperatorSelection`]: Adaptive operator
+//!   rates, mutation strength and operator choice
+//! - [`LocalSearch`]: Lamarckian or Baldwinian local search on offspring (memetic GA)
+//! - [`Niching`]: Fitness sharing, deterministic crowding and restricted tournament
+//!   selection for population diversity
+//! - [`Restart`]: Restart the population on stagnation instead of stopping
+//! - [`Lexicographic`], [`FeasibilityFirst`]: Built-in fitness types for
+//!   hierarchical objectives
 //! - [`GaRunner`]: Executes the evolutionary loop
+//! - [`GaEngine`]: Step-wise run that exposes the population between generations
 //! - [`GaResult`]: Final optimization result with statistics
+//! - [`GaState`]: Checkpoint of a run, resumable with [`GaRunner::resume_from`]
+//! - [`IslandRunner`]: Island-model GA with periodic migration between sub-populations
+//! - [`Nsga2Runner`]: NSGA-II loop for a [`MultiObjectiveProblem`]
+//! - [`Nsga3Runner`]: NSGA-III reference-point survival for many objectives
+//! - [`MoeadRunner`]: MOEA/D decomposition into scalar subproblems
 //!
 //! # Submodules
 //!
-//! - [`operators`]: Generic permutation crossover (OX, PMX) and mutation operators
-//! - [`multi_objective`]: Pareto non-dominated sorting and crowding distance (NSGA-II utilities)
+//! - [`operators`]: Generic permutation crossover (OX, PMX, CX, ERX, PBX, OBX, UPMX) and
+//!   mutation operators; [`operators::real`], [`operators::binary`] and
+//!   [`operators::integer`] for other encodings
+//! - [`multi_objective`]: Pareto non-dominated sorting, crowding distance, reference points
+//!   and a bounded Pareto archive
+//! - [`indicators`]: Hypervolume, GD, IGD/IGD+, spread and spacing
 //!
 //! # References
 //!
@@ -26,15 +45,46 @@
 //! - Goldberg (1989), *Genetic Algorithms in Search, Optimization, and Machine Learning*
 //! - De Jong (2006), *Evolutionary Computation: A Unified Approach*
 //! - Deb et al. (2002), *A Fast and Elitist Multiobjective GA: NSGA-II*
+//! - Deb & Jain (2014), *An Evolutionary Many-Objective Optimization Algorithm
+//!   Using Reference-Point-Based Nondominated Sorting Approach* (NSGA-III)
+//! - Zhang & Li (2007), *MOEA/D: A Multiobjective Evolutionary Algorithm Based
+//!   on Decomposition*
 
+mod adaptive;
 mod config;
+mod constraint;
+mod engine;
+pub mod indicators;
+mod island;
+mod memetic;
+mod moead;
 pub mod multi_objective;
+mod niching;
+mod nsga2;
+mod nsga3;
 pub mod operators;
+mod replacement;
+mod restart;
 mod runner;
 mod selection;
 mod types;
 
+pub use adaptive::{OneFifthRule, OperatorSelection, OperatorUsage, RateControl};
 pub use config::GaConfig;
+pub use constraint::ConstraintHandling;
+pub use engine::{GaEngine, GaState};
+pub use island::{
+    EmigrantPolicy, ImmigrantPolicy, IslandConfig, IslandResult, IslandRunner, MigrationTopology,
+};
+pub use memetic::{LearningMode, LocalSearch};
+pub use moead::{Decomposition, MoeadConfig, MoeadResult, MoeadRunner};
+pub use niching::Niching;
+pub use nsga2::{Nsga2Config, Nsga2Result, Nsga2Runner, ParetoSolution};
+pub use nsga3::{Nsga3Config, Nsga3Runner};
+pub use replacement::{Replacement, SteadyStatePolicy};
+pub use restart::{Restart, RestartMode};
 pub use runner::{GaResult, GaRunner, GenerationStats};
-pub use selection::Selection;
-pub use types::{Fitness, GaProblem, Individual};
+pub use selection::{Selection, SelectionContext};
+pub use types::{
+    FeasibilityFirst, Fitness, GaProblem, Individual, Lexicographic, MultiObjectiveProblem,
+};
@@ -0,0 +1,704 @@
+//! MOEA/D: multi-objective optimization by decomposition.
+//!
+//! [`MoeadRunner`] decomposes a [`MultiObjectiveProblem`] into one scalar
+//! subproblem per weight vector and optimizes them simultaneously. Each
+//! subproblem mates with its `T` closest neighbors (in weight space) and
+//! offspring replace neighboring solutions whose scalarized value they
+//! improve. Variation reuses the problem's own
+//! [`crossover`](MultiObjectiveProblem::crossover) and
+//! [`mutate`](MultiObjectiveProblem::mutate).
+//!
+//! # References
+//!
+//! - Zhang & Li (2007), "MOEA/D: A Multiobjective Evolutionary Algorithm Based
+//!   on Decomposition", IEEE Trans. Evol. Comput. 11(6), 712-731
+//! - Li & Zhang (2009), "Multiobjective Optimization Problems With Complicated
+//!   Pareto Sets, MOEA/D and NSGA-II" (neighborhood probability δ, limit nr)
+
+use super::multi_objective::{das_dennis_points, non_dominated_sort};
+use super::nsga2::ParetoSolution;
+use super::types::MultiObjectiveProblem;
+use rand::Rng;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+use u_numflow::random::{create_rng, shuffle};
+
+/// Scalarizing function used to turn an objective vector into a subproblem
+/// value. All variants are minimized.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Decomposition {
+    /// Weighted sum: `g = Σ wᵢ·fᵢ`.
+    ///
+    /// Cheap, but only reaches convex parts of the Pareto front.
+    WeightedSum,
+
+    /// Weighted Tchebycheff: `g = maxᵢ wᵢ·|fᵢ − zᵢ*|`.
+    ///
+    /// Reaches non-convex fronts. Zero weights are treated as `1e-6`.
+    #[default]
+    Tchebycheff,
+
+    /// Penalty-based boundary intersection: `g = d₁ + θ·d₂`, where `d₁` is the
+    /// distance along the weight direction from the ideal point and `d₂` the
+    /// perpendicular distance to it.
+    Pbi {
+        /// Penalty for deviating from the weight direction. Typically 5.0.
+        theta: f64,
+    },
+}
+
+impl Decomposition {
+    /// Scalarizes `objectives` for the subproblem with `weight`, relative to
+    /// the ideal point `ideal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use u_metaheur::ga::Decomposition;
+    ///
+    /// let g = Decomposition::Tchebycheff.scalarize(&[3.0, 1.0], &[0.5, 0.5], &[1.0, 0.0]);
+    /// assert!((g - 1.0).abs() < 1e-12); // max(0.5·2, 0.5·1)
+    /// ```
+    pub fn scalarize(&self, objectives: &[f64], weight: &[f64], ideal: &[f64]) -> f64 {
+        match *self {
+            Decomposition::WeightedSum => objectives.iter().zip(weight).map(|(f, w)| f * w).sum(),
+            Decomposition::Tchebycheff => objectives
+                .iter()
+                .zip(weight)
+                .zip(ideal)
+                .map(|((f, &w), z)| w.max(1e-6) * (f - z).abs())
+                .fold(f64::NEG_INFINITY, f64::max),
+            Decomposition::Pbi { theta } => {
+                let norm = weight.iter().map(|w| w * w).sum::<f64>().sqrt();
+                let diff: Vec<f64> = objectives.iter().zip(ideal).map(|(f, z)| f - z).collect();
+                let d1 = diff
+                    .iter()
+                    .zip(weight)
+                    .map(|(d, w)| d * w)
+                    .sum::<f64>()
+                    .abs()
+                    / norm;
+                let d2 = diff
+                    .iter()
+                    .zip(weight)
+                    .map(|(d, w)| (d - d1 * w / norm).powi(2))
+                    .sum::<f64>()
+                    .sqrt();
+                d1 + theta * d2
+            }
+        }
+    }
+}
+
+/// Configuration for MOEA/D.
+///
+/// The number of subproblems (and thus the population size) equals the
+/// number of weight vectors: Das–Dennis points from
+/// [`divisions`](Self::divisions) unless [`weights`](Self::weights) is set.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::ga::{Decomposition, MoeadConfig};
+///
+/// let config = MoeadConfig::default()
+///     .with_divisions(99) // 100 subproblems for 2 objectives
+///     .with_neighborhood_size(20)
+///     .with_decomposition(Decomposition::Pbi { theta: 5.0 })
+///     .with_seed(42);
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MoeadConfig {
+    /// Divisions per objective axis for Das–Dennis weight vectors.
+    ///
+    /// Ignored when [`weights`](Self::weights) is set.
+    pub divisions: usize,
+
+    /// User-supplied weight vectors (one per subproblem). `None` generates
+    /// Das–Dennis points from `divisions`.
+    pub weights: Option<Vec<Vec<f64>>>,
+
+    /// Scalarizing function.
+    pub decomposition: Decomposition,
+
+    /// Number of closest weight vectors forming each neighborhood (T).
+    ///
+    /// Clamped to the number of subproblems at run time.
+    pub neighborhood_size: usize,
+
+    /// Probability of mating within the neighborhood rather than the whole
+    /// population (δ).
+    pub neighbor_mating_prob: f64,
+
+    /// Maximum number of solutions one offspring may replace (nr).
+    pub max_replacements: usize,
+
+    /// Maximum number of generations (one pass over all subproblems each).
+    pub max_generations: usize,
+
+    /// Probability of applying crossover to a pair of parents (0.0–1.0).
+    pub crossover_rate: f64,
+
+    /// Probability of applying mutation to an offspring (0.0–1.0).
+    pub mutation_rate: f64,
+
+    /// Random seed for reproducibility.
+    ///
+    /// `None` uses a random seed.
+    pub seed: Option<u64>,
+
+    /// Optional wall-clock time limit in milliseconds.
+    pub time_limit_ms: Option<u64>,
+}
+
+impl Default for MoeadConfig {
+    fn default() -> Self {
+        Self {
+            divisions: 99,
+            weights: None,
+            decomposition: Decomposition::default(),
+            neighborhood_size: 20,
+            neighbor_mating_prob: 0.9,
+            max_replacements: 2,
+            max_generations: 250,
+            crossover_rate: 0.9,
+            mutation_rate: 0.1,
+            seed: None,
+            time_limit_ms: None,
+        }
+    }
+}
+
+impl MoeadConfig {
+    /// Sets the number of Das–Dennis divisions.
+    pub fn with_divisions(mut self, divisions: usize) -> Self {
+        self.divisions = divisions;
+        self
+    }
+
+    /// Uses the given weight vectors instead of Das–Dennis points.
+    pub fn with_weights(mut self, weights: Vec<Vec<f64>>) -> Self {
+        self.weights = Some(weights);
+        self
+    }
+
+    /// Sets the scalarizing function.
+    pub fn with_decomposition(mut self, decomposition: Decomposition) -> Self {
+        self.decomposition = decomposition;
+        self
+    }
+
+    /// Sets the neighborhood size (T).
+    pub fn with_neighborhood_size(mut self, t: usize) -> Self {
+        self.neighborhood_size = t;
+        self
+    }
+
+    /// Sets the neighborhood mating probability (δ).
+    pub fn with_neighbor_mating_prob(mut self, p: f64) -> Self {
+        self.neighbor_mating_prob = p.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the replacement limit (nr).
+    pub fn with_max_replacements(mut self, nr: usize) -> Self {
+        self.max_replacements = nr;
+        self
+    }
+
+    /// Sets the maximum number of generations.
+    pub fn with_max_generations(mut self, n: usize) -> Self {
+        self.max_generations = n;
+        self
+    }
+
+    /// Sets the crossover rate.
+    pub fn with_crossover_rate(mut self, rate: f64) -> Self {
+        self.crossover_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the mutation rate.
+    pub fn with_mutation_rate(mut self, rate: f64) -> Self {
+        self.mutation_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the random seed for reproducibility.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the wall-clock time limit in milliseconds.
+    pub fn with_time_limit_ms(mut self, ms: u64) -> Self {
+        self.time_limit_ms = Some(ms);
+        self
+    }
+
+    /// Validates the configuration.
+    ///
+    /// Returns `Err` with a description if any parameter is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        match &self.weights {
+            None if self.divisions == 0 => return Err("divisions must be at least 1".into()),
+            Some(w) if w.len() < 2 => return Err("at least 2 weight vectors required".into()),
+            Some(w) if w.iter().flatten().any(|&v| v < 0.0) => {
+                return Err("weights must be non-negative".into());
+            }
+            _ => {}
+        }
+        if self.neighborhood_size < 2 {
+            return Err("neighborhood_size must be at least 2".into());
+        }
+        if self.max_replacements == 0 {
+            return Err("max_replacements must be at least 1".into());
+        }
+        if self.max_generations == 0 {
+            return Err("max_generations must be at least 1".into());
+        }
+        if let Decomposition::Pbi { theta } = self.decomposition {
+            if theta < 0.0 {
+                return Err(format!("pbi theta must be non-negative, got {theta}"));
+            }
+        }
+        if self.time_limit_ms == Some(0) {
+            return Err("time_limit_ms must be positive or None".into());
+        }
+        Ok(())
+    }
+}
+
+/// Result of a MOEA/D run.
+#[derive(Debug, Clone)]
+pub struct MoeadResult<S> {
+    /// Non-dominated solutions among the final subproblem solutions.
+    pub pareto_front: Vec<ParetoSolution<S>>,
+
+    /// Final solution of each subproblem, aligned with `weights`.
+    pub population: Vec<ParetoSolution<S>>,
+
+    /// Weight vector of each subproblem.
+    pub weights: Vec<Vec<f64>>,
+
+    /// Best value found for each objective (the ideal point z*).
+    pub ideal_point: Vec<f64>,
+
+    /// Total number of generations executed.
+    pub generations: usize,
+
+    /// Whether the run was cancelled externally.
+    pub cancelled: bool,
+
+    /// Whether the run was stopped due to the wall-clock time limit.
+    pub timed_out: bool,
+}
+
+/// Executes MOEA/D.
+///
+/// # Usage
+///
+/// ```ignore
+/// let config = MoeadConfig::default().with_divisions(99).with_seed(42);
+/// let result = MoeadRunner::run(&problem, &config)?;
+/// ```
+pub struct MoeadRunner;
+
+impl MoeadRunner {
+    /// Runs MOEA/D.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid, the weight vectors
+    /// do not match the objective count, or the problem returns objective
+    /// vectors of the wrong length.
+    pub fn run<P: MultiObjectiveProblem>(
+        problem: &P,
+        config: &MoeadConfig,
+    ) -> Result<MoeadResult<P::Solution>, String> {
+        Self::run_with_cancel(problem, config, None)
+    }
+
+    /// Runs MOEA/D with an optional cancellation token.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid, the weight vectors
+    /// do not match the objective count, or the problem returns objective
+    /// vectors of the wrong length.
+    pub fn run_with_cancel<P: MultiObjectiveProblem>(
+        problem: &P,
+        config: &MoeadConfig,
+        cancel: Option<Arc<AtomicBool>>,
+    ) -> Result<MoeadResult<P::Solution>, String> {
+        config.validate()?;
+        let m = problem.objective_count();
+        if m == 0 {
+            return Err("objective_count must be at least 1".into());
+        }
+
+        let weights = match &config.weights {
+            Some(w) => w.clone(),
+            None => das_dennis_points(m, config.divisions),
+        };
+        if weights.iter().any(|w| w.len() != m) {
+            return Err(format!("weight vectors must have {m} coordinates"));
+        }
+        let n = weights.len();
+        if n < 2 {
+            return Err("at least 2 subproblems required; increase divisions".into());
+        }
+        let neighborhoods = neighborhoods(&weights, config.neighborhood_size.min(n));
+
+        let mut rng = match config.seed {
+            Some(seed) => create_rng(seed),
+            None => create_rng(rand::random()),
+        };
+
+        // Initialize one solution per subproblem
+        let mut population: Vec<P::Solution> =
+            (0..n).map(|_| problem.create_solution(&mut rng)).collect();
+        let mut objectives = Vec::with_capacity(n);
+        for s in &population {
+            objectives.push(checked_evaluate(problem, s, m)?);
+        }
+        let mut ideal = vec![f64::INFINITY; m];
+        for f in &objectives {
+            update_ideal(&mut ideal, f);
+        }
+
+        let mut generations = 0;
+        let mut cancelled = false;
+        #[allow(unused_mut)]
+        let mut timed_out = false;
+        #[cfg(not(target_arch = "wasm32"))]
+        let start_time = Instant::now();
+        let all: Vec<usize> = (0..n).collect();
+        let mut order: Vec<usize> = (0..n).collect();
+
+        for _gen in 0..config.max_generations {
+            if let Some(ref flag) = cancel {
+                if flag.load(Ordering::Relaxed) {
+                    cancelled = true;
+                    break;
+                }
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(limit_ms) = config.time_limit_ms {
+                if start_time.elapsed().as_millis() as u64 >= limit_ms {
+                    timed_out = true;
+                    break;
+                }
+            }
+
+            shuffle(&mut order, &mut rng);
+            for &i in &order {
+                // Mating pool: neighborhood with probability δ, else everyone
+                let pool = if rng.random_range(0.0..1.0) < config.neighbor_mating_prob {
+                    &neighborhoods[i]
+                } else {
+                    &all
+                };
+
+                let a = pool[rng.random_range(0..pool.len())];
+                let mut b = pool[rng.random_range(0..pool.len())];
+                if b == a {
+                    b = pool[(pool.iter().position(|&x| x == a).unwrap_or(0) + 1) % pool.len()];
+                }
+
+                let mut child = if rng.random_range(0.0..1.0) < config.crossover_rate {
+                    problem
+                        .crossover(&population[a], &population[b], &mut rng)
+                        .into_iter()
+                        .next()
+                        .unwrap_or_else(|| population[a].clone())
+                } else {
+                    population[a].clone()
+                };
+                if rng.random_range(0.0..1.0) < config.mutation_rate {
+                    problem.mutate(&mut child, &mut rng);
+                }
+
+                let child_objs = checked_evaluate(problem, &child, m)?;
+                update_ideal(&mut ideal, &child_objs);
+
+                // Replace up to nr pool members that the child improves
+                let mut candidates = pool.clone();
+                shuffle(&mut candidates, &mut rng);
+                let mut replaced = 0;
+                for j in candidates {
+                    if replaced >= config.max_replacements {
+                        break;
+                    }
+                    let g_child = config
+                        .decomposition
+                        .scalarize(&child_objs, &weights[j], &ideal);
+                    let g_current =
+                        config
+                            .decomposition
+                            .scalarize(&objectives[j], &weights[j], &ideal);
+                    if g_child <= g_current {
+                        population[j] = child.clone();
+                        objectives[j] = child_objs.clone();
+                        replaced += 1;
+                    }
+                }
+            }
+
+            generations += 1;
+            let front = non_dominated_sort(&objectives).fronts[0].len();
+            problem.on_generation(generations, front);
+        }
+
+        let population: Vec<ParetoSolution<P::Solution>> = population
+            .into_iter()
+            .zip(objectives)
+            .map(|(solution, objectives)| ParetoSolution {
+                solution,
+                objectives,
+            })
+            .collect();
+        let objs: Vec<Vec<f64>> = population.iter().map(|p| p.objectives.clone()).collect();
+        let pareto_front = non_dominated_sort(&objs).fronts[0]
+            .iter()
+            .map(|&i| population[i].clone())
+            .collect();
+
+        Ok(MoeadResult {
+            pareto_front,
+            population,
+            weights,
+            ideal_point: ideal,
+            generations,
+            cancelled,
+            timed_out,
+        })
+    }
+}
+
+/// For each weight vector, the indices of its `t` closest weight vectors
+/// (Euclidean distance, including itself).
+fn neighborhoods(weights: &[Vec<f64>], t: usize) -> Vec<Vec<usize>> {
+    weights
+        .iter()
+        .map(|wi| {
+            let mut by_distance: Vec<(usize, f64)> = weights
+                .iter()
+                .enumerate()
+                .map(|(j, wj)| {
+                    let d: f64 = wi.iter().zip(wj).map(|(a, b)| (a - b).powi(2)).sum();
+                    (j, d)
+                })
+                .collect();
+            by_distance.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+            by_distance.into_iter().take(t).map(|(j, _)| j).collect()
+        })
+        .collect()
+}
+
+fn update_ideal(ideal: &mut [f64], objectives: &[f64]) {
+    for (z, &f) in ideal.iter_mut().zip(objectives) {
+        *z = z.min(f);
+    }
+}
+
+fn checked_evaluate<P: MultiObjectiveProblem>(
+    problem: &P,
+    solution: &P::Solution,
+    objective_count: usize,
+) -> Result<Vec<f64>, String> {
+    let f = problem.evaluate(solution);
+    if f.len() != objective_count {
+        return Err(format!(
+            "evaluate returned {} objectives, expected {objective_count}",
+            f.len()
+        ));
+    }
+    Ok(f)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ---- ZDT1: convex front f2 = 1 - sqrt(f1) when g = 1 ----
+
+    struct Zdt1 {
+        n: usize,
+    }
+
+    impl Zdt1 {
+        fn g(x: &[f64]) -> f64 {
+            1.0 + 9.0 * x[1..].iter().sum::<f64>() / (x.len() - 1) as f64
+        }
+    }
+
+    impl MultiObjectiveProblem for Zdt1 {
+        type Solution = Vec<f64>;
+
+        fn objective_count(&self) -> usize {
+            2
+        }
+
+        fn create_solution<R: Rng>(&self, rng: &mut R) -> Vec<f64> {
+            (0..self.n).map(|_| rng.random_range(0.0..1.0)).collect()
+        }
+
+        fn evaluate(&self, x: &Vec<f64>) -> Vec<f64> {
+            let g = Self::g(x);
+            vec![x[0], g * (1.0 - (x[0] / g).sqrt())]
+        }
+
+        fn crossover<R: Rng>(&self, a: &Vec<f64>, b: &Vec<f64>, rng: &mut R) -> Vec<Vec<f64>> {
+            let child = a
+                .iter()
+                .zip(b)
+                .map(|(&x, &y)| {
+                    let t: f64 = rng.random_range(-0.25..1.25);
+                    (x + t * (y - x)).clamp(0.0, 1.0)
+                })
+                .collect();
+            vec![child]
+        }
+
+        fn mutate<R: Rng>(&self, x: &mut Vec<f64>, rng: &mut R) {
+            let i = rng.random_range(0..x.len());
+            x[i] = (x[i] + rng.random_range(-0.1..0.1)).clamp(0.0, 1.0);
+        }
+    }
+
+    #[test]
+    fn test_scalarize_weighted_sum() {
+        let g = Decomposition::WeightedSum.scalarize(&[2.0, 4.0], &[0.25, 0.75], &[0.0, 0.0]);
+        assert!((g - 3.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_scalarize_tchebycheff_zero_weight() {
+        // Zero weight is floored at 1e-6, so the other objective dominates
+        let g = Decomposition::Tchebycheff.scalarize(&[5.0, 2.0], &[0.0, 1.0], &[0.0, 0.0]);
+        assert!((g - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_scalarize_pbi() {
+        // Point on the weight direction: d2 = 0, d1 = distance from ideal
+        let g = Decomposition::Pbi { theta: 5.0 }.scalarize(&[1.0, 1.0], &[1.0, 1.0], &[0.0, 0.0]);
+        assert!((g - 2.0_f64.sqrt()).abs() < 1e-12);
+
+        // Perpendicular offset is penalized by theta
+        let g = Decomposition::Pbi { theta: 5.0 }.scalarize(&[1.0, 0.0], &[0.0, 1.0], &[0.0, 0.0]);
+        assert!((g - 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_neighborhoods_include_self_and_closest() {
+        let weights = das_dennis_points(2, 4); // (0,1), (.25,.75), ..., (1,0)
+        let nb = neighborhoods(&weights, 3);
+        assert_eq!(nb.len(), 5);
+        assert_eq!(nb[0][0], 0);
+        let mut middle = nb[2].clone();
+        middle.sort_unstable();
+        assert_eq!(middle, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_config_validate() {
+        assert!(MoeadConfig::default().validate().is_ok());
+        assert!(MoeadConfig::default().with_divisions(0).validate().is_err());
+        assert!(MoeadConfig::default()
+            .with_neighborhood_size(1)
+            .validate()
+            .is_err());
+        assert!(MoeadConfig::default()
+            .with_max_replacements(0)
+            .validate()
+            .is_err());
+        assert!(MoeadConfig::default()
+            .with_decomposition(Decomposition::Pbi { theta: -1.0 })
+            .validate()
+            .is_err());
+        assert!(MoeadConfig::default()
+            .with_weights(vec![vec![1.0, 0.0]])
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_zdt1_all_decompositions_converge() {
+        for decomposition in [
+            Decomposition::WeightedSum,
+            Decomposition::Tchebycheff,
+            Decomposition::Pbi { theta: 5.0 },
+        ] {
+            let config = MoeadConfig::default()
+                .with_divisions(29)
+                .with_neighborhood_size(10)
+                .with_decomposition(decomposition)
+                .with_max_generations(150)
+                .with_mutation_rate(0.5)
+                .with_seed(42);
+
+            let result = MoeadRunner::run(&Zdt1 { n: 6 }, &config).unwrap();
+
+            assert_eq!(result.population.len(), 30);
+            assert_eq!(result.generations, 150);
+            let mean_g: f64 = result
+                .pareto_front
+                .iter()
+                .map(|p| Zdt1::g(&p.solution))
+                .sum::<f64>()
+                / result.pareto_front.len() as f64;
+            assert!(
+                mean_g < 1.3,
+                "{decomposition:?}: front should approach g = 1, got mean g {mean_g}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_tchebycheff_front_is_spread() {
+        let config = MoeadConfig::default()
+            .with_divisions(19)
+            .with_neighborhood_size(5)
+            .with_max_generations(100)
+            .with_mutation_rate(0.5)
+            .with_seed(7);
+
+        let result = MoeadRunner::run(&Zdt1 { n: 4 }, &config).unwrap();
+
+        let f1: Vec<f64> = result
+            .pareto_front
+            .iter()
+            .map(|p| p.objectives[0])
+            .collect();
+        let min = f1.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = f1.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        assert!(max - min > 0.8, "front should span f1, got [{min}, {max}]");
+        assert!(result.ideal_point[0] < 1e-2);
+    }
+
+    #[test]
+    fn test_cancellation() {
+        let config = MoeadConfig::default().with_divisions(9).with_seed(1);
+        let cancel = Arc::new(AtomicBool::new(true));
+
+        let result = MoeadRunner::run_with_cancel(&Zdt1 { n: 3 }, &config, Some(cancel)).unwrap();
+
+        assert!(result.cancelled);
+        assert_eq!(result.generations, 0);
+        assert_eq!(result.population.len(), 10);
+    }
+
+    #[test]
+    fn test_weight_dimension_mismatch() {
+        let config = MoeadConfig::default().with_weights(vec![vec![1.0, 0.0, 0.0]; 3]);
+        assert!(MoeadRunner::run(&Zdt1 { n: 3 }, &config).is_err());
+    }
+}
@@ -7,11 +7,18 @@
 //!
 //! - [`non_dominated_sort`]: Fast non-dominated sorting (Deb et al., 2002)
 //! - [`crowding_distance`]: Crowding distance assignment for diversity preservation
+//! - [`das_dennis_points`]: Structured reference points on the unit simplex (NSGA-III)
+//! - [`ParetoArchive`]: Bounded external archive with optional ε-dominance
 //!
 //! # References
 //!
 //! - Deb et al. (2002), "A Fast and Elitist Multiobjective Genetic Algorithm: NSGA-II"
 //! - IEEE Transactions on Evolutionary Computation, 6(2), 182-197
+//! - Das & Dennis (1998), "Normal-Boundary Intersection", SIAM J. Optim. 8(3), 631-657
+//! - Laumanns et al. (2002), "Combining Convergence and Diversity in Evolutionary
+//!   Multiobjective Optimization", Evol. Comput. 10(3), 263-282
+
+use super::nsga2::ParetoSolution;
 
 /// Result of non-dominated sorting.
 ///
@@ -263,6 +270,260 @@ pub fn crowding_distance(objectives: &[Vec<f64>]) -> Vec<f64> {
     distances
 }
 
+/// Das–Dennis structured reference points on the unit simplex.
+///
+/// Generates every point whose coordinates are multiples of `1/divisions`
+/// and sum to 1. Used as reference directions by NSGA-III and as weight
+/// vectors by decomposition-based methods.
+///
+/// # Complexity
+///
+/// Produces `C(divisions + m - 1, m - 1)` points, where m = `objectives`.
+///
+/// # Panics
+///
+/// Panics if `objectives` or `divisions` is zero.
+///
+/// # Example
+///
+/// ```
+/// use u_metaheur::ga::multi_objective::das_dennis_points;
+///
+/// let points = das_dennis_points(3, 4);
+/// assert_eq!(points.len(), 15); // C(6, 2)
+/// for p in &points {
+///     assert!((p.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+/// }
+/// ```
+pub fn das_dennis_points(objectives: usize, divisions: usize) -> Vec<Vec<f64>> {
+    assert!(objectives > 0, "objectives must be at least 1");
+    assert!(divisions > 0, "divisions must be at least 1");
+
+    let mut points = Vec::new();
+    let mut current = vec![0usize; objectives];
+    das_dennis_recurse(&mut points, &mut current, 0, divisions, divisions);
+    points
+}
+
+/// Fills `current[index..]` with all compositions of `left` and emits points.
+fn das_dennis_recurse(
+    points: &mut Vec<Vec<f64>>,
+    current: &mut [usize],
+    index: usize,
+    left: usize,
+    divisions: usize,
+) {
+    if index == current.len() - 1 {
+        current[index] = left;
+        points.push(
+            current
+                .iter()
+                .map(|&c| c as f64 / divisions as f64)
+                .collect(),
+        );
+        return;
+    }
+    for k in 0..=left {
+        current[index] = k;
+        das_dennis_recurse(points, current, index + 1, left - k, divisions);
+    }
+}
+
+/// Bounded external archive of mutually non-dominated solutions.
+///
+/// Insertion rejects candidates dominated by (or equal to) an archive member
+/// and evicts members the candidate dominates. When the archive exceeds its
+/// capacity, the member with the smallest crowding distance is removed, so
+/// boundary solutions are always kept.
+///
+/// With [`with_epsilon`](Self::with_epsilon), dominance is checked on
+/// ε-boxes instead (Laumanns et al., 2002): at most one solution survives per
+/// box, which bounds the archive size by the grid resolution and keeps the
+/// front evenly spread.
+///
+/// All objectives are **minimized**.
+///
+/// # Example
+///
+/// ```
+/// use u_metaheur::ga::multi_objective::ParetoArchive;
+///
+/// let mut archive = ParetoArchive::new(100);
+/// assert!(archive.insert("a", vec![1.0, 5.0]));
+/// assert!(archive.insert("b", vec![5.0, 1.0]));
+/// assert!(!archive.insert("c", vec![6.0, 6.0])); // dominated
+/// assert!(archive.insert("d", vec![0.5, 0.5])); // dominates both
+/// assert_eq!(archive.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParetoArchive<S> {
+    capacity: usize,
+    epsilon: Option<f64>,
+    members: Vec<ParetoSolution<S>>,
+}
+
+impl<S> ParetoArchive<S> {
+    /// Creates an empty archive holding at most `capacity` solutions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be at least 1");
+        Self {
+            capacity,
+            epsilon: None,
+            members: Vec::new(),
+        }
+    }
+
+    /// Switches to additive ε-dominance with box size `epsilon`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `epsilon` is not positive.
+    pub fn with_epsilon(mut self, epsilon: f64) -> Self {
+        assert!(epsilon > 0.0, "epsilon must be positive");
+        self.epsilon = Some(epsilon);
+        self
+    }
+
+    /// Maximum number of solutions kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of solutions currently archived.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Whether the archive is empty.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Archived solutions, in insertion order.
+    pub fn solutions(&self) -> &[ParetoSolution<S>] {
+        &self.members
+    }
+
+    /// Objective vectors of the archived solutions, for use with the
+    /// [`indicators`](super::indicators) functions.
+    pub fn objectives(&self) -> Vec<Vec<f64>> {
+        self.members.iter().map(|m| m.objectives.clone()).collect()
+    }
+
+    /// Consumes the archive and returns its solutions.
+    pub fn into_solutions(self) -> Vec<ParetoSolution<S>> {
+        self.members
+    }
+
+    /// Offers a solution to the archive.
+    ///
+    /// Returns `true` if the solution was accepted and is still archived
+    /// after any capacity truncation.
+    pub fn insert(&mut self, solution: S, objectives: Vec<f64>) -> bool {
+        let accepted = match self.epsilon {
+            None => self.insert_pareto(&objectives),
+            Some(eps) => self.insert_epsilon(&objectives, eps),
+        };
+        if !accepted {
+            return false;
+        }
+
+        self.members.push(ParetoSolution {
+            solution,
+            objectives,
+        });
+        if self.members.len() > self.capacity {
+            let evicted = self.most_crowded();
+            self.members.remove(evicted);
+            return evicted != self.members.len();
+        }
+        true
+    }
+
+    /// Checks the candidate against plain Pareto dominance and evicts the
+    /// members it dominates. Returns whether it should be added.
+    fn insert_pareto(&mut self, objectives: &[f64]) -> bool {
+        for m in &self.members {
+            match dominance_cmp(&m.objectives, objectives) {
+                Dominance::Left => return false,
+                Dominance::Neither if m.objectives == objectives => return false,
+                _ => {}
+            }
+        }
+        self.members
+            .retain(|m| dominance_cmp(objectives, &m.objectives) != Dominance::Left);
+        true
+    }
+
+    /// Checks the candidate against ε-box dominance and evicts the members it
+    /// ε-dominates or beats within the same box.
+    fn insert_epsilon(&mut self, objectives: &[f64], eps: f64) -> bool {
+        let cand_box = epsilon_box(objectives, eps);
+        let mut evict = Vec::new();
+        for (i, m) in self.members.iter().enumerate() {
+            let member_box = epsilon_box(&m.objectives, eps);
+            if member_box == cand_box {
+                // Same box: keep the dominating solution, else the one
+                // closer to the box corner
+                let better = match dominance_cmp(objectives, &m.objectives) {
+                    Dominance::Left => true,
+                    Dominance::Right => false,
+                    Dominance::Neither => {
+                        corner_distance(objectives, &cand_box, eps)
+                            < corner_distance(&m.objectives, &member_box, eps)
+                    }
+                };
+                if !better {
+                    return false;
+                }
+                evict.push(i);
+                continue;
+            }
+            match dominance_cmp(&member_box, &cand_box) {
+                Dominance::Left => return false,
+                Dominance::Right => evict.push(i),
+                Dominance::Neither => {}
+            }
+        }
+        for &i in evict.iter().rev() {
+            self.members.remove(i);
+        }
+        true
+    }
+
+    /// Index of the member with the smallest crowding distance (ties go to
+    /// the most recently inserted).
+    fn most_crowded(&self) -> usize {
+        let distances = crowding_distance(&self.objectives());
+        let mut worst = distances.len() - 1;
+        for (i, &d) in distances.iter().enumerate().rev() {
+            if d < distances[worst] {
+                worst = i;
+            }
+        }
+        worst
+    }
+}
+
+/// Box coordinates of `objectives` on a grid of size `eps`.
+fn epsilon_box(objectives: &[f64], eps: f64) -> Vec<f64> {
+    objectives.iter().map(|f| (f / eps).floor()).collect()
+}
+
+/// Distance from `objectives` to the lower corner of its ε-box.
+fn corner_distance(objectives: &[f64], cell: &[f64], eps: f64) -> f64 {
+    objectives
+        .iter()
+        .zip(cell)
+        .map(|(f, b)| (f - b * eps).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -445,4 +706,88 @@ mod tests {
         // Actually: with 3 points, boundaries get inf, middle gets finite
         // But since there are exactly 3 in front 0, the middle one is finite
     }
+
+    // ---- Das–Dennis reference points ----
+
+    #[test]
+    fn test_das_dennis_counts() {
+        assert_eq!(das_dennis_points(2, 4).len(), 5);
+        assert_eq!(das_dennis_points(3, 12).len(), 91);
+        assert_eq!(das_dennis_points(5, 4).len(), 70);
+        assert_eq!(das_dennis_points(1, 3), vec![vec![1.0]]);
+    }
+
+    #[test]
+    fn test_das_dennis_on_simplex_and_unique() {
+        let points = das_dennis_points(4, 3);
+        for p in &points {
+            assert_eq!(p.len(), 4);
+            assert!(p.iter().all(|&v| (0.0..=1.0).contains(&v)));
+            assert!((p.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        }
+        for i in 0..points.len() {
+            for j in (i + 1)..points.len() {
+                assert_ne!(points[i], points[j]);
+            }
+        }
+    }
+
+    // ---- Pareto archive ----
+
+    #[test]
+    fn test_archive_dominance_filter() {
+        let mut archive = ParetoArchive::new(10);
+        assert!(archive.insert(0, vec![2.0, 2.0]));
+        assert!(!archive.insert(1, vec![2.0, 2.0]), "duplicates rejected");
+        assert!(!archive.insert(2, vec![3.0, 2.0]), "dominated rejected");
+        assert!(archive.insert(3, vec![1.0, 3.0]));
+        assert!(archive.insert(4, vec![1.0, 1.0]), "dominates everything");
+        assert_eq!(archive.len(), 1);
+        assert_eq!(archive.solutions()[0].solution, 4);
+    }
+
+    #[test]
+    fn test_archive_capacity_keeps_extremes() {
+        let mut archive = ParetoArchive::new(5);
+        for i in 0..=20 {
+            let x = i as f64 / 20.0;
+            archive.insert(i, vec![x, 1.0 - x]);
+        }
+        assert_eq!(archive.len(), 5);
+        let objs = archive.objectives();
+        assert!(objs.contains(&vec![0.0, 1.0]));
+        assert!(objs.contains(&vec![1.0, 0.0]));
+        assert_eq!(non_dominated_sort(&objs).fronts.len(), 1);
+    }
+
+    #[test]
+    fn test_archive_epsilon_one_per_box() {
+        let mut archive = ParetoArchive::new(1000).with_epsilon(0.25);
+        for i in 0..=100 {
+            let x = i as f64 / 100.0;
+            archive.insert(i, vec![x, 1.0 - x]);
+        }
+        // Boxes along the line x + y = 1 with eps = 0.25: at most 5 survive
+        assert!(archive.len() <= 5, "got {}", archive.len());
+        let boxes: Vec<Vec<f64>> = archive
+            .objectives()
+            .iter()
+            .map(|o| epsilon_box(o, 0.25))
+            .collect();
+        for i in 0..boxes.len() {
+            for j in (i + 1)..boxes.len() {
+                assert_ne!(boxes[i], boxes[j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_archive_epsilon_same_box_prefers_dominating() {
+        let mut archive = ParetoArchive::new(10).with_epsilon(1.0);
+        assert!(archive.insert("a", vec![0.8, 0.8]));
+        assert!(archive.insert("b", vec![0.5, 0.5]));
+        assert!(!archive.insert("c", vec![0.6, 0.6]));
+        assert_eq!(archive.len(), 1);
+        assert_eq!(archive.solutions()[0].solution, "b");
+    }
 }
@@ -0,0 +1,334 @@
+//! Diversity maintenance (niching) for the GA.
+//!
+//! Without countermeasures a GA population tends to collapse onto copies
+//! of one individual. [`Niching`] keeps several niches alive, either by
+//! sharing fitness among similar individuals or by letting offspring
+//! replace only individuals similar to them. Duplicate elimination
+//! ([`GaConfig::eliminate_duplicates`](super::GaConfig::eliminate_duplicates))
+//! replaces repeated genotypes with random individuals.
+//!
+//! Similarity is measured by [`GaProblem::distance`].
+//!
+//! # References
+//!
+//! - Goldberg & Richardson (1987), "Genetic Algorithms with Sharing for
+//!   Multimodal Function Optimization"
+//! - Mahfoud (1995), *Niching Methods for Genetic Algorithms*
+//!   (deterministic crowding)
+//! - Harik (1995), "Finding Multimodal Solutions Using Restricted Tournament
+//!   Selection"
+
+use super::selection::{fitness_keys, fitnesses};
+use super::types::GaProblem;
+use crate::direction::Direction;
+use std::borrow::Borrow;
+use std::collections::HashSet;
+
+/// Niching method for diversity maintenance.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::ga::{GaConfig, Niching};
+///
+/// let config = GaConfig::default()
+///     .with_niching(Niching::RestrictedTournament { window: 10 })
+///     .with_duplicate_elimination(true);
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Niching {
+    /// No niching.
+    #[default]
+    None,
+
+    /// Fitness sharing: parent selection sees each individual's fitness
+    /// shared with its neighbors within distance `sigma`.
+    ///
+    /// The niche count of individual `i` is `mᵢ = Σⱼ sh(dᵢⱼ)` with
+    /// `sh(d) = 1 − (d/σ)^α` for `d < σ` and 0 otherwise. Because fitness
+    /// is minimized and may be negative, the shared value is computed on
+    /// the distance to the worst fitness: `(f_worst − fᵢ) / mᵢ`, higher is
+    /// better. Elitism and survival still use the raw fitness.
+    FitnessSharing {
+        /// Niche radius, must be positive.
+        sigma: f64,
+        /// Shape of the sharing function, must be positive (1 is linear).
+        alpha: f64,
+    },
+
+    /// Deterministic crowding: the population is paired at random, each
+    /// pair produces two offspring, and each offspring competes with the
+    /// more similar parent, replacing it only if better.
+    ///
+    /// Replaces the population update of
+    /// [`GaConfig::replacement`](super::GaConfig::replacement), which must
+    /// be [`Replacement::Generational`](super::Replacement::Generational);
+    /// `elite_ratio` is not used. The best individual is never lost.
+    DeterministicCrowding,
+
+    /// Restricted tournament selection: each offspring is compared with the
+    /// most similar of `window` randomly chosen individuals and replaces it
+    /// only if better.
+    ///
+    /// A generation breeds `population_size` offspring by the configured
+    /// [`Selection`](super::Selection). Like deterministic crowding it
+    /// requires generational replacement and ignores `elite_ratio`.
+    RestrictedTournament {
+        /// Number of individuals sampled per offspring, at least 1.
+        window: usize,
+    },
+}
+
+impl Niching {
+    pub(super) fn validate(&self) -> Result<(), String> {
+        match *self {
+            Niching::FitnessSharing { sigma, alpha } if sigma <= 0.0 || alpha <= 0.0 => {
+                Err("fitness sharing sigma and alpha must be positive".into())
+            }
+            Niching::RestrictedTournament { window: 0 } => {
+                Err("restricted tournament window must be at least 1".into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether the method replaces the configured population update.
+    pub(super) fn replaces_population(&self) -> bool {
+        matches!(
+            self,
+            Niching::DeterministicCrowding | Niching::RestrictedTournament { .. }
+        )
+    }
+}
+
+/// Selection keys (lower is better) after fitness sharing.
+///
+/// `keys` are the constraint handler's keys, or `None` to share the
+/// fitness keys of a minimization.
+pub(super) fn shared_keys<P: GaProblem>(
+    problem: &P,
+    population: &[P::Individual],
+    keys: Option<&[f64]>,
+    sigma: f64,
+    alpha: f64,
+) -> Vec<f64> {
+    let base: Vec<f64> = match keys {
+        Some(keys) => keys.to_vec(),
+        None => fitness_keys(&fitnesses(population), Direction::Minimize),
+    };
+    let worst = base.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    let mut niche_counts = vec![1.0; population.len()]; // sh(0) = 1 for the individual itself
+    for (i, a) in population.iter().enumerate() {
+        for (j, b) in population.iter().enumerate().skip(i + 1) {
+            let d = problem.distance(a, b);
+            if d < sigma {
+                let sh = 1.0 - (d / sigma).powf(alpha);
+                niche_counts[i] += sh;
+                niche_counts[j] += sh;
+            }
+        }
+    }
+
+    base.iter()
+        .zip(&niche_counts)
+        .map(|(&b, &m)| -(worst - b) / m)
+        .collect()
+}
+
+/// Index among `candidates` of the individual closest to `target`.
+///
+/// # Panics
+/// Panics if `candidates` is empty.
+pub(super) fn closest<P: GaProblem>(
+    problem: &P,
+    target: &P::Individual,
+    population: &[P::Individual],
+    candidates: &[usize],
+) -> usize {
+    candidates
+        .iter()
+        .map(|&i| (i, problem.distance(target, &population[i])))
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(i, _)| i)
+        .expect("at least one candidate")
+}
+
+/// Indices of individuals that repeat an earlier one.
+///
+/// Genotypes are compared by [`GaProblem::individual_hash`] when the
+/// problem provides it, otherwise by a [`GaProblem::distance`] of 0.
+pub(super) fn duplicate_indices<P: GaProblem>(
+    problem: &P,
+    population: &[P::Individual],
+) -> Vec<usize> {
+    let hashes: Option<Vec<u64>> = population
+        .iter()
+        .map(|ind| problem.individual_hash(ind))
+        .collect();
+
+    match hashes {
+        Some(hashes) => {
+            let mut seen = HashSet::with_capacity(hashes.len());
+            (0..hashes.len())
+                .filter(|&i| !seen.insert(hashes[i]))
+                .collect()
+        }
+        None => {
+            let mut kept: Vec<usize> = Vec::with_capacity(population.len());
+            let mut duplicates = Vec::new();
+            for (i, ind) in population.iter().enumerate() {
+                if kept
+                    .iter()
+                    .any(|&k| problem.distance(ind, &population[k]) <= 0.0)
+                {
+                    duplicates.push(i);
+                } else {
+                    kept.push(i);
+                }
+            }
+            duplicates
+        }
+    }
+}
+
+/// Mean distance over all pairs of individuals (0 for fewer than two).
+pub(super) fn mean_pairwise_distance<P: GaProblem, B: Borrow<P::Individual>>(
+    problem: &P,
+    population: &[B],
+) -> f64 {
+    let n = population.len();
+    if n < 2 {
+        return 0.0;
+    }
+    let mut total = 0.0;
+    for (i, a) in population.iter().enumerate() {
+        for b in &population[i + 1..] {
+            total += problem.distance(a.borrow(), b.borrow());
+        }
+    }
+    total / (n * (n - 1) / 2) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ga::Individual;
+    use rand::Rng;
+
+    #[derive(Clone, Debug)]
+    struct Point {
+        x: f64,
+        fitness: f64,
+    }
+
+    impl Individual for Point {
+        type Fitness = f64;
+        fn fitness(&self) -> f64 {
+            self.fitness
+        }
+        fn set_fitness(&mut self, f: f64) {
+            self.fitness = f;
+        }
+    }
+
+    struct Line {
+        hashed: bool,
+    }
+
+    impl GaProblem for Line {
+        type Individual = Point;
+
+        fn create_individual<R: Rng>(&self, rng: &mut R) -> Point {
+            point(rng.random_range(0.0..10.0), 0.0)
+        }
+
+        fn evaluate(&self, ind: &Point) -> f64 {
+            ind.fitness
+        }
+
+        fn individual_hash(&self, ind: &Point) -> Option<u64> {
+            self.hashed.then_some(ind.x.to_bits())
+        }
+
+        fn distance(&self, a: &Point, b: &Point) -> f64 {
+            (a.x - b.x).abs()
+        }
+    }
+
+    fn point(x: f64, fitness: f64) -> Point {
+        Point { x, fitness }
+    }
+
+    #[test]
+    fn test_shared_keys_penalize_crowded_niches() {
+        let problem = Line { hashed: false };
+        // Three equally good individuals in one niche, one alone
+        let population = vec![
+            point(0.0, 0.0),
+            point(0.1, 0.0),
+            point(0.2, 0.0),
+            point(9.0, 0.0),
+            point(5.0, 10.0),
+        ];
+        let keys = shared_keys(&problem, &population, None, 1.0, 1.0);
+
+        assert!(keys[3] < keys[0], "the lone optimum is preferred: {keys:?}");
+        assert_eq!(keys[4], 0.0, "the worst individual has nothing to share");
+        assert_eq!(keys[3], -10.0, "no neighbors within sigma");
+    }
+
+    #[test]
+    fn test_closest() {
+        let problem = Line { hashed: false };
+        let population = vec![point(0.0, 0.0), point(4.0, 0.0), point(6.0, 0.0)];
+        let target = point(5.5, 0.0);
+        assert_eq!(closest(&problem, &target, &population, &[0, 1, 2]), 2);
+        assert_eq!(closest(&problem, &target, &population, &[0, 1]), 1);
+    }
+
+    #[test]
+    fn test_duplicate_indices_by_hash_and_distance() {
+        let population = vec![
+            point(1.0, 0.0),
+            point(2.0, 0.0),
+            point(1.0, 0.0),
+            point(2.0, 0.0),
+            point(1.0, 0.0),
+        ];
+        for hashed in [true, false] {
+            let problem = Line { hashed };
+            assert_eq!(duplicate_indices(&problem, &population), vec![2, 3, 4]);
+        }
+    }
+
+    #[test]
+    fn test_mean_pairwise_distance() {
+        let problem = Line { hashed: false };
+        let population = vec![point(0.0, 0.0), point(1.0, 0.0), point(3.0, 0.0)];
+        // (1 + 3 + 2) / 3
+        assert_eq!(mean_pairwise_distance(&problem, &population), 2.0);
+        assert_eq!(mean_pairwise_distance(&problem, &population[..1]), 0.0);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Niching::None.validate().is_ok());
+        assert!(Niching::FitnessSharing {
+            sigma: 0.0,
+            alpha: 1.0
+        }
+        .validate()
+        .is_err());
+        assert!(Niching::RestrictedTournament { window: 0 }
+            .validate()
+            .is_err());
+        assert!(Niching::DeterministicCrowding.replaces_population());
+        assert!(!Niching::FitnessSharing {
+            sigma: 1.0,
+            alpha: 1.0
+        }
+        .replaces_population());
+    }
+}
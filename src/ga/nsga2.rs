@@ -0,0 +1,669 @@
+//! NSGA-II multi-objective evolutionary loop.
+//!
+//! [`Nsga2Runner`] evolves a population for a [`MultiObjectiveProblem`]
+//! using crowded-comparison tournament selection and (μ+λ) environmental
+//! selection, built on [`non_dominated_sort`] and [`crowding_distance`].
+//!
+//! # References
+//!
+//! - Deb et al. (2002), "A Fast and Elitist Multiobjective Genetic Algorithm: NSGA-II"
+
+use super::multi_objective::{crowding_distance, non_dominated_sort};
+use super::types::MultiObjectiveProblem;
+use rand::Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+use u_numflow::random::create_rng;
+
+/// Configuration for NSGA-II.
+///
+/// Mirrors the termination and operator parameters of
+/// [`GaConfig`](super::GaConfig). Elitism is implicit in the (μ+λ)
+/// environmental selection, so there is no elite ratio.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::ga::Nsga2Config;
+///
+/// let config = Nsga2Config::default()
+///     .with_population_size(200)
+///     .with_max_generations(300)
+///     .with_seed(42);
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Nsga2Config {
+    /// Number of individuals in the population (μ).
+    ///
+    /// Each generation produces the same number of offspring (λ = μ).
+    pub population_size: usize,
+
+    /// Maximum number of generations before termination.
+    pub max_generations: usize,
+
+    /// Probability of applying crossover to a pair of parents (0.0–1.0).
+    pub crossover_rate: f64,
+
+    /// Probability of applying mutation to an offspring (0.0–1.0).
+    pub mutation_rate: f64,
+
+    /// Whether to evaluate individuals in parallel using rayon.
+    pub parallel: bool,
+
+    /// Random seed for reproducibility.
+    ///
+    /// `None` uses a random seed.
+    pub seed: Option<u64>,
+
+    /// Optional wall-clock time limit in milliseconds.
+    ///
+    /// Checked at the start of each generation. `None` disables
+    /// time-based termination (the default).
+    pub time_limit_ms: Option<u64>,
+}
+
+impl Default for Nsga2Config {
+    fn default() -> Self {
+        Self {
+            population_size: 100,
+            max_generations: 250,
+            crossover_rate: 0.9,
+            mutation_rate: 0.1,
+            parallel: true,
+            seed: None,
+            time_limit_ms: None,
+        }
+    }
+}
+
+impl Nsga2Config {
+    /// Sets the population size.
+    pub fn with_population_size(mut self, n: usize) -> Self {
+        self.population_size = n;
+        self
+    }
+
+    /// Sets the maximum number of generations.
+    pub fn with_max_generations(mut self, n: usize) -> Self {
+        self.max_generations = n;
+        self
+    }
+
+    /// Sets the crossover rate.
+    pub fn with_crossover_rate(mut self, rate: f64) -> Self {
+        self.crossover_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the mutation rate.
+    pub fn with_mutation_rate(mut self, rate: f64) -> Self {
+        self.mutation_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Enables or disables parallel evaluation.
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Sets the random seed for reproducibility.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the wall-clock time limit in milliseconds.
+    pub fn with_time_limit_ms(mut self, ms: u64) -> Self {
+        self.time_limit_ms = Some(ms);
+        self
+    }
+
+    /// Validates the configuration.
+    ///
+    /// Returns `Err` with a description if any parameter is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.population_size < 2 {
+            return Err("population_size must be at least 2".into());
+        }
+        if self.max_generations == 0 {
+            return Err("max_generations must be at least 1".into());
+        }
+        if self.time_limit_ms == Some(0) {
+            return Err("time_limit_ms must be positive or None".into());
+        }
+        Ok(())
+    }
+}
+
+/// A solution together with its objective vector.
+#[derive(Debug, Clone)]
+pub struct ParetoSolution<S> {
+    /// The solution.
+    pub solution: S,
+
+    /// Objective values (all minimized).
+    pub objectives: Vec<f64>,
+}
+
+/// Result of an NSGA-II run.
+#[derive(Debug, Clone)]
+pub struct Nsga2Result<S> {
+    /// Non-dominated solutions (rank 0) of the final population.
+    pub pareto_front: Vec<ParetoSolution<S>>,
+
+    /// The complete final population.
+    pub population: Vec<ParetoSolution<S>>,
+
+    /// Total number of generations executed.
+    pub generations: usize,
+
+    /// Whether the run was cancelled externally.
+    pub cancelled: bool,
+
+    /// Whether the run was stopped due to the wall-clock time limit.
+    pub timed_out: bool,
+
+    /// Size of the first front at the end of each generation
+    /// (index 0 is the initial population).
+    pub front_size_history: Vec<usize>,
+}
+
+/// A population member with its non-domination rank and crowding distance.
+#[derive(Debug, Clone)]
+pub(super) struct Member<S> {
+    pub(super) solution: S,
+    pub(super) objectives: Vec<f64>,
+    pub(super) rank: usize,
+    pub(super) crowding: f64,
+}
+
+/// Executes the NSGA-II evolutionary loop.
+///
+/// # Usage
+///
+/// ```ignore
+/// let problem = MyBiObjectiveProblem::new();
+/// let config = Nsga2Config::default().with_seed(42);
+/// let result = Nsga2Runner::run(&problem, &config)?;
+/// for p in &result.pareto_front {
+///     println!("{:?}", p.objectives);
+/// }
+/// ```
+pub struct Nsga2Runner;
+
+impl Nsga2Runner {
+    /// Runs NSGA-II.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid or the problem
+    /// returns objective vectors of the wrong length.
+    pub fn run<P: MultiObjectiveProblem>(
+        problem: &P,
+        config: &Nsga2Config,
+    ) -> Result<Nsga2Result<P::Solution>, String> {
+        Self::run_with_cancel(problem, config, None)
+    }
+
+    /// Runs NSGA-II with an optional cancellation token.
+    ///
+    /// If `cancel` is `Some` and the flag is set to `true`, the run stops
+    /// at the start of the next generation and returns the current front.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid or the problem
+    /// returns objective vectors of the wrong length.
+    pub fn run_with_cancel<P: MultiObjectiveProblem>(
+        problem: &P,
+        config: &Nsga2Config,
+        cancel: Option<Arc<AtomicBool>>,
+    ) -> Result<Nsga2Result<P::Solution>, String> {
+        evolve(problem, config, cancel, &CrowdingSurvival)
+    }
+}
+
+/// Environmental selection strategy plugged into [`evolve`].
+///
+/// Implementations choose `target` survivors from the combined parent and
+/// offspring population and assign each survivor its front `rank`.
+pub(super) trait Survival {
+    fn survive<S, R: Rng>(
+        &self,
+        combined: Vec<Member<S>>,
+        target: usize,
+        rng: &mut R,
+    ) -> Vec<Member<S>>;
+}
+
+/// NSGA-II survival: whole fronts, last front truncated by crowding distance.
+struct CrowdingSurvival;
+
+impl Survival for CrowdingSurvival {
+    fn survive<S, R: Rng>(
+        &self,
+        combined: Vec<Member<S>>,
+        target: usize,
+        _rng: &mut R,
+    ) -> Vec<Member<S>> {
+        environmental_selection(combined, target)
+    }
+}
+
+/// The shared (μ+λ) evolutionary loop used by NSGA-II and NSGA-III.
+pub(super) fn evolve<P: MultiObjectiveProblem, V: Survival>(
+    problem: &P,
+    config: &Nsga2Config,
+    cancel: Option<Arc<AtomicBool>>,
+    survival: &V,
+) -> Result<Nsga2Result<P::Solution>, String> {
+    config.validate()?;
+    let m = problem.objective_count();
+    if m == 0 {
+        return Err("objective_count must be at least 1".into());
+    }
+
+    let mut rng = match config.seed {
+        Some(seed) => create_rng(seed),
+        None => create_rng(rand::random()),
+    };
+    let n = config.population_size;
+
+    // 1. Initialize and evaluate population
+    let solutions: Vec<P::Solution> = (0..n).map(|_| problem.create_solution(&mut rng)).collect();
+    let initial = evaluate_all(problem, solutions, m, config.parallel)?;
+    let mut population = survival.survive(initial, n, &mut rng);
+
+    let mut front_size_history = Vec::with_capacity(config.max_generations + 1);
+    front_size_history.push(front_size(&population));
+
+    let mut cancelled = false;
+    #[allow(unused_mut)]
+    let mut timed_out = false;
+    #[cfg(not(target_arch = "wasm32"))]
+    let start_time = Instant::now();
+
+    // 2. Evolutionary loop
+    for gen in 0..config.max_generations {
+        if let Some(ref flag) = cancel {
+            if flag.load(Ordering::Relaxed) {
+                cancelled = true;
+                break;
+            }
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(limit_ms) = config.time_limit_ms {
+            if start_time.elapsed().as_millis() as u64 >= limit_ms {
+                timed_out = true;
+                break;
+            }
+        }
+
+        // Offspring generation (λ = μ)
+        let mut offspring: Vec<P::Solution> = Vec::with_capacity(n);
+        while offspring.len() < n {
+            let p1 = crowded_tournament(&population, &mut rng);
+            let p2 = crowded_tournament(&population, &mut rng);
+
+            let children = if rng.random_range(0.0..1.0) < config.crossover_rate {
+                problem.crossover(&population[p1].solution, &population[p2].solution, &mut rng)
+            } else {
+                vec![population[p1].solution.clone()]
+            };
+
+            for mut child in children {
+                if offspring.len() >= n {
+                    break;
+                }
+                if rng.random_range(0.0..1.0) < config.mutation_rate {
+                    problem.mutate(&mut child, &mut rng);
+                }
+                offspring.push(child);
+            }
+        }
+
+        // (μ+λ) environmental selection
+        let mut combined = population;
+        combined.extend(evaluate_all(problem, offspring, m, config.parallel)?);
+        population = survival.survive(combined, n, &mut rng);
+
+        let size = front_size(&population);
+        front_size_history.push(size);
+        problem.on_generation(gen + 1, size);
+    }
+
+    let generations = front_size_history.len() - 1;
+    let pareto_front = population
+        .iter()
+        .filter(|mem| mem.rank == 0)
+        .map(to_pareto_solution)
+        .collect();
+    let population = population.iter().map(to_pareto_solution).collect();
+
+    Ok(Nsga2Result {
+        pareto_front,
+        population,
+        generations,
+        cancelled,
+        timed_out,
+        front_size_history,
+    })
+}
+
+/// Evaluates solutions and wraps them as unranked members.
+fn evaluate_all<P: MultiObjectiveProblem>(
+    problem: &P,
+    solutions: Vec<P::Solution>,
+    objective_count: usize,
+    parallel: bool,
+) -> Result<Vec<Member<P::Solution>>, String> {
+    #[cfg(feature = "parallel")]
+    let objectives: Vec<Vec<f64>> = if parallel {
+        solutions.par_iter().map(|s| problem.evaluate(s)).collect()
+    } else {
+        solutions.iter().map(|s| problem.evaluate(s)).collect()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let objectives: Vec<Vec<f64>> = {
+        let _ = parallel;
+        solutions.iter().map(|s| problem.evaluate(s)).collect()
+    };
+
+    if let Some(bad) = objectives.iter().find(|o| o.len() != objective_count) {
+        return Err(format!(
+            "evaluate returned {} objectives, expected {objective_count}",
+            bad.len()
+        ));
+    }
+
+    Ok(solutions
+        .into_iter()
+        .zip(objectives)
+        .map(|(solution, objectives)| Member {
+            solution,
+            objectives,
+            rank: usize::MAX,
+            crowding: 0.0,
+        })
+        .collect())
+}
+
+/// Selects `target` survivors from `combined` by front, breaking ties in the
+/// last admitted front by descending crowding distance.
+///
+/// Survivors carry their rank and crowding distance (computed per front).
+fn environmental_selection<S>(combined: Vec<Member<S>>, target: usize) -> Vec<Member<S>> {
+    let objectives: Vec<Vec<f64>> = combined.iter().map(|m| m.objectives.clone()).collect();
+    let sorted = non_dominated_sort(&objectives);
+    let mut slots: Vec<Option<Member<S>>> = combined.into_iter().map(Some).collect();
+    let mut survivors = Vec::with_capacity(target);
+
+    for (rank, front) in sorted.fronts.iter().enumerate() {
+        if survivors.len() >= target {
+            break;
+        }
+
+        let front_objs: Vec<Vec<f64>> = front.iter().map(|&i| objectives[i].clone()).collect();
+        let distances = crowding_distance(&front_objs);
+        let mut order: Vec<usize> = (0..front.len()).collect();
+
+        let remaining = target - survivors.len();
+        if front.len() > remaining {
+            // Partial front: keep the most isolated solutions
+            order.sort_by(|&a, &b| {
+                distances[b]
+                    .partial_cmp(&distances[a])
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            order.truncate(remaining);
+        }
+
+        for k in order {
+            let mut member = slots[front[k]]
+                .take()
+                .expect("each index appears in exactly one front");
+            member.rank = rank;
+            member.crowding = distances[k];
+            survivors.push(member);
+        }
+    }
+
+    survivors
+}
+
+/// Binary tournament using the crowded-comparison operator.
+///
+/// Lower rank wins; within the same rank, larger crowding distance wins.
+fn crowded_tournament<S, R: Rng>(population: &[Member<S>], rng: &mut R) -> usize {
+    let n = population.len();
+    let a = rng.random_range(0..n);
+    let b = rng.random_range(0..n);
+    if crowded_less(&population[a], &population[b]) {
+        a
+    } else if crowded_less(&population[b], &population[a]) {
+        b
+    } else if rng.random_bool(0.5) {
+        a
+    } else {
+        b
+    }
+}
+
+/// Crowded-comparison partial order `a ≺ₙ b` (Deb et al., 2002).
+fn crowded_less<S>(a: &Member<S>, b: &Member<S>) -> bool {
+    a.rank < b.rank || (a.rank == b.rank && a.crowding > b.crowding)
+}
+
+fn front_size<S>(population: &[Member<S>]) -> usize {
+    population.iter().filter(|m| m.rank == 0).count()
+}
+
+fn to_pareto_solution<S: Clone>(member: &Member<S>) -> ParetoSolution<S> {
+    ParetoSolution {
+        solution: member.solution.clone(),
+        objectives: member.objectives.clone(),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ---- Schaffer N.1: f1 = x², f2 = (x - 2)², Pareto set x ∈ [0, 2] ----
+
+    struct Schaffer;
+
+    impl MultiObjectiveProblem for Schaffer {
+        type Solution = f64;
+
+        fn objective_count(&self) -> usize {
+            2
+        }
+
+        fn create_solution<R: Rng>(&self, rng: &mut R) -> f64 {
+            rng.random_range(-10.0..10.0)
+        }
+
+        fn evaluate(&self, x: &f64) -> Vec<f64> {
+            vec![x * x, (x - 2.0) * (x - 2.0)]
+        }
+
+        fn crossover<R: Rng>(&self, a: &f64, b: &f64, rng: &mut R) -> Vec<f64> {
+            let t: f64 = rng.random_range(0.0..1.0);
+            vec![t * a + (1.0 - t) * b, (1.0 - t) * a + t * b]
+        }
+
+        fn mutate<R: Rng>(&self, x: &mut f64, rng: &mut R) {
+            *x += rng.random_range(-0.5..0.5);
+        }
+    }
+
+    fn member(objectives: Vec<f64>) -> Member<()> {
+        Member {
+            solution: (),
+            objectives,
+            rank: usize::MAX,
+            crowding: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_config_defaults_and_validate() {
+        let config = Nsga2Config::default();
+        assert_eq!(config.population_size, 100);
+        assert!(config.validate().is_ok());
+        assert!(config.clone().with_population_size(1).validate().is_err());
+        assert!(config.clone().with_max_generations(0).validate().is_err());
+        assert!(config.with_time_limit_ms(0).validate().is_err());
+    }
+
+    #[test]
+    fn test_schaffer_front_converges() {
+        let config = Nsga2Config::default()
+            .with_population_size(40)
+            .with_max_generations(100)
+            .with_mutation_rate(0.3)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let result = Nsga2Runner::run(&Schaffer, &config).unwrap();
+
+        assert_eq!(result.population.len(), 40);
+        assert_eq!(result.generations, 100);
+        assert!(
+            result.pareto_front.len() >= 20,
+            "expected a well-populated front, got {}",
+            result.pareto_front.len()
+        );
+        for p in &result.pareto_front {
+            assert!(
+                (-0.1..=2.1).contains(&p.solution),
+                "front solution {} outside Pareto set [0, 2]",
+                p.solution
+            );
+        }
+    }
+
+    #[test]
+    fn test_front_is_mutually_non_dominated() {
+        let config = Nsga2Config::default()
+            .with_population_size(30)
+            .with_max_generations(30)
+            .with_seed(7)
+            .with_parallel(false);
+
+        let result = Nsga2Runner::run(&Schaffer, &config).unwrap();
+        let objs: Vec<Vec<f64>> = result
+            .pareto_front
+            .iter()
+            .map(|p| p.objectives.clone())
+            .collect();
+        let sorted = non_dominated_sort(&objs);
+        assert_eq!(sorted.fronts.len(), 1);
+        assert_eq!(result.front_size_history.len(), 31);
+    }
+
+    #[test]
+    fn test_environmental_selection_prefers_lower_fronts() {
+        let combined = vec![
+            member(vec![4.0, 4.0]), // front 1
+            member(vec![1.0, 5.0]), // front 0
+            member(vec![3.0, 3.0]), // front 0
+            member(vec![5.0, 1.0]), // front 0
+            member(vec![6.0, 6.0]), // front 2
+        ];
+        let survivors = environmental_selection(combined, 4);
+        assert_eq!(survivors.len(), 4);
+        assert_eq!(survivors.iter().filter(|m| m.rank == 0).count(), 3);
+        assert!(survivors.iter().any(|m| m.objectives == vec![4.0, 4.0]));
+    }
+
+    #[test]
+    fn test_environmental_selection_truncates_by_crowding() {
+        // One front of 4; keeping 2 must retain both boundary points
+        let combined = vec![
+            member(vec![0.0, 4.0]),
+            member(vec![1.0, 3.0]),
+            member(vec![1.1, 2.9]),
+            member(vec![4.0, 0.0]),
+        ];
+        let survivors = environmental_selection(combined, 2);
+        let kept: Vec<Vec<f64>> = survivors.iter().map(|m| m.objectives.clone()).collect();
+        assert!(kept.contains(&vec![0.0, 4.0]));
+        assert!(kept.contains(&vec![4.0, 0.0]));
+    }
+
+    #[test]
+    fn test_crowded_comparison() {
+        let mut a = member(vec![0.0]);
+        let mut b = member(vec![0.0]);
+        a.rank = 0;
+        b.rank = 1;
+        assert!(crowded_less(&a, &b));
+        b.rank = 0;
+        a.crowding = 1.0;
+        b.crowding = 2.0;
+        assert!(crowded_less(&b, &a));
+        assert!(!crowded_less(&a, &b));
+    }
+
+    #[test]
+    fn test_cancellation() {
+        let config = Nsga2Config::default()
+            .with_population_size(20)
+            .with_seed(42)
+            .with_parallel(false);
+        let cancel = Arc::new(AtomicBool::new(true));
+
+        let result = Nsga2Runner::run_with_cancel(&Schaffer, &config, Some(cancel)).unwrap();
+
+        assert!(result.cancelled);
+        assert_eq!(result.generations, 0);
+        assert!(!result.pareto_front.is_empty());
+    }
+
+    #[test]
+    fn test_timeout_stops_early() {
+        let config = Nsga2Config::default()
+            .with_population_size(20)
+            .with_max_generations(10_000_000)
+            .with_time_limit_ms(30)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let result = Nsga2Runner::run(&Schaffer, &config).unwrap();
+
+        assert!(result.timed_out);
+        assert!(result.generations < 10_000_000);
+    }
+
+    #[test]
+    fn test_wrong_objective_count_is_error() {
+        struct Broken;
+        impl MultiObjectiveProblem for Broken {
+            type Solution = f64;
+            fn objective_count(&self) -> usize {
+                3
+            }
+            fn create_solution<R: Rng>(&self, _rng: &mut R) -> f64 {
+                0.0
+            }
+            fn evaluate(&self, x: &f64) -> Vec<f64> {
+                vec![*x]
+            }
+        }
+
+        let config = Nsga2Config::default().with_seed(1).with_parallel(false);
+        let err = Nsga2Runner::run(&Broken, &config).unwrap_err();
+        assert!(err.contains("expected 3"), "unexpected error: {err}");
+    }
+}
@@ -0,0 +1,695 @@
+//! NSGA-III reference-point survival for many-objective problems.
+//!
+//! With four or more objectives almost every solution is non-dominated and
+//! crowding distance no longer discriminates between them. [`Nsga3Runner`]
+//! keeps the NSGA-II loop and non-dominated sorting but replaces the
+//! crowding-based truncation of the last front with niche preservation
+//! around a set of reference directions:
+//!
+//! 1. Normalize objectives by the ideal point and the hyperplane intercepts
+//!    of the extreme points (falling back to the nadir point)
+//! 2. Associate each solution with its closest reference line
+//! 3. Fill the remaining slots from the least crowded reference niches
+//!
+//! # References
+//!
+//! - Deb & Jain (2014), "An Evolutionary Many-Objective Optimization Algorithm
+//!   Using Reference-Point-Based Nondominated Sorting Approach, Part I",
+//!   IEEE Trans. Evol. Comput. 18(4), 577-601
+
+use super::multi_objective::{das_dennis_points, non_dominated_sort};
+use super::nsga2::{evolve, Member, Nsga2Config, Nsga2Result, Survival};
+use super::types::MultiObjectiveProblem;
+use rand::Rng;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+/// Configuration for NSGA-III.
+///
+/// Shares its loop parameters with [`Nsga2Config`] and adds the reference
+/// point layout. The population size should be close to the number of
+/// reference points (`C(divisions + m - 1, m - 1)` for `m` objectives).
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::ga::Nsga3Config;
+///
+/// // 5 objectives, 4 divisions → 70 reference points
+/// let config = Nsga3Config::default()
+///     .with_divisions(4)
+///     .with_population_size(72)
+///     .with_seed(42);
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Nsga3Config {
+    /// Number of individuals in the population.
+    pub population_size: usize,
+
+    /// Maximum number of generations before termination.
+    pub max_generations: usize,
+
+    /// Probability of applying crossover to a pair of parents (0.0–1.0).
+    pub crossover_rate: f64,
+
+    /// Probability of applying mutation to an offspring (0.0–1.0).
+    pub mutation_rate: f64,
+
+    /// Divisions per objective axis for Das–Dennis reference points.
+    ///
+    /// Ignored when [`reference_points`](Self::reference_points) is set.
+    pub divisions: usize,
+
+    /// User-supplied reference directions (one per row, length = objective
+    /// count). `None` generates Das–Dennis points from `divisions`.
+    pub reference_points: Option<Vec<Vec<f64>>>,
+
+    /// Whether to evaluate individuals in parallel using rayon.
+    pub parallel: bool,
+
+    /// Random seed for reproducibility.
+    ///
+    /// `None` uses a random seed.
+    pub seed: Option<u64>,
+
+    /// Optional wall-clock time limit in milliseconds.
+    pub time_limit_ms: Option<u64>,
+}
+
+impl Default for Nsga3Config {
+    fn default() -> Self {
+        Self {
+            population_size: 100,
+            max_generations: 300,
+            crossover_rate: 0.9,
+            mutation_rate: 0.1,
+            divisions: 4,
+            reference_points: None,
+            parallel: true,
+            seed: None,
+            time_limit_ms: None,
+        }
+    }
+}
+
+impl Nsga3Config {
+    /// Sets the population size.
+    pub fn with_population_size(mut self, n: usize) -> Self {
+        self.population_size = n;
+        self
+    }
+
+    /// Sets the maximum number of generations.
+    pub fn with_max_generations(mut self, n: usize) -> Self {
+        self.max_generations = n;
+        self
+    }
+
+    /// Sets the crossover rate.
+    pub fn with_crossover_rate(mut self, rate: f64) -> Self {
+        self.crossover_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the mutation rate.
+    pub fn with_mutation_rate(mut self, rate: f64) -> Self {
+        self.mutation_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the number of Das–Dennis divisions.
+    pub fn with_divisions(mut self, divisions: usize) -> Self {
+        self.divisions = divisions;
+        self
+    }
+
+    /// Uses the given reference directions instead of Das–Dennis points.
+    pub fn with_reference_points(mut self, points: Vec<Vec<f64>>) -> Self {
+        self.reference_points = Some(points);
+        self
+    }
+
+    /// Enables or disables parallel evaluation.
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Sets the random seed for reproducibility.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the wall-clock time limit in milliseconds.
+    pub fn with_time_limit_ms(mut self, ms: u64) -> Self {
+        self.time_limit_ms = Some(ms);
+        self
+    }
+
+    /// Validates the configuration.
+    ///
+    /// Returns `Err` with a description if any parameter is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        self.loop_config().validate()?;
+        match &self.reference_points {
+            None if self.divisions == 0 => Err("divisions must be at least 1".into()),
+            Some(points) if points.is_empty() => Err("reference_points must not be empty".into()),
+            Some(points)
+                if points
+                    .iter()
+                    .any(|p| p.iter().any(|&v| v < 0.0) || p.iter().sum::<f64>() <= 0.0) =>
+            {
+                Err("reference_points must be non-negative and non-zero".into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The loop parameters shared with NSGA-II.
+    fn loop_config(&self) -> Nsga2Config {
+        Nsga2Config {
+            population_size: self.population_size,
+            max_generations: self.max_generations,
+            crossover_rate: self.crossover_rate,
+            mutation_rate: self.mutation_rate,
+            parallel: self.parallel,
+            seed: self.seed,
+            time_limit_ms: self.time_limit_ms,
+        }
+    }
+}
+
+/// Executes NSGA-III.
+///
+/// Returns the same [`Nsga2Result`] as [`Nsga2Runner`](super::Nsga2Runner).
+///
+/// # Usage
+///
+/// ```ignore
+/// let problem = MySchedulingObjectives::new(); // 5 objectives
+/// let config = Nsga3Config::default().with_divisions(4).with_population_size(72);
+/// let result = Nsga3Runner::run(&problem, &config)?;
+/// ```
+pub struct Nsga3Runner;
+
+impl Nsga3Runner {
+    /// Runs NSGA-III.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid, the reference
+    /// points do not match the objective count, or the problem returns
+    /// objective vectors of the wrong length.
+    pub fn run<P: MultiObjectiveProblem>(
+        problem: &P,
+        config: &Nsga3Config,
+    ) -> Result<Nsga2Result<P::Solution>, String> {
+        Self::run_with_cancel(problem, config, None)
+    }
+
+    /// Runs NSGA-III with an optional cancellation token.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid, the reference
+    /// points do not match the objective count, or the problem returns
+    /// objective vectors of the wrong length.
+    pub fn run_with_cancel<P: MultiObjectiveProblem>(
+        problem: &P,
+        config: &Nsga3Config,
+        cancel: Option<Arc<AtomicBool>>,
+    ) -> Result<Nsga2Result<P::Solution>, String> {
+        config.validate()?;
+        let m = problem.objective_count();
+        if m == 0 {
+            return Err("objective_count must be at least 1".into());
+        }
+
+        let points = match &config.reference_points {
+            Some(points) => points.clone(),
+            None => das_dennis_points(m, config.divisions),
+        };
+        if points.iter().any(|p| p.len() != m) {
+            return Err(format!("reference points must have {m} coordinates"));
+        }
+
+        evolve(
+            problem,
+            &config.loop_config(),
+            cancel,
+            &ReferencePointSurvival { points },
+        )
+    }
+}
+
+/// NSGA-III survival: whole fronts, last front filled by niche preservation.
+struct ReferencePointSurvival {
+    points: Vec<Vec<f64>>,
+}
+
+impl Survival for ReferencePointSurvival {
+    fn survive<S, R: Rng>(
+        &self,
+        combined: Vec<Member<S>>,
+        target: usize,
+        rng: &mut R,
+    ) -> Vec<Member<S>> {
+        let objectives: Vec<Vec<f64>> = combined.iter().map(|m| m.objectives.clone()).collect();
+        let sorted = non_dominated_sort(&objectives);
+
+        // S_t: fronts up to and including the one that reaches `target`
+        let mut front_count = 0;
+        let mut st_size = 0;
+        for front in &sorted.fronts {
+            if st_size >= target {
+                break;
+            }
+            st_size += front.len();
+            front_count += 1;
+        }
+
+        let mut slots: Vec<Option<Member<S>>> = combined.into_iter().map(Some).collect();
+        let mut survivors = Vec::with_capacity(target);
+        let take = |slots: &mut Vec<Option<Member<S>>>, i: usize| {
+            let mut member = slots[i]
+                .take()
+                .expect("each index appears in exactly one front");
+            member.rank = sorted.ranks[i];
+            member.crowding = 0.0;
+            member
+        };
+
+        let full_fronts = if st_size == target {
+            front_count
+        } else {
+            front_count - 1
+        };
+        for front in &sorted.fronts[..full_fronts] {
+            for &i in front {
+                survivors.push(take(&mut slots, i));
+            }
+        }
+        if survivors.len() == target {
+            return survivors;
+        }
+
+        // Normalize and associate every member of S_t
+        let st: Vec<usize> = sorted.fronts[..front_count].concat();
+        let normalized = normalize(&objectives, &st);
+        let associations: Vec<(usize, f64)> = normalized
+            .iter()
+            .map(|f| associate(f, &self.points))
+            .collect();
+
+        // Niche counts of already accepted members (all of S_t but the last front)
+        let accepted = st.len() - sorted.fronts[front_count - 1].len();
+        let mut niche_count = vec![0usize; self.points.len()];
+        for &(j, _) in &associations[..accepted] {
+            niche_count[j] += 1;
+        }
+
+        // Candidates from the last front, grouped by reference point
+        let mut candidates: Vec<Vec<(usize, f64)>> = vec![Vec::new(); self.points.len()];
+        for (k, &idx) in st.iter().enumerate().skip(accepted) {
+            let (j, d) = associations[k];
+            candidates[j].push((idx, d));
+        }
+
+        let mut excluded = vec![false; self.points.len()];
+        while survivors.len() < target {
+            let min_count = (0..self.points.len())
+                .filter(|&j| !excluded[j])
+                .map(|j| niche_count[j])
+                .min()
+                .expect("last front has enough members to fill the population");
+            let tied: Vec<usize> = (0..self.points.len())
+                .filter(|&j| !excluded[j] && niche_count[j] == min_count)
+                .collect();
+            let j = tied[rng.random_range(0..tied.len())];
+
+            if candidates[j].is_empty() {
+                excluded[j] = true;
+                continue;
+            }
+
+            let pick = if niche_count[j] == 0 {
+                // Empty niche: closest member to the reference line
+                (0..candidates[j].len())
+                    .min_by(|&a, &b| {
+                        candidates[j][a]
+                            .1
+                            .partial_cmp(&candidates[j][b].1)
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                    .expect("candidates checked non-empty")
+            } else {
+                rng.random_range(0..candidates[j].len())
+            };
+            let (idx, _) = candidates[j].swap_remove(pick);
+            survivors.push(take(&mut slots, idx));
+            niche_count[j] += 1;
+        }
+
+        survivors
+    }
+}
+
+/// Normalizes the objective vectors of `members` (indices into `objectives`).
+///
+/// Translates by the ideal point, then divides by the intercepts of the
+/// hyperplane through the extreme points. Degenerate intercepts fall back
+/// to the translated nadir point.
+fn normalize(objectives: &[Vec<f64>], members: &[usize]) -> Vec<Vec<f64>> {
+    let m = objectives[members[0]].len();
+
+    let mut ideal = vec![f64::INFINITY; m];
+    for &i in members {
+        for (z, &f) in ideal.iter_mut().zip(&objectives[i]) {
+            *z = z.min(f);
+        }
+    }
+    let translated: Vec<Vec<f64>> = members
+        .iter()
+        .map(|&i| {
+            objectives[i]
+                .iter()
+                .zip(&ideal)
+                .map(|(f, z)| f - z)
+                .collect()
+        })
+        .collect();
+
+    // Extreme point per axis: minimizes the achievement scalarizing function
+    let extremes: Vec<Vec<f64>> = (0..m)
+        .map(|axis| {
+            translated
+                .iter()
+                .min_by(|a, b| {
+                    asf(a, axis)
+                        .partial_cmp(&asf(b, axis))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .expect("members must not be empty")
+                .clone()
+        })
+        .collect();
+
+    let nadir: Vec<f64> = (0..m)
+        .map(|j| translated.iter().map(|f| f[j]).fold(0.0, f64::max))
+        .collect();
+    let intercepts: Vec<f64> = match hyperplane_intercepts(&extremes) {
+        Some(a) => a
+            .iter()
+            .zip(&nadir)
+            .map(|(&a, &n)| if a > 1e-10 { a } else { n })
+            .collect(),
+        None => nadir,
+    };
+
+    translated
+        .into_iter()
+        .map(|f| {
+            f.iter()
+                .zip(&intercepts)
+                .map(|(&v, &a)| if a > 1e-10 { v / a } else { v })
+                .collect()
+        })
+        .collect()
+}
+
+/// Achievement scalarizing function toward the given axis.
+fn asf(f: &[f64], axis: usize) -> f64 {
+    f.iter()
+        .enumerate()
+        .map(|(j, &v)| if j == axis { v } else { v / 1e-6 })
+        .fold(f64::NEG_INFINITY, f64::max)
+}
+
+/// Intercepts of the hyperplane through the given `m` points.
+///
+/// Solves `E · b = 1` by Gaussian elimination with partial pivoting and
+/// returns `a_j = 1 / b_j`, or `None` if the system is degenerate.
+fn hyperplane_intercepts(extremes: &[Vec<f64>]) -> Option<Vec<f64>> {
+    let m = extremes.len();
+    let mut a: Vec<Vec<f64>> = extremes
+        .iter()
+        .map(|row| {
+            let mut r = row.clone();
+            r.push(1.0);
+            r
+        })
+        .collect();
+
+    for col in 0..m {
+        let pivot = (col..m).max_by(|&x, &y| {
+            a[x][col]
+                .abs()
+                .partial_cmp(&a[y][col].abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        let pivot_row = a[col].clone();
+        for (r, row) in a.iter_mut().enumerate() {
+            if r != col {
+                let factor = row[col] / pivot_row[col];
+                for (x, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                    *x -= factor * p;
+                }
+            }
+        }
+    }
+
+    let intercepts: Vec<f64> = (0..m).map(|j| a[j][j] / a[j][m]).collect();
+    if intercepts.iter().all(|v| v.is_finite() && *v > 0.0) {
+        Some(intercepts)
+    } else {
+        None
+    }
+}
+
+/// Returns the closest reference line and the perpendicular distance to it.
+fn associate(f: &[f64], points: &[Vec<f64>]) -> (usize, f64) {
+    points
+        .iter()
+        .enumerate()
+        .map(|(j, w)| (j, perpendicular_distance(f, w)))
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .expect("reference points must not be empty")
+}
+
+/// Distance from `f` to the line through the origin with direction `w`.
+fn perpendicular_distance(f: &[f64], w: &[f64]) -> f64 {
+    let ww: f64 = w.iter().map(|v| v * v).sum();
+    let proj = f.iter().zip(w).map(|(a, b)| a * b).sum::<f64>() / ww;
+    f.iter()
+        .zip(w)
+        .map(|(&a, &b)| (a - proj * b).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+    use u_numflow::random::create_rng;
+
+    // ---- DTLZ2 (Deb et al., 2005): Pareto front is the unit sphere octant ----
+
+    struct Dtlz2 {
+        m: usize,
+        k: usize,
+    }
+
+    impl MultiObjectiveProblem for Dtlz2 {
+        type Solution = Vec<f64>;
+
+        fn objective_count(&self) -> usize {
+            self.m
+        }
+
+        fn create_solution<R: Rng>(&self, rng: &mut R) -> Vec<f64> {
+            (0..self.m + self.k - 1)
+                .map(|_| rng.random_range(0.0..1.0))
+                .collect()
+        }
+
+        fn evaluate(&self, x: &Vec<f64>) -> Vec<f64> {
+            let g: f64 = x[self.m - 1..].iter().map(|v| (v - 0.5).powi(2)).sum();
+            (0..self.m)
+                .map(|i| {
+                    let mut f = 1.0 + g;
+                    for v in &x[..self.m - 1 - i] {
+                        f *= (v * FRAC_PI_2).cos();
+                    }
+                    if i > 0 {
+                        f *= (x[self.m - 1 - i] * FRAC_PI_2).sin();
+                    }
+                    f
+                })
+                .collect()
+        }
+
+        fn crossover<R: Rng>(&self, a: &Vec<f64>, b: &Vec<f64>, rng: &mut R) -> Vec<Vec<f64>> {
+            let child = a
+                .iter()
+                .zip(b)
+                .map(|(&x, &y)| if rng.random_bool(0.5) { x } else { y })
+                .collect();
+            vec![child]
+        }
+
+        fn mutate<R: Rng>(&self, x: &mut Vec<f64>, rng: &mut R) {
+            for v in x.iter_mut() {
+                if rng.random_bool(0.2) {
+                    *v = (*v + rng.random_range(-0.1..0.1)).clamp(0.0, 1.0);
+                }
+            }
+        }
+    }
+
+    fn member(objectives: Vec<f64>) -> Member<()> {
+        Member {
+            solution: (),
+            objectives,
+            rank: usize::MAX,
+            crowding: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_config_validate() {
+        assert!(Nsga3Config::default().validate().is_ok());
+        assert!(Nsga3Config::default().with_divisions(0).validate().is_err());
+        assert!(Nsga3Config::default()
+            .with_reference_points(vec![])
+            .validate()
+            .is_err());
+        assert!(Nsga3Config::default()
+            .with_reference_points(vec![vec![0.0, 0.0]])
+            .validate()
+            .is_err());
+        assert!(Nsga3Config::default()
+            .with_population_size(1)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_hyperplane_intercepts_axis_points() {
+        let extremes = vec![
+            vec![2.0, 0.0, 0.0],
+            vec![0.0, 3.0, 0.0],
+            vec![0.0, 0.0, 4.0],
+        ];
+        let a = hyperplane_intercepts(&extremes).unwrap();
+        assert!((a[0] - 2.0).abs() < 1e-10);
+        assert!((a[1] - 3.0).abs() < 1e-10);
+        assert!((a[2] - 4.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_hyperplane_intercepts_degenerate() {
+        let extremes = vec![vec![1.0, 1.0], vec![1.0, 1.0]];
+        assert!(hyperplane_intercepts(&extremes).is_none());
+    }
+
+    #[test]
+    fn test_normalize_maps_extremes_to_unit() {
+        let objs = vec![vec![1.0, 11.0], vec![3.0, 1.0], vec![2.0, 6.0]];
+        let normalized = normalize(&objs, &[0, 1, 2]);
+        assert!((normalized[0][0] - 0.0).abs() < 1e-10);
+        assert!((normalized[0][1] - 1.0).abs() < 1e-10);
+        assert!((normalized[1][0] - 1.0).abs() < 1e-10);
+        assert!((normalized[1][1] - 0.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_perpendicular_distance() {
+        assert!(perpendicular_distance(&[1.0, 1.0], &[1.0, 1.0]).abs() < 1e-12);
+        let d = perpendicular_distance(&[1.0, 0.0], &[0.0, 1.0]);
+        assert!((d - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_survival_fills_empty_niches_first() {
+        // One non-dominated front on the line f1 + f2 = 1, clustered near (0, 1)
+        let combined = vec![
+            member(vec![0.0, 1.0]),
+            member(vec![0.02, 0.98]),
+            member(vec![0.04, 0.96]),
+            member(vec![0.5, 0.5]),
+            member(vec![1.0, 0.0]),
+        ];
+        let survival = ReferencePointSurvival {
+            points: das_dennis_points(2, 2), // (0,1), (0.5,0.5), (1,0)
+        };
+        let mut rng = create_rng(42);
+
+        let survivors = survival.survive(combined, 3, &mut rng);
+        let kept: Vec<Vec<f64>> = survivors.iter().map(|m| m.objectives.clone()).collect();
+
+        assert_eq!(kept.len(), 3);
+        assert!(kept.contains(&vec![0.0, 1.0]));
+        assert!(kept.contains(&vec![0.5, 0.5]));
+        assert!(kept.contains(&vec![1.0, 0.0]));
+        assert!(survivors.iter().all(|m| m.rank == 0));
+    }
+
+    #[test]
+    fn test_dtlz2_four_objectives_converges_and_spreads() {
+        let problem = Dtlz2 { m: 4, k: 5 };
+        let config = Nsga3Config::default()
+            .with_divisions(4) // 35 reference points
+            .with_population_size(36)
+            .with_max_generations(150)
+            .with_mutation_rate(0.5)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let result = Nsga3Runner::run(&problem, &config).unwrap();
+
+        assert_eq!(result.population.len(), 36);
+        let mean_radius: f64 = result
+            .pareto_front
+            .iter()
+            .map(|p| p.objectives.iter().map(|f| f * f).sum::<f64>().sqrt())
+            .sum::<f64>()
+            / result.pareto_front.len() as f64;
+        assert!(
+            mean_radius < 1.25,
+            "front should approach the unit sphere, mean radius {mean_radius}"
+        );
+
+        // Solutions should occupy many distinct reference niches
+        let points = das_dennis_points(4, 4);
+        let mut niches: Vec<usize> = result
+            .pareto_front
+            .iter()
+            .map(|p| associate(&p.objectives, &points).0)
+            .collect();
+        niches.sort_unstable();
+        niches.dedup();
+        assert!(
+            niches.len() >= 15,
+            "expected a spread front, got {} niches",
+            niches.len()
+        );
+    }
+
+    #[test]
+    fn test_reference_point_dimension_mismatch() {
+        let problem = Dtlz2 { m: 3, k: 2 };
+        let config = Nsga3Config::default()
+            .with_reference_points(vec![vec![0.5, 0.5]])
+            .with_parallel(false);
+        assert!(Nsga3Runner::run(&problem, &config).is_err());
+    }
+}
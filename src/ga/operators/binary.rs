@@ -0,0 +1,274 @@
+//! Operators for bitstring chromosomes.
+//!
+//! The crossover operators are generic over the gene type, so they apply to
+//! integer or any other fixed-length vectors as well; [`bit_flip_mutation`]
+//! is specific to `bool` genes.
+//!
+//! # Crossover Operators
+//!
+//! - [`one_point_crossover`]: Exchange the tails after one cut point
+//! - [`two_point_crossover`]: Exchange the segment between two cut points
+//! - [`uniform_crossover`]: Exchange each gene with probability 1/2
+//!
+//! # Mutation Operators
+//!
+//! - [`bit_flip_mutation`]: Flip each bit with a per-gene rate
+//!
+//! # References
+//!
+//! - Holland (1975), *Adaptation in Natural and Artificial Systems*
+//! - De Jong (1975), "An Analysis of the Behavior of a Class of Genetic
+//!   Adaptive Systems"
+//! - Syswerda (1989), "Uniform Crossover in Genetic Algorithms"
+
+use rand::Rng;
+
+// ============================================================================
+// Crossover operators
+// ============================================================================
+
+/// One-point crossover.
+///
+/// Picks a cut point `c` in `1..n` and returns `p1[..c] ++ p2[c..]` and
+/// `p2[..c] ++ p1[c..]`. Parents shorter than two genes are returned
+/// unchanged.
+///
+/// # Complexity
+/// O(n)
+///
+/// # Panics
+/// Panics if parents have different lengths.
+pub fn one_point_crossover<T: Clone, R: Rng>(
+    parent1: &[T],
+    parent2: &[T],
+    rng: &mut R,
+) -> (Vec<T>, Vec<T>) {
+    let n = parent1.len();
+    assert_eq!(n, parent2.len(), "parents must have equal length");
+
+    if n < 2 {
+        return (parent1.to_vec(), parent2.to_vec());
+    }
+
+    let cut = rng.random_range(1..n);
+    exchange_segment(parent1, parent2, cut, n)
+}
+
+/// Two-point crossover.
+///
+/// Picks two cut points `1 <= a < b < n` and exchanges the segment
+/// `[a, b)` between the parents, so both ends of each child come from the
+/// same parent. Parents shorter than three genes fall back to
+/// [`one_point_crossover`].
+///
+/// # Complexity
+/// O(n)
+///
+/// # Panics
+/// Panics if parents have different lengths.
+pub fn two_point_crossover<T: Clone, R: Rng>(
+    parent1: &[T],
+    parent2: &[T],
+    rng: &mut R,
+) -> (Vec<T>, Vec<T>) {
+    let n = parent1.len();
+    assert_eq!(n, parent2.len(), "parents must have equal length");
+
+    if n < 3 {
+        return one_point_crossover(parent1, parent2, rng);
+    }
+
+    let a = rng.random_range(1..n - 1);
+    let b = rng.random_range(a + 1..n);
+    exchange_segment(parent1, parent2, a, b)
+}
+
+/// Uniform crossover: each gene is exchanged between the children with
+/// probability 1/2.
+///
+/// # Complexity
+/// O(n)
+///
+/// # Panics
+/// Panics if parents have different lengths.
+pub fn uniform_crossover<T: Clone, R: Rng>(
+    parent1: &[T],
+    parent2: &[T],
+    rng: &mut R,
+) -> (Vec<T>, Vec<T>) {
+    assert_eq!(
+        parent1.len(),
+        parent2.len(),
+        "parents must have equal length"
+    );
+
+    parent1
+        .iter()
+        .zip(parent2)
+        .map(|(a, b)| {
+            if rng.random_bool(0.5) {
+                (b.clone(), a.clone())
+            } else {
+                (a.clone(), b.clone())
+            }
+        })
+        .unzip()
+}
+
+/// Copy the parents, exchanging positions `[start, end)`.
+fn exchange_segment<T: Clone>(
+    parent1: &[T],
+    parent2: &[T],
+    start: usize,
+    end: usize,
+) -> (Vec<T>, Vec<T>) {
+    let mut child1 = parent1.to_vec();
+    let mut child2 = parent2.to_vec();
+    child1[start..end].clone_from_slice(&parent2[start..end]);
+    child2[start..end].clone_from_slice(&parent1[start..end]);
+    (child1, child2)
+}
+
+// ============================================================================
+// Mutation operators
+// ============================================================================
+
+/// Bit-flip mutation: each bit flips independently with probability `rate`.
+///
+/// `rate = 1/n` flips one bit per chromosome on average.
+///
+/// # Complexity
+/// O(n)
+pub fn bit_flip_mutation<R: Rng>(bits: &mut [bool], rate: f64, rng: &mut R) {
+    for bit in bits.iter_mut() {
+        if rng.random::<f64>() < rate {
+            *bit = !*bit;
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use u_numflow::random::create_rng;
+
+    /// Every child gene must come from one of the parents at the same position.
+    fn genes_from_parents(child: &[bool], p1: &[bool], p2: &[bool]) -> bool {
+        child
+            .iter()
+            .zip(p1.iter().zip(p2))
+            .all(|(c, (a, b))| c == a || c == b)
+    }
+
+    /// Number of positions where the child switches between the parents.
+    fn switches(child: &[u8], p1: &[u8]) -> usize {
+        let from_p1: Vec<bool> = child.iter().zip(p1).map(|(c, a)| c == a).collect();
+        from_p1.windows(2).filter(|w| w[0] != w[1]).count()
+    }
+
+    // ---- One-point crossover ----
+
+    #[test]
+    fn test_one_point_single_cut() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0u8; 10];
+        let p2 = vec![1u8; 10];
+
+        for _ in 0..100 {
+            let (c1, c2) = one_point_crossover(&p1, &p2, &mut rng);
+            assert_eq!(c1.len(), 10);
+            assert_eq!(switches(&c1, &p1), 1, "one cut expected: {c1:?}");
+            assert_eq!(c1[0], 0);
+            assert_eq!(c1[9], 1);
+            assert!(c1.iter().zip(&c2).all(|(a, b)| a != b));
+        }
+    }
+
+    #[test]
+    fn test_one_point_short_parents() {
+        let mut rng = create_rng(42);
+        let (c1, c2) = one_point_crossover(&[true], &[false], &mut rng);
+        assert_eq!(c1, vec![true]);
+        assert_eq!(c2, vec![false]);
+    }
+
+    // ---- Two-point crossover ----
+
+    #[test]
+    fn test_two_point_exchanges_interior_segment() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0u8; 10];
+        let p2 = vec![1u8; 10];
+
+        for _ in 0..100 {
+            let (c1, c2) = two_point_crossover(&p1, &p2, &mut rng);
+            assert_eq!(switches(&c1, &p1), 2, "two cuts expected: {c1:?}");
+            assert_eq!(c1[0], 0);
+            assert_eq!(c1[9], 0);
+            assert!(c1.iter().zip(&c2).all(|(a, b)| a != b));
+        }
+    }
+
+    #[test]
+    fn test_two_point_short_parents() {
+        let mut rng = create_rng(42);
+        let (c1, c2) = two_point_crossover(&[0u8, 0], &[1u8, 1], &mut rng);
+        assert_eq!(c1, vec![0, 1]);
+        assert_eq!(c2, vec![1, 0]);
+    }
+
+    // ---- Uniform crossover ----
+
+    #[test]
+    fn test_uniform_takes_genes_from_parents() {
+        let mut rng = create_rng(42);
+        let p1: Vec<bool> = (0..20).map(|i| i % 2 == 0).collect();
+        let p2: Vec<bool> = (0..20).map(|i| i % 3 == 0).collect();
+
+        for _ in 0..100 {
+            let (c1, c2) = uniform_crossover(&p1, &p2, &mut rng);
+            assert!(genes_from_parents(&c1, &p1, &p2));
+            assert!(genes_from_parents(&c2, &p1, &p2));
+        }
+    }
+
+    #[test]
+    fn test_uniform_mixes_parents() {
+        let mut rng = create_rng(42);
+        let p1 = vec![false; 100];
+        let p2 = vec![true; 100];
+        let (c1, c2) = uniform_crossover(&p1, &p2, &mut rng);
+        let ones = c1.iter().filter(|&&b| b).count();
+        assert!(
+            (30..=70).contains(&ones),
+            "expected ~50 genes from p2, got {ones}"
+        );
+        assert!(c1.iter().zip(&c2).all(|(a, b)| a != b));
+    }
+
+    // ---- Bit-flip mutation ----
+
+    #[test]
+    fn test_bit_flip_rates() {
+        let mut rng = create_rng(42);
+
+        let mut bits = vec![false; 50];
+        bit_flip_mutation(&mut bits, 0.0, &mut rng);
+        assert!(bits.iter().all(|&b| !b));
+
+        bit_flip_mutation(&mut bits, 1.0, &mut rng);
+        assert!(bits.iter().all(|&b| b));
+
+        let mut bits = vec![false; 1000];
+        bit_flip_mutation(&mut bits, 0.1, &mut rng);
+        let flipped = bits.iter().filter(|&&b| b).count();
+        assert!(
+            (50..=150).contains(&flipped),
+            "expected ~100 flips, got {flipped}"
+        );
+    }
+}
@@ -0,0 +1,287 @@
+//! Operators for bounded integer chromosomes.
+//!
+//! Mutation operators for vectors of `i64` genes, each gene confined to its
+//! own `[lower, upper]` interval given by [`IntBounds`]. Integer vectors
+//! recombine with the generic crossovers of [`super::binary`], re-exported
+//! here for convenience.
+//!
+//! # Crossover Operators
+//!
+//! - [`one_point_crossover`], [`two_point_crossover`], [`uniform_crossover`]
+//!
+//! # Mutation Operators
+//!
+//! - [`random_reset_mutation`]: Redraw a gene uniformly within its bounds
+//! - [`creep_mutation`]: Add a small random step to a gene
+//!
+//! # References
+//!
+//! - Eiben & Smith (2015), *Introduction to Evolutionary Computing*, §4.3
+
+use rand::Rng;
+
+pub use super::binary::{one_point_crossover, two_point_crossover, uniform_crossover};
+
+/// Per-gene `[lower, upper]` intervals of an integer chromosome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntBounds {
+    lower: Vec<i64>,
+    upper: Vec<i64>,
+}
+
+impl IntBounds {
+    /// Creates bounds from per-gene lower and upper limits (inclusive).
+    ///
+    /// # Panics
+    /// Panics if the vectors have different lengths or any `lower[i] > upper[i]`.
+    pub fn new(lower: Vec<i64>, upper: Vec<i64>) -> Self {
+        assert_eq!(
+            lower.len(),
+            upper.len(),
+            "lower and upper bounds must have equal length"
+        );
+        assert!(
+            lower.iter().zip(&upper).all(|(lo, hi)| lo <= hi),
+            "lower bounds must not exceed upper bounds"
+        );
+        Self { lower, upper }
+    }
+
+    /// Creates `dim` genes sharing the interval `[lower, upper]`.
+    ///
+    /// # Panics
+    /// Panics if `lower > upper`.
+    pub fn uniform(dim: usize, lower: i64, upper: i64) -> Self {
+        Self::new(vec![lower; dim], vec![upper; dim])
+    }
+
+    /// Number of genes.
+    pub fn len(&self) -> usize {
+        self.lower.len()
+    }
+
+    /// Returns `true` if there are no genes.
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+
+    /// Per-gene lower limits.
+    pub fn lower(&self) -> &[i64] {
+        &self.lower
+    }
+
+    /// Per-gene upper limits.
+    pub fn upper(&self) -> &[i64] {
+        &self.upper
+    }
+
+    /// Clamps every gene into its interval.
+    pub fn clamp(&self, genes: &mut [i64]) {
+        for ((x, &lo), &hi) in genes.iter_mut().zip(&self.lower).zip(&self.upper) {
+            *x = (*x).clamp(lo, hi);
+        }
+    }
+
+    /// Samples a chromosome uniformly within the bounds.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> Vec<i64> {
+        self.lower
+            .iter()
+            .zip(&self.upper)
+            .map(|(&lo, &hi)| rng.random_range(lo..=hi))
+            .collect()
+    }
+}
+
+// ============================================================================
+// Mutation operators
+// ============================================================================
+
+/// Random-reset mutation: each gene is redrawn uniformly from its bounds
+/// with probability `rate`.
+///
+/// The integer counterpart of bit-flip mutation, suited to nominal values
+/// such as assignment choices.
+///
+/// # Complexity
+/// O(n)
+///
+/// # Panics
+/// Panics if `genes` and `bounds` have different lengths.
+pub fn random_reset_mutation<R: Rng>(
+    genes: &mut [i64],
+    bounds: &IntBounds,
+    rate: f64,
+    rng: &mut R,
+) {
+    assert_eq!(
+        genes.len(),
+        bounds.len(),
+        "genes and bounds must have equal length"
+    );
+
+    for (i, x) in genes.iter_mut().enumerate() {
+        if rng.random::<f64>() < rate {
+            *x = rng.random_range(bounds.lower[i]..=bounds.upper[i]);
+        }
+    }
+}
+
+/// Creep mutation: with probability `rate`, each gene moves up or down by a
+/// random step in `1..=max_step` and is clamped to its bounds.
+///
+/// Suited to ordinal values such as quantities, where nearby values
+/// behave alike.
+///
+/// # Complexity
+/// O(n)
+///
+/// # Panics
+/// Panics if `genes` and `bounds` have different lengths or `max_step` is
+/// zero.
+pub fn creep_mutation<R: Rng>(
+    genes: &mut [i64],
+    bounds: &IntBounds,
+    max_step: u64,
+    rate: f64,
+    rng: &mut R,
+) {
+    assert_eq!(
+        genes.len(),
+        bounds.len(),
+        "genes and bounds must have equal length"
+    );
+    assert!(max_step > 0, "max_step must be positive");
+
+    for (i, x) in genes.iter_mut().enumerate() {
+        if rng.random::<f64>() >= rate {
+            continue;
+        }
+        let step = rng.random_range(1..=max_step).min(i64::MAX as u64) as i64;
+        let moved = if rng.random_bool(0.5) {
+            x.saturating_add(step)
+        } else {
+            x.saturating_sub(step)
+        };
+        *x = moved.clamp(bounds.lower[i], bounds.upper[i]);
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use u_numflow::random::create_rng;
+
+    fn within(genes: &[i64], bounds: &IntBounds) -> bool {
+        genes
+            .iter()
+            .zip(bounds.lower())
+            .zip(bounds.upper())
+            .all(|((x, lo), hi)| lo <= x && x <= hi)
+    }
+
+    fn test_bounds() -> IntBounds {
+        IntBounds::new(vec![0, -10, 5, 3], vec![1, 10, 100, 3])
+    }
+
+    // ---- Bounds ----
+
+    #[test]
+    fn test_bounds_clamp_and_sample() {
+        let mut rng = create_rng(42);
+        let bounds = test_bounds();
+
+        let mut genes = vec![-4, 0, 500, 0];
+        bounds.clamp(&mut genes);
+        assert_eq!(genes, vec![0, 0, 100, 3]);
+
+        for _ in 0..100 {
+            assert!(within(&bounds.sample(&mut rng), &bounds));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "lower bounds must not exceed upper bounds")]
+    fn test_bounds_reject_inverted_interval() {
+        IntBounds::new(vec![1], vec![0]);
+    }
+
+    // ---- Crossover ----
+
+    #[test]
+    fn test_crossovers_keep_genes_in_bounds() {
+        let mut rng = create_rng(42);
+        let bounds = test_bounds();
+
+        for _ in 0..100 {
+            let p1 = bounds.sample(&mut rng);
+            let p2 = bounds.sample(&mut rng);
+            let children = [
+                one_point_crossover(&p1, &p2, &mut rng),
+                two_point_crossover(&p1, &p2, &mut rng),
+                uniform_crossover(&p1, &p2, &mut rng),
+            ];
+            for (c1, c2) in &children {
+                assert!(within(c1, &bounds) && within(c2, &bounds));
+            }
+        }
+    }
+
+    // ---- Random-reset mutation ----
+
+    #[test]
+    fn test_random_reset_stays_within_bounds() {
+        let mut rng = create_rng(42);
+        let bounds = test_bounds();
+
+        for _ in 0..200 {
+            let mut genes = bounds.sample(&mut rng);
+            random_reset_mutation(&mut genes, &bounds, 1.0, &mut rng);
+            assert!(within(&genes, &bounds), "out of bounds: {genes:?}");
+        }
+    }
+
+    #[test]
+    fn test_random_reset_zero_rate() {
+        let mut rng = create_rng(42);
+        let bounds = IntBounds::uniform(20, 0, 1000);
+        let original = bounds.sample(&mut rng);
+        let mut genes = original.clone();
+        random_reset_mutation(&mut genes, &bounds, 0.0, &mut rng);
+        assert_eq!(genes, original);
+    }
+
+    // ---- Creep mutation ----
+
+    #[test]
+    fn test_creep_moves_by_bounded_steps() {
+        let mut rng = create_rng(42);
+        let bounds = IntBounds::uniform(10, -1000, 1000);
+
+        for _ in 0..100 {
+            let original = vec![0; 10];
+            let mut genes = original.clone();
+            creep_mutation(&mut genes, &bounds, 3, 1.0, &mut rng);
+            for (&x, &o) in genes.iter().zip(&original) {
+                let step = (x - o).abs();
+                assert!((1..=3).contains(&step), "creep step {step} out of range");
+            }
+        }
+    }
+
+    #[test]
+    fn test_creep_stays_within_bounds() {
+        let mut rng = create_rng(42);
+        let bounds = test_bounds();
+
+        for _ in 0..200 {
+            let mut genes = bounds.sample(&mut rng);
+            creep_mutation(&mut genes, &bounds, 50, 1.0, &mut rng);
+            assert!(within(&genes, &bounds), "out of bounds: {genes:?}");
+        }
+    }
+}
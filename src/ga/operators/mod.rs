@@ -0,0 +1,1300 @@
+//! Generic permutation-based genetic operators.
+//!
+//! Crossover and mutation operators for permutation-encoded chromosomes.
+//! These operate on `&[usize]` index vectors and are domain-agnostic:
+//! scheduling, TSP, and any permutation problem can use them.
+//!
+//! # Crossover Operators
+//!
+//! - [`order_crossover`] (OX): Davis (1985) — preserves relative order
+//! - [`pmx_crossover`] (PMX): Goldberg & Lingle (1985) — preserves absolute position
+//! - [`cycle_crossover`] (CX): Oliver et al. (1987) — every element keeps a parent's position
+//! - [`edge_recombination_crossover`] (ERX): Whitley et al. (1989) — preserves adjacency
+//! - [`position_based_crossover`] (PBX): Syswerda (1991) — positions of a random subset
+//! - [`order_based_crossover`] (OBX): Syswerda (1991) — order of a random subset
+//! - [`uniform_pmx_crossover`] (UPMX): Cicirello & Smith (2000) — PMX at scattered positions
+//!
+//! # Mutation Operators
+//!
+//! - [`swap_mutation`]: Exchange two random positions — O(1)
+//! - [`insert_mutation`]: Remove and reinsert at random position — O(n)
+//! - [`invert_mutation`]: Reverse a random segment (2-opt) — O(n)
+//!
+//! # Real-Coded Operators
+//!
+//! The [`real`] submodule holds bound-aware crossover and mutation for
+//! chromosomes of `f64` genes (SBX, BLX-α, polynomial and Gaussian
+//! mutation, ...).
+//!
+//! # Binary and Integer Operators
+//!
+//! - [`binary`]: one-point, two-point and uniform crossover (generic over the
+//!   gene type) and bit-flip mutation
+//! - [`integer`]: random-reset and creep mutation for bounded integer vectors
+//!
+//! # References
+//!
+//! - Davis (1985), "Applying Adaptive Algorithms to Epistatic Domains"
+//! - Goldberg & Lingle (1985), "Alleles, Loci, and the Traveling Salesman Problem"
+//! - Oliver, Smith & Holland (1987), "A Study of Permutation Crossover Operators
+//!   on the Traveling Salesman Problem"
+//! - Whitley, Starkweather & Fuquay (1989), "Scheduling Problems and Traveling
+//!   Salesmen: The Genetic Edge Recombination Operator"
+//! - Syswerda (1991), "Schedule Optimization Using Genetic Algorithms"
+//! - Cicirello & Smith (2000), "Modeling GA Performance for Control Parameter
+//!   Optimization"
+//! - Cicirello (2023), "Genetic Operators for Permutation Representation"
+
+pub mod binary;
+pub mod integer;
+pub mod real;
+
+use rand::Rng;
+
+// ============================================================================
+// Crossover operators
+// ============================================================================
+
+/// Order Crossover (OX) for permutations.
+///
+/// Preserves the **relative order** of elements from both parents.
+///
+/// # Algorithm (Davis, 1985)
+///
+/// 1. Select a random segment `[start, end]` from parent1
+/// 2. Copy segment to child at the same positions
+/// 3. Fill remaining positions with elements from parent2, in their original
+///    order, skipping elements already present in the child
+///
+/// # Complexity
+/// O(n) time, O(n) space
+///
+/// # Panics
+/// Panics if parents have different lengths or are empty.
+pub fn order_crossover<R: Rng>(
+    parent1: &[usize],
+    parent2: &[usize],
+    rng: &mut R,
+) -> (Vec<usize>, Vec<usize>) {
+    let n = parent1.len();
+    assert_eq!(n, parent2.len(), "parents must have equal length");
+    assert!(n > 0, "parents must not be empty");
+
+    if n == 1 {
+        return (parent1.to_vec(), parent2.to_vec());
+    }
+
+    let (start, end) = random_segment(n, rng);
+
+    let child1 = ox_build_child(parent1, parent2, start, end);
+    let child2 = ox_build_child(parent2, parent1, start, end);
+
+    (child1, child2)
+}
+
+/// Build one OX child: copy segment from `template`, fill from `donor`.
+fn ox_build_child(template: &[usize], donor: &[usize], start: usize, end: usize) -> Vec<usize> {
+    let n = template.len();
+    let mut child = vec![usize::MAX; n];
+    let mut in_segment = vec![false; n];
+
+    // Step 1: Copy segment from template
+    for i in start..=end {
+        child[i] = template[i];
+        in_segment[template[i]] = true;
+    }
+
+    // Step 2: Fill from donor, starting after segment end, wrapping around
+    let mut pos = (end + 1) % n;
+    for offset in 0..n {
+        let donor_idx = (end + 1 + offset) % n;
+        let val = donor[donor_idx];
+        if !in_segment[val] {
+            child[pos] = val;
+            pos = (pos + 1) % n;
+        }
+    }
+
+    child
+}
+
+/// Partially Mapped Crossover (PMX) for permutations.
+///
+/// Preserves the **absolute position** of elements from both parents
+/// as much as possible.
+///
+/// # Algorithm (Goldberg & Lingle, 1985)
+///
+/// 1. Select a random segment `[start, end]` from parent1
+/// 2. Copy segment to child at the same positions
+/// 3. For each element in parent2's segment that isn't in the child yet,
+///    find its position through the mapping chain and place it there
+/// 4. Fill remaining positions from parent2
+///
+/// # Complexity
+/// O(n) time, O(n) space
+///
+/// # Panics
+/// Panics if parents have different lengths or are empty.
+pub fn pmx_crossover<R: Rng>(
+    parent1: &[usize],
+    parent2: &[usize],
+    rng: &mut R,
+) -> (Vec<usize>, Vec<usize>) {
+    let n = parent1.len();
+    assert_eq!(n, parent2.len(), "parents must have equal length");
+    assert!(n > 0, "parents must not be empty");
+
+    if n == 1 {
+        return (parent1.to_vec(), parent2.to_vec());
+    }
+
+    let (start, end) = random_segment(n, rng);
+
+    let child1 = pmx_build_child(parent1, parent2, start, end);
+    let child2 = pmx_build_child(parent2, parent1, start, end);
+
+    (child1, child2)
+}
+
+/// Build one PMX child: copy segment from `template`, map from `donor`.
+fn pmx_build_child(template: &[usize], donor: &[usize], start: usize, end: usize) -> Vec<usize> {
+    let n = template.len();
+    let sentinel = usize::MAX;
+    let mut child = vec![sentinel; n];
+    let mut placed = vec![false; n];
+
+    // Step 1: Copy segment from template
+    for i in start..=end {
+        child[i] = template[i];
+        placed[template[i]] = true;
+    }
+
+    // Step 2: For elements in donor's segment not yet placed,
+    //         follow the mapping chain to find a free position
+    for i in start..=end {
+        let donor_val = donor[i];
+        if placed[donor_val] {
+            continue;
+        }
+        // Follow chain: template[i] -> find where template[i] is in donor -> check if that pos is free
+        let mut pos = i;
+        loop {
+            let mapped_val = template[pos];
+            // Find where mapped_val is in donor
+            let donor_pos = donor
+                .iter()
+                .position(|&v| v == mapped_val)
+                .expect("valid permutation: every value in template exists in donor");
+            if donor_pos < start || donor_pos > end {
+                // Position is outside segment — it's free
+                child[donor_pos] = donor_val;
+                placed[donor_val] = true;
+                break;
+            }
+            // Position is inside segment — follow chain
+            pos = donor_pos;
+        }
+    }
+
+    // Step 3: Fill remaining from donor
+    for i in 0..n {
+        if child[i] == sentinel {
+            child[i] = donor[i];
+        }
+    }
+
+    child
+}
+
+/// Cycle Crossover (CX) for permutations.
+///
+/// Every element keeps the **absolute position** it has in one of the
+/// parents.
+///
+/// # Algorithm (Oliver, Smith & Holland, 1987)
+///
+/// 1. Partition the positions into cycles: starting at position `i`, jump to
+///    the position of `parent2[i]` in parent1 until `i` is reached again
+/// 2. Child1 takes the first, third, ... cycle from parent1 and the others
+///    from parent2; child2 does the opposite
+///
+/// CX is deterministic; `rng` is accepted so that all crossover operators
+/// share one signature.
+///
+/// # Complexity
+/// O(n) time, O(n) space
+///
+/// # Panics
+/// Panics if parents have different lengths or are empty.
+pub fn cycle_crossover<R: Rng>(
+    parent1: &[usize],
+    parent2: &[usize],
+    _rng: &mut R,
+) -> (Vec<usize>, Vec<usize>) {
+    let n = parent1.len();
+    assert_eq!(n, parent2.len(), "parents must have equal length");
+    assert!(n > 0, "parents must not be empty");
+
+    let pos1 = inverse(parent1);
+    let mut child1 = vec![usize::MAX; n];
+    let mut child2 = vec![usize::MAX; n];
+    let mut from_parent1 = true;
+
+    for start in 0..n {
+        if child1[start] != usize::MAX {
+            continue;
+        }
+        let mut i = start;
+        loop {
+            if from_parent1 {
+                child1[i] = parent1[i];
+                child2[i] = parent2[i];
+            } else {
+                child1[i] = parent2[i];
+                child2[i] = parent1[i];
+            }
+            i = pos1[parent2[i]];
+            if i == start {
+                break;
+            }
+        }
+        from_parent1 = !from_parent1;
+    }
+
+    (child1, child2)
+}
+
+/// Edge Recombination Crossover (ERX) for permutations.
+///
+/// Preserves **adjacency**: children are built almost entirely from edges
+/// present in either parent, which suits routing problems. Permutations
+/// are treated as closed tours, so the last element is adjacent to the
+/// first.
+///
+/// # Algorithm (Whitley et al., 1989; enhanced variant 1991)
+///
+/// 1. Build the edge table: the neighbors of every element in both parents,
+///    marking edges the parents share
+/// 2. Start child1 with `parent1[0]` (child2 with `parent2[0]`)
+/// 3. Move to an unvisited neighbor of the current element, preferring
+///    shared edges, then the neighbor with the fewest unvisited neighbors
+///    of its own; ties are broken at random
+/// 4. If the current element has no unvisited neighbor, continue with a
+///    random unvisited element
+///
+/// # Complexity
+/// O(n) time, O(n) space
+///
+/// # Panics
+/// Panics if parents have different lengths or are empty.
+pub fn edge_recombination_crossover<R: Rng>(
+    parent1: &[usize],
+    parent2: &[usize],
+    rng: &mut R,
+) -> (Vec<usize>, Vec<usize>) {
+    let n = parent1.len();
+    assert_eq!(n, parent2.len(), "parents must have equal length");
+    assert!(n > 0, "parents must not be empty");
+
+    if n == 1 {
+        return (parent1.to_vec(), parent2.to_vec());
+    }
+
+    let edges = edge_table(parent1, parent2);
+
+    let child1 = erx_build_child(&edges, parent1[0], rng);
+    let child2 = erx_build_child(&edges, parent2[0], rng);
+
+    (child1, child2)
+}
+
+/// Edge-table entry mask for an edge present in both parents.
+const SHARED_EDGE: u8 = 0b11;
+
+/// Undirected, cyclic adjacency of both parents.
+///
+/// Each entry is `(neighbor, mask)`, where bit `k` of `mask` is set when
+/// parent `k` contains the edge.
+fn edge_table(parent1: &[usize], parent2: &[usize]) -> Vec<Vec<(usize, u8)>> {
+    let n = parent1.len();
+    let mut edges: Vec<Vec<(usize, u8)>> = vec![Vec::with_capacity(4); n];
+
+    for (k, parent) in [parent1, parent2].into_iter().enumerate() {
+        let bit = 1u8 << k;
+        for i in 0..n {
+            let a = parent[i];
+            for b in [parent[(i + n - 1) % n], parent[(i + 1) % n]] {
+                match edges[a].iter_mut().find(|(v, _)| *v == b) {
+                    Some((_, mask)) => *mask |= bit,
+                    None => edges[a].push((b, bit)),
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+/// Build one ERX child by walking the edge table from `start`.
+fn erx_build_child<R: Rng>(edges: &[Vec<(usize, u8)>], start: usize, rng: &mut R) -> Vec<usize> {
+    let n = edges.len();
+    let mut child = Vec::with_capacity(n);
+    let mut used = vec![false; n];
+    // Unvisited elements, with each element's index in `remaining`
+    let mut remaining: Vec<usize> = (0..n).collect();
+    let mut slot: Vec<usize> = (0..n).collect();
+
+    let mut current = start;
+    loop {
+        child.push(current);
+        used[current] = true;
+        let s = slot[current];
+        remaining.swap_remove(s);
+        if s < remaining.len() {
+            slot[remaining[s]] = s;
+        }
+        if remaining.is_empty() {
+            break;
+        }
+
+        current = match erx_next(edges, &used, current, rng) {
+            Some(next) => next,
+            None => remaining[rng.random_range(0..remaining.len())],
+        };
+    }
+
+    child
+}
+
+/// Choose the unvisited neighbor of `current` to visit next, if any.
+fn erx_next<R: Rng>(
+    edges: &[Vec<(usize, u8)>],
+    used: &[bool],
+    current: usize,
+    rng: &mut R,
+) -> Option<usize> {
+    let mut best = None;
+    let mut best_key = (true, usize::MAX);
+    let mut ties = 0usize;
+
+    for &(v, mask) in &edges[current] {
+        if used[v] {
+            continue;
+        }
+        let degree = edges[v].iter().filter(|&&(w, _)| !used[w]).count();
+        // Shared edges first, then fewest unvisited neighbors
+        let key = (mask != SHARED_EDGE, degree);
+        if best.is_none() || key < best_key {
+            best = Some(v);
+            best_key = key;
+            ties = 1;
+        } else if key == best_key {
+            // Reservoir sampling keeps the choice uniform among ties
+            ties += 1;
+            if rng.random_range(0..ties) == 0 {
+                best = Some(v);
+            }
+        }
+    }
+
+    best
+}
+
+/// Position-Based Crossover (PBX) for permutations.
+///
+/// Preserves the **absolute position** of a random subset of elements from
+/// one parent and the **relative order** of the rest from the other.
+///
+/// # Algorithm (Syswerda, 1991)
+///
+/// 1. Select each position independently with probability 1/2
+/// 2. Copy parent1's elements at the selected positions to child1
+/// 3. Fill the remaining positions with the missing elements, in the order
+///    they appear in parent2
+///
+/// Child2 is built the same way with the parents' roles swapped.
+///
+/// # Complexity
+/// O(n) time, O(n) space
+///
+/// # Panics
+/// Panics if parents have different lengths or are empty.
+pub fn position_based_crossover<R: Rng>(
+    parent1: &[usize],
+    parent2: &[usize],
+    rng: &mut R,
+) -> (Vec<usize>, Vec<usize>) {
+    let n = parent1.len();
+    assert_eq!(n, parent2.len(), "parents must have equal length");
+    assert!(n > 0, "parents must not be empty");
+
+    if n == 1 {
+        return (parent1.to_vec(), parent2.to_vec());
+    }
+
+    let selected = random_positions(n, rng);
+
+    let child1 = pbx_build_child(parent1, parent2, &selected);
+    let child2 = pbx_build_child(parent2, parent1, &selected);
+
+    (child1, child2)
+}
+
+/// Build one PBX child: keep `template` at selected positions, fill from `donor`.
+fn pbx_build_child(template: &[usize], donor: &[usize], selected: &[bool]) -> Vec<usize> {
+    let n = template.len();
+    let mut child = vec![usize::MAX; n];
+    let mut placed = vec![false; n];
+
+    // Step 1: Copy selected positions from template
+    for ((slot, &keep), &v) in child.iter_mut().zip(selected).zip(template) {
+        if keep {
+            *slot = v;
+            placed[v] = true;
+        }
+    }
+
+    // Step 2: Fill the gaps left to right with donor's missing elements
+    let mut donor_values = donor.iter().copied().filter(|&v| !placed[v]);
+    for slot in child.iter_mut().filter(|v| **v == usize::MAX) {
+        *slot = donor_values
+            .next()
+            .expect("valid permutation: one missing element per free position");
+    }
+
+    child
+}
+
+/// Order-Based Crossover (OBX) for permutations.
+///
+/// Imposes the **relative order** of a random subset of elements from one
+/// parent onto the other, leaving every other element in place.
+///
+/// # Algorithm (Syswerda, 1991)
+///
+/// 1. Select each position independently with probability 1/2
+/// 2. Collect parent2's elements at the selected positions
+/// 3. Child1 is parent1 with the positions holding those elements
+///    refilled with them in parent2's order
+///
+/// Child2 is built the same way with the parents' roles swapped.
+///
+/// # Complexity
+/// O(n) time, O(n) space
+///
+/// # Panics
+/// Panics if parents have different lengths or are empty.
+pub fn order_based_crossover<R: Rng>(
+    parent1: &[usize],
+    parent2: &[usize],
+    rng: &mut R,
+) -> (Vec<usize>, Vec<usize>) {
+    let n = parent1.len();
+    assert_eq!(n, parent2.len(), "parents must have equal length");
+    assert!(n > 0, "parents must not be empty");
+
+    if n == 1 {
+        return (parent1.to_vec(), parent2.to_vec());
+    }
+
+    let selected = random_positions(n, rng);
+
+    let child1 = obx_build_child(parent1, parent2, &selected);
+    let child2 = obx_build_child(parent2, parent1, &selected);
+
+    (child1, child2)
+}
+
+/// Build one OBX child: reorder `base` by `donor`'s selected elements.
+fn obx_build_child(base: &[usize], donor: &[usize], selected: &[bool]) -> Vec<usize> {
+    let mut is_selected_value = vec![false; base.len()];
+    let mut ordered = Vec::new();
+
+    for (&keep, &v) in selected.iter().zip(donor) {
+        if keep {
+            is_selected_value[v] = true;
+            ordered.push(v);
+        }
+    }
+
+    let mut child = base.to_vec();
+    let mut next = ordered.into_iter();
+    for slot in child.iter_mut() {
+        if is_selected_value[*slot] {
+            *slot = next
+                .next()
+                .expect("valid permutation: selected elements occur once in base");
+        }
+    }
+
+    child
+}
+
+/// Per-position swap probability of [`uniform_pmx_crossover`].
+const UPMX_RATE: f64 = 1.0 / 3.0;
+
+/// Uniform Partially Mapped Crossover (UPMX) for permutations.
+///
+/// A PMX variant without a contiguous segment: the parents' elements are
+/// exchanged at scattered positions, preserving **absolute position**
+/// elsewhere.
+///
+/// # Algorithm (Cicirello & Smith, 2000)
+///
+/// 1. Start with child1 = parent1 and child2 = parent2
+/// 2. For each position `i`, with probability 1/3: in child1, swap
+///    `parent2[i]` into position `i`; in child2, swap `parent1[i]` into
+///    position `i`
+///
+/// # Complexity
+/// O(n) time, O(n) space
+///
+/// # Panics
+/// Panics if parents have different lengths or are empty.
+pub fn uniform_pmx_crossover<R: Rng>(
+    parent1: &[usize],
+    parent2: &[usize],
+    rng: &mut R,
+) -> (Vec<usize>, Vec<usize>) {
+    let n = parent1.len();
+    assert_eq!(n, parent2.len(), "parents must have equal length");
+    assert!(n > 0, "parents must not be empty");
+
+    let mut child1 = parent1.to_vec();
+    let mut child2 = parent2.to_vec();
+    let mut pos1 = inverse(&child1);
+    let mut pos2 = inverse(&child2);
+
+    for i in 0..n {
+        if rng.random_bool(UPMX_RATE) {
+            swap_into(&mut child1, &mut pos1, i, parent2[i]);
+            swap_into(&mut child2, &mut pos2, i, parent1[i]);
+        }
+    }
+
+    (child1, child2)
+}
+
+/// Move `value` to position `i` of `perm` by a swap, keeping `pos` (the
+/// inverse of `perm`) in sync.
+fn swap_into(perm: &mut [usize], pos: &mut [usize], i: usize, value: usize) {
+    let j = pos[value];
+    let displaced = perm[i];
+    perm.swap(i, j);
+    pos[displaced] = j;
+    pos[value] = i;
+}
+
+// ============================================================================
+// Mutation operators
+// ============================================================================
+
+/// Swap mutation: exchange two random positions.
+///
+/// # Complexity
+/// O(1)
+pub fn swap_mutation<R: Rng>(perm: &mut [usize], rng: &mut R) {
+    let n = perm.len();
+    if n < 2 {
+        return;
+    }
+    let i = rng.random_range(0..n);
+    let j = rng.random_range(0..n);
+    perm.swap(i, j);
+}
+
+/// Insert mutation: remove an element and reinsert at a random position.
+///
+/// Equivalent to a single "insert" move in local search.
+///
+/// # Complexity
+/// O(n) due to array shifting
+pub fn insert_mutation<R: Rng>(perm: &mut Vec<usize>, rng: &mut R) {
+    let n = perm.len();
+    if n < 2 {
+        return;
+    }
+    let from = rng.random_range(0..n);
+    let item = perm.remove(from);
+    let to = rng.random_range(0..n); // n-1 elements, but 0..n insertion points
+    perm.insert(to, item);
+}
+
+/// Invert mutation: reverse a random segment (2-opt move).
+///
+/// # Complexity
+/// O(n) worst case for segment reversal
+pub fn invert_mutation<R: Rng>(perm: &mut [usize], rng: &mut R) {
+    let n = perm.len();
+    if n < 2 {
+        return;
+    }
+    let (start, end) = random_segment(n, rng);
+    perm[start..=end].reverse();
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Pick a random segment `[start, end]` within `0..n` where `start <= end`.
+fn random_segment<R: Rng>(n: usize, rng: &mut R) -> (usize, usize) {
+    let a = rng.random_range(0..n);
+    let b = rng.random_range(0..n);
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Select each position of `0..n` independently with probability 1/2.
+fn random_positions<R: Rng>(n: usize, rng: &mut R) -> Vec<bool> {
+    (0..n).map(|_| rng.random_bool(0.5)).collect()
+}
+
+/// Position of every element: `inverse(perm)[perm[i]] == i`.
+fn inverse(perm: &[usize]) -> Vec<usize> {
+    let mut pos = vec![0; perm.len()];
+    for (i, &v) in perm.iter().enumerate() {
+        pos[v] = i;
+    }
+    pos
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use u_numflow::random::{create_rng, shuffle};
+
+    /// Check that a slice is a valid permutation of 0..n.
+    fn is_valid_permutation(perm: &[usize], n: usize) -> bool {
+        if perm.len() != n {
+            return false;
+        }
+        let set: HashSet<usize> = perm.iter().copied().collect();
+        set.len() == n && perm.iter().all(|&v| v < n)
+    }
+
+    // ---- OX Crossover ----
+
+    #[test]
+    fn test_ox_produces_valid_permutations() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let p2 = vec![7, 6, 5, 4, 3, 2, 1, 0];
+
+        for _ in 0..100 {
+            let (c1, c2) = order_crossover(&p1, &p2, &mut rng);
+            assert!(is_valid_permutation(&c1, 8), "OX child1 not valid: {c1:?}");
+            assert!(is_valid_permutation(&c2, 8), "OX child2 not valid: {c2:?}");
+        }
+    }
+
+    #[test]
+    fn test_ox_preserves_segment() {
+        let mut rng = create_rng(123);
+        let p1 = vec![0, 1, 2, 3, 4];
+        let p2 = vec![4, 3, 2, 1, 0];
+
+        // Run many times and verify segment preservation
+        for _ in 0..50 {
+            let (c1, _c2) = order_crossover(&p1, &p2, &mut rng);
+            assert!(is_valid_permutation(&c1, 5));
+        }
+    }
+
+    #[test]
+    fn test_ox_single_element() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0];
+        let p2 = vec![0];
+        let (c1, c2) = order_crossover(&p1, &p2, &mut rng);
+        assert_eq!(c1, vec![0]);
+        assert_eq!(c2, vec![0]);
+    }
+
+    #[test]
+    fn test_ox_two_elements() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0, 1];
+        let p2 = vec![1, 0];
+
+        for _ in 0..20 {
+            let (c1, c2) = order_crossover(&p1, &p2, &mut rng);
+            assert!(is_valid_permutation(&c1, 2));
+            assert!(is_valid_permutation(&c2, 2));
+        }
+    }
+
+    // ---- PMX Crossover ----
+
+    #[test]
+    fn test_pmx_produces_valid_permutations() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let p2 = vec![3, 7, 5, 1, 6, 0, 2, 4];
+
+        for _ in 0..100 {
+            let (c1, c2) = pmx_crossover(&p1, &p2, &mut rng);
+            assert!(is_valid_permutation(&c1, 8), "PMX child1 not valid: {c1:?}");
+            assert!(is_valid_permutation(&c2, 8), "PMX child2 not valid: {c2:?}");
+        }
+    }
+
+    #[test]
+    fn test_pmx_preserves_segment() {
+        let mut rng = create_rng(99);
+        let p1 = vec![0, 1, 2, 3, 4];
+        let p2 = vec![4, 3, 2, 1, 0];
+
+        for _ in 0..50 {
+            let (c1, _) = pmx_crossover(&p1, &p2, &mut rng);
+            assert!(is_valid_permutation(&c1, 5));
+        }
+    }
+
+    #[test]
+    fn test_pmx_single_element() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0];
+        let p2 = vec![0];
+        let (c1, c2) = pmx_crossover(&p1, &p2, &mut rng);
+        assert_eq!(c1, vec![0]);
+        assert_eq!(c2, vec![0]);
+    }
+
+    #[test]
+    fn test_pmx_identical_parents() {
+        let mut rng = create_rng(42);
+        let p = vec![0, 1, 2, 3, 4];
+        let (c1, c2) = pmx_crossover(&p, &p, &mut rng);
+        assert_eq!(c1, p);
+        assert_eq!(c2, p);
+    }
+
+    // ---- CX Crossover ----
+
+    #[test]
+    fn test_cx_produces_valid_permutations() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let p2 = vec![3, 7, 5, 1, 6, 0, 2, 4];
+
+        for _ in 0..100 {
+            let (c1, c2) = cycle_crossover(&p1, &p2, &mut rng);
+            assert!(is_valid_permutation(&c1, 8), "CX child1 not valid: {c1:?}");
+            assert!(is_valid_permutation(&c2, 8), "CX child2 not valid: {c2:?}");
+        }
+    }
+
+    #[test]
+    fn test_cx_keeps_parent_positions() {
+        // p1 = [0,1,2,3,4,5,6,7], p2 = [1,2,0,4,3,5,7,6]
+        // Cycles: {0,1,2}, {3,4}, {5}, {6,7}
+        let mut rng = create_rng(42);
+        let p1 = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let p2 = vec![1, 2, 0, 4, 3, 5, 7, 6];
+        let (c1, c2) = cycle_crossover(&p1, &p2, &mut rng);
+        assert_eq!(c1, vec![0, 1, 2, 4, 3, 5, 7, 6]);
+        assert_eq!(c2, vec![1, 2, 0, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_cx_single_element() {
+        let mut rng = create_rng(42);
+        let (c1, c2) = cycle_crossover(&[0], &[0], &mut rng);
+        assert_eq!(c1, vec![0]);
+        assert_eq!(c2, vec![0]);
+    }
+
+    #[test]
+    fn test_cx_identical_parents() {
+        let mut rng = create_rng(42);
+        let p = vec![0, 1, 2, 3, 4];
+        let (c1, c2) = cycle_crossover(&p, &p, &mut rng);
+        assert_eq!(c1, p);
+        assert_eq!(c2, p);
+    }
+
+    // ---- ERX Crossover ----
+
+    #[test]
+    fn test_erx_produces_valid_permutations() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let p2 = vec![3, 7, 5, 1, 6, 0, 2, 4];
+
+        for _ in 0..100 {
+            let (c1, c2) = edge_recombination_crossover(&p1, &p2, &mut rng);
+            assert!(is_valid_permutation(&c1, 8), "ERX child1 not valid: {c1:?}");
+            assert!(is_valid_permutation(&c2, 8), "ERX child2 not valid: {c2:?}");
+            assert_eq!(c1[0], p1[0]);
+            assert_eq!(c2[0], p2[0]);
+        }
+    }
+
+    #[test]
+    fn test_erx_single_element() {
+        let mut rng = create_rng(42);
+        let (c1, c2) = edge_recombination_crossover(&[0], &[0], &mut rng);
+        assert_eq!(c1, vec![0]);
+        assert_eq!(c2, vec![0]);
+    }
+
+    #[test]
+    fn test_erx_identical_parents_keep_tour() {
+        // Every edge of the child must be an edge of the (cyclic) parent tour.
+        let mut rng = create_rng(7);
+        let p = vec![4, 0, 3, 1, 5, 2];
+        let n = p.len();
+        let is_edge = |a: usize, b: usize| {
+            (0..n).any(|i| {
+                let (x, y) = (p[i], p[(i + 1) % n]);
+                (x, y) == (a, b) || (y, x) == (a, b)
+            })
+        };
+
+        for _ in 0..50 {
+            let (c1, _) = edge_recombination_crossover(&p, &p, &mut rng);
+            assert!(is_valid_permutation(&c1, n));
+            for i in 0..n {
+                let (a, b) = (c1[i], c1[(i + 1) % n]);
+                assert!(
+                    is_edge(a, b),
+                    "ERX child edge {a}-{b} not in parent: {c1:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_erx_edge_table_marks_shared_edges() {
+        let edges = edge_table(&[0, 1, 2, 3], &[0, 1, 3, 2]);
+        // 0-1 is in both parents; 1-2 only in parent1; 1-3 only in parent2
+        assert!(edges[0].contains(&(1, SHARED_EDGE)));
+        assert!(edges[1].contains(&(2, 0b01)));
+        assert!(edges[1].contains(&(3, 0b10)));
+        assert!(edges.iter().all(|list| list.len() <= 4));
+    }
+
+    // ---- PBX Crossover ----
+
+    #[test]
+    fn test_pbx_produces_valid_permutations() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let p2 = vec![3, 7, 5, 1, 6, 0, 2, 4];
+
+        for _ in 0..100 {
+            let (c1, c2) = position_based_crossover(&p1, &p2, &mut rng);
+            assert!(is_valid_permutation(&c1, 8), "PBX child1 not valid: {c1:?}");
+            assert!(is_valid_permutation(&c2, 8), "PBX child2 not valid: {c2:?}");
+        }
+    }
+
+    #[test]
+    fn test_pbx_keeps_selected_positions() {
+        // p1 = [0,1,2,3,4,5], p2 = [5,4,3,2,1,0], positions {1, 4} selected
+        // child: [_,1,_,_,4,_] filled with 5,3,2,0 in p2 order
+        let p1 = vec![0, 1, 2, 3, 4, 5];
+        let p2 = vec![5, 4, 3, 2, 1, 0];
+        let selected = [false, true, false, false, true, false];
+        let child = pbx_build_child(&p1, &p2, &selected);
+        assert_eq!(child, vec![5, 1, 3, 2, 4, 0]);
+    }
+
+    #[test]
+    fn test_pbx_single_element() {
+        let mut rng = create_rng(42);
+        let (c1, c2) = position_based_crossover(&[0], &[0], &mut rng);
+        assert_eq!(c1, vec![0]);
+        assert_eq!(c2, vec![0]);
+    }
+
+    #[test]
+    fn test_pbx_identical_parents() {
+        let mut rng = create_rng(42);
+        let p = vec![0, 1, 2, 3, 4];
+        let (c1, c2) = position_based_crossover(&p, &p, &mut rng);
+        assert_eq!(c1, p);
+        assert_eq!(c2, p);
+    }
+
+    // ---- OBX Crossover ----
+
+    #[test]
+    fn test_obx_produces_valid_permutations() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let p2 = vec![3, 7, 5, 1, 6, 0, 2, 4];
+
+        for _ in 0..100 {
+            let (c1, c2) = order_based_crossover(&p1, &p2, &mut rng);
+            assert!(is_valid_permutation(&c1, 8), "OBX child1 not valid: {c1:?}");
+            assert!(is_valid_permutation(&c2, 8), "OBX child2 not valid: {c2:?}");
+        }
+    }
+
+    #[test]
+    fn test_obx_reorders_selected_elements() {
+        // p2 at positions {1, 2, 4} = [4, 3, 1]; in p1 those elements sit at
+        // positions 1, 3, 4 and are rewritten in p2's order.
+        let p1 = vec![0, 1, 2, 3, 4, 5];
+        let p2 = vec![5, 4, 3, 2, 1, 0];
+        let selected = [false, true, true, false, true, false];
+        let child = obx_build_child(&p1, &p2, &selected);
+        assert_eq!(child, vec![0, 4, 2, 3, 1, 5]);
+    }
+
+    #[test]
+    fn test_obx_single_element() {
+        let mut rng = create_rng(42);
+        let (c1, c2) = order_based_crossover(&[0], &[0], &mut rng);
+        assert_eq!(c1, vec![0]);
+        assert_eq!(c2, vec![0]);
+    }
+
+    #[test]
+    fn test_obx_identical_parents() {
+        let mut rng = create_rng(42);
+        let p = vec![0, 1, 2, 3, 4];
+        let (c1, c2) = order_based_crossover(&p, &p, &mut rng);
+        assert_eq!(c1, p);
+        assert_eq!(c2, p);
+    }
+
+    // ---- UPMX Crossover ----
+
+    #[test]
+    fn test_upmx_produces_valid_permutations() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let p2 = vec![3, 7, 5, 1, 6, 0, 2, 4];
+
+        for _ in 0..100 {
+            let (c1, c2) = uniform_pmx_crossover(&p1, &p2, &mut rng);
+            assert!(
+                is_valid_permutation(&c1, 8),
+                "UPMX child1 not valid: {c1:?}"
+            );
+            assert!(
+                is_valid_permutation(&c2, 8),
+                "UPMX child2 not valid: {c2:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_upmx_single_element() {
+        let mut rng = create_rng(42);
+        let (c1, c2) = uniform_pmx_crossover(&[0], &[0], &mut rng);
+        assert_eq!(c1, vec![0]);
+        assert_eq!(c2, vec![0]);
+    }
+
+    #[test]
+    fn test_upmx_identical_parents() {
+        let mut rng = create_rng(42);
+        let p = vec![0, 1, 2, 3, 4];
+        let (c1, c2) = uniform_pmx_crossover(&p, &p, &mut rng);
+        assert_eq!(c1, p);
+        assert_eq!(c2, p);
+    }
+
+    // ---- All crossovers: random parents ----
+
+    #[test]
+    fn test_crossovers_random_parents_stay_valid() {
+        let mut rng = create_rng(2024);
+
+        for n in [2, 3, 10, 31] {
+            for _ in 0..30 {
+                let mut p1: Vec<usize> = (0..n).collect();
+                let mut p2: Vec<usize> = (0..n).collect();
+                shuffle(&mut p1, &mut rng);
+                shuffle(&mut p2, &mut rng);
+
+                let children = [
+                    ("OX", order_crossover(&p1, &p2, &mut rng)),
+                    ("PMX", pmx_crossover(&p1, &p2, &mut rng)),
+                    ("CX", cycle_crossover(&p1, &p2, &mut rng)),
+                    ("ERX", edge_recombination_crossover(&p1, &p2, &mut rng)),
+                    ("PBX", position_based_crossover(&p1, &p2, &mut rng)),
+                    ("OBX", order_based_crossover(&p1, &p2, &mut rng)),
+                    ("UPMX", uniform_pmx_crossover(&p1, &p2, &mut rng)),
+                ];
+                for (name, (c1, c2)) in &children {
+                    assert!(
+                        is_valid_permutation(c1, n),
+                        "{name} child1 not valid: {c1:?}"
+                    );
+                    assert!(
+                        is_valid_permutation(c2, n),
+                        "{name} child2 not valid: {c2:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    // ---- Swap Mutation ----
+
+    #[test]
+    fn test_swap_preserves_permutation() {
+        let mut rng = create_rng(42);
+        for _ in 0..100 {
+            let mut perm: Vec<usize> = (0..10).collect();
+            swap_mutation(&mut perm, &mut rng);
+            assert!(is_valid_permutation(&perm, 10));
+        }
+    }
+
+    #[test]
+    fn test_swap_single_element() {
+        let mut rng = create_rng(42);
+        let mut perm = vec![0];
+        swap_mutation(&mut perm, &mut rng);
+        assert_eq!(perm, vec![0]);
+    }
+
+    // ---- Insert Mutation ----
+
+    #[test]
+    fn test_insert_preserves_permutation() {
+        let mut rng = create_rng(42);
+        for _ in 0..100 {
+            let mut perm: Vec<usize> = (0..10).collect();
+            insert_mutation(&mut perm, &mut rng);
+            assert!(is_valid_permutation(&perm, 10));
+        }
+    }
+
+    #[test]
+    fn test_insert_single_element() {
+        let mut rng = create_rng(42);
+        let mut perm = vec![0];
+        insert_mutation(&mut perm, &mut rng);
+        assert_eq!(perm, vec![0]);
+    }
+
+    // ---- Invert Mutation ----
+
+    #[test]
+    fn test_invert_preserves_permutation() {
+        let mut rng = create_rng(42);
+        for _ in 0..100 {
+            let mut perm: Vec<usize> = (0..10).collect();
+            invert_mutation(&mut perm, &mut rng);
+            assert!(is_valid_permutation(&perm, 10));
+        }
+    }
+
+    #[test]
+    fn test_invert_reverses_segment() {
+        let mut rng = create_rng(42);
+        let mut perm = vec![0, 1, 2, 3, 4];
+        // Force specific segment by running until we observe a reversal
+        let original = perm.clone();
+        let mut changed = false;
+        for _ in 0..100 {
+            perm = original.clone();
+            invert_mutation(&mut perm, &mut rng);
+            if perm != original {
+                changed = true;
+                break;
+            }
+        }
+        assert!(changed, "invert should change the permutation eventually");
+        assert!(is_valid_permutation(&perm, 5));
+    }
+
+    // ---- Integration: crossover + mutation pipeline ----
+
+    #[test]
+    fn test_full_pipeline_preserves_validity() {
+        let mut rng = create_rng(42);
+        let p1: Vec<usize> = (0..20).collect();
+        let mut p2: Vec<usize> = (0..20).collect();
+        p2.reverse();
+
+        for _ in 0..50 {
+            let (mut c1, mut c2) = order_crossover(&p1, &p2, &mut rng);
+            swap_mutation(&mut c1, &mut rng);
+            insert_mutation(&mut c2, &mut rng);
+            invert_mutation(&mut c1, &mut rng);
+
+            assert!(is_valid_permutation(&c1, 20), "pipeline c1 invalid: {c1:?}");
+            assert!(is_valid_permutation(&c2, 20), "pipeline c2 invalid: {c2:?}");
+        }
+    }
+
+    #[test]
+    fn test_pmx_pipeline_preserves_validity() {
+        let mut rng = create_rng(42);
+        let p1: Vec<usize> = (0..15).collect();
+        let mut p2: Vec<usize> = (0..15).collect();
+        p2.reverse();
+
+        for _ in 0..50 {
+            let (mut c1, _) = pmx_crossover(&p1, &p2, &mut rng);
+            swap_mutation(&mut c1, &mut rng);
+            assert!(is_valid_permutation(&c1, 15));
+        }
+    }
+
+    // ---- Random segment helper ----
+
+    #[test]
+    fn test_random_segment_bounds() {
+        let mut rng = create_rng(42);
+        for _ in 0..1000 {
+            let (start, end) = random_segment(10, &mut rng);
+            assert!(start <= end);
+            assert!(end < 10);
+        }
+    }
+
+    // ---- OX: mathematical invariants ----
+    //
+    // The OX operator (Davis, 1985) must satisfy:
+    //   1. Output is a permutation of 0..n (no duplicates, no omissions).
+    //   2. The copied segment from `template` appears unchanged in the child.
+    //   3. Remaining positions follow the relative order of `donor`.
+
+    #[test]
+    fn test_ox_no_duplicates_no_omissions() {
+        // For any two valid permutations, children must be permutations.
+        let mut rng = create_rng(999);
+        let n = 12;
+        let p1: Vec<usize> = (0..n).collect();
+        let mut p2: Vec<usize> = (0..n).collect();
+        // Make p2 a different permutation
+        p2.reverse();
+
+        for _ in 0..200 {
+            let (c1, c2) = order_crossover(&p1, &p2, &mut rng);
+
+            // Each value 0..n must appear exactly once
+            let mut seen_c1 = vec![0u32; n];
+            let mut seen_c2 = vec![0u32; n];
+            for &v in &c1 {
+                assert!(v < n, "OX c1 contains out-of-range value {v}");
+                seen_c1[v] += 1;
+            }
+            for &v in &c2 {
+                assert!(v < n, "OX c2 contains out-of-range value {v}");
+                seen_c2[v] += 1;
+            }
+            for i in 0..n {
+                assert_eq!(
+                    seen_c1[i], 1,
+                    "OX c1: value {i} appears {} times",
+                    seen_c1[i]
+                );
+                assert_eq!(
+                    seen_c2[i], 1,
+                    "OX c2: value {i} appears {} times",
+                    seen_c2[i]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ox_segment_preserved_exactly() {
+        // Deterministic: manually verify the segment is copied without modification.
+        // parent1 = [0,1,2,3,4,5,6,7], parent2 = [7,6,5,4,3,2,1,0]
+        // With rng seed producing segment [2, 5]:
+        // child1 segment at [2..=5] = p1[2..=5] = [2,3,4,5]
+        let p1 = vec![0usize, 1, 2, 3, 4, 5, 6, 7];
+        let p2 = vec![7usize, 6, 5, 4, 3, 2, 1, 0];
+
+        // Build child manually for segment [2,5]
+        let child = ox_build_child(&p1, &p2, 2, 5);
+
+        // Segment [2..=5] must match p1
+        assert_eq!(child[2], 2);
+        assert_eq!(child[3], 3);
+        assert_eq!(child[4], 4);
+        assert_eq!(child[5], 5);
+
+        // Must still be a valid permutation
+        assert!(
+            is_valid_permutation(&child, 8),
+            "manual OX child invalid: {child:?}"
+        );
+    }
+
+    // ---- ALNS weight update: numerical verification ----
+    //
+    // Formula: w_new = w·(1-r) + r·(π/θ)
+    // Reference: Ropke & Pisinger (2006), Eq. (1).
+
+    #[test]
+    fn test_alns_weight_update_formula() {
+        // Case 1: w=1.0, r=0.5, π=6, θ=3 → w_new = 1.0·0.5 + 0.5·(6/3) = 0.5 + 1.0 = 1.5
+        {
+            let w = 1.0_f64;
+            let r = 0.5_f64;
+            let pi = 6.0_f64;
+            let theta = 3.0_f64;
+            let w_new = w * (1.0 - r) + r * (pi / theta);
+            assert!(
+                (w_new - 1.5).abs() < 1e-12,
+                "w=1,r=0.5,π=6,θ=3: expected 1.5, got {w_new}"
+            );
+        }
+
+        // Case 2: w=1.0, r=0.5, π=0, θ=3 → w_new = 1.0·0.5 + 0.5·0 = 0.5
+        {
+            let w = 1.0_f64;
+            let r = 0.5_f64;
+            let pi = 0.0_f64;
+            let theta = 3.0_f64;
+            let w_new = w * (1.0 - r) + r * (pi / theta);
+            assert!(
+                (w_new - 0.5).abs() < 1e-12,
+                "w=1,r=0.5,π=0,θ=3: expected 0.5 (decay), got {w_new}"
+            );
+        }
+
+        // Case 3: r→1 (full replacement) → w_new = π/θ
+        {
+            let w = 999.0_f64; // doesn't matter
+            let r = 1.0_f64;
+            let pi = 4.0_f64;
+            let theta = 2.0_f64;
+            let w_new = w * (1.0 - r) + r * (pi / theta);
+            assert!(
+                (w_new - 2.0).abs() < 1e-12,
+                "r=1 should give w_new = π/θ = 2.0, got {w_new}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_alns_score_hierarchy() {
+        // sigma_1 > sigma_2 > sigma_3 > 0 must hold.
+        // Default config: 33 > 9 > 3 (Ropke & Pisinger 2006, Table 1).
+        let sigma1 = 33.0_f64; // new global best
+        let sigma2 = 9.0_f64; // improved current
+        let sigma3 = 3.0_f64; // accepted worse (SA)
+
+        assert!(
+            sigma1 > sigma2,
+            "sigma_1 must exceed sigma_2: {sigma1} <= {sigma2}"
+        );
+        assert!(
+            sigma2 > sigma3,
+            "sigma_2 must exceed sigma_3: {sigma2} <= {sigma3}"
+        );
+        assert!(sigma3 > 0.0, "sigma_3 must be positive: {sigma3}");
+    }
+}
@@ -0,0 +1,559 @@
+//! Real-coded genetic operators.
+//!
+//! Crossover and mutation operators for chromosomes of `f64` genes, each
+//! gene confined to its own `[lower, upper]` interval given by [`Bounds`].
+//! Every operator returns genes within the bounds when the parents are
+//! within them.
+//!
+//! # Crossover Operators
+//!
+//! - [`sbx_crossover`]: Simulated Binary Crossover, Deb & Agrawal (1995)
+//! - [`blx_alpha_crossover`]: BLX-α blend crossover, Eshelman & Schaffer (1993)
+//! - [`arithmetic_crossover`]: Whole arithmetic (convex) recombination
+//! - [`uniform_crossover`]: Gene-wise exchange
+//!
+//! # Mutation Operators
+//!
+//! - [`polynomial_mutation`]: Deb & Goyal (1996)
+//! - [`gaussian_mutation`]: Fixed per-gene step sizes
+//! - [`self_adaptive_gaussian_mutation`]: Step sizes evolve with the genes,
+//!   Schwefel (1995)
+//!
+//! # References
+//!
+//! - Deb & Agrawal (1995), "Simulated Binary Crossover for Continuous Search Space"
+//! - Deb & Goyal (1996), "A Combined Genetic Adaptive Search (GeneAS) for
+//!   Engineering Design"
+//! - Eshelman & Schaffer (1993), "Real-Coded Genetic Algorithms and
+//!   Interval-Schemata"
+//! - Schwefel (1995), *Evolution and Optimum Seeking*
+
+use rand::Rng;
+
+pub use crate::continuous::{BoundHandling, Bounds};
+
+/// Genes closer than this are treated as equal by SBX.
+const SBX_EPSILON: f64 = 1e-14;
+
+/// Lower limit for self-adapted step sizes.
+const MIN_SIGMA: f64 = 1e-10;
+
+// ============================================================================
+// Crossover operators
+// ============================================================================
+
+/// Simulated Binary Crossover (SBX), bounded form.
+///
+/// Spreads children around the parents with a distribution that mimics
+/// single-point crossover on binary strings. Larger `eta` keeps children
+/// closer to their parents; 2–20 is the usual range.
+///
+/// # Algorithm (Deb & Agrawal, 1995)
+///
+/// For each gene, with probability 1/2 (otherwise the parents' genes are
+/// copied):
+///
+/// 1. Draw the spread factor β from a polynomial distribution truncated so
+///    that both children stay within the gene's bounds
+/// 2. Place the children at `(x1 + x2)/2 ∓ β·|x2 − x1|/2`
+/// 3. Assign the two values to the children in random order
+///
+/// # Complexity
+/// O(n)
+///
+/// # Panics
+/// Panics if the parents and bounds have different lengths.
+pub fn sbx_crossover<R: Rng>(
+    parent1: &[f64],
+    parent2: &[f64],
+    bounds: &Bounds,
+    eta: f64,
+    rng: &mut R,
+) -> (Vec<f64>, Vec<f64>) {
+    check_lengths(parent1, parent2, bounds);
+
+    let mut child1 = parent1.to_vec();
+    let mut child2 = parent2.to_vec();
+
+    for (i, (&x1, &x2)) in parent1.iter().zip(parent2).enumerate() {
+        if rng.random_bool(0.5) || (x1 - x2).abs() <= SBX_EPSILON {
+            continue;
+        }
+        let (lo, hi) = (bounds.lower()[i], bounds.upper()[i]);
+        let (y1, y2) = (x1.min(x2), x1.max(x2));
+        let u = rng.random::<f64>();
+
+        let beta_q = sbx_spread(1.0 + 2.0 * (y1 - lo) / (y2 - y1), eta, u);
+        let c1 = 0.5 * ((y1 + y2) - beta_q * (y2 - y1));
+        let beta_q = sbx_spread(1.0 + 2.0 * (hi - y2) / (y2 - y1), eta, u);
+        let c2 = 0.5 * ((y1 + y2) + beta_q * (y2 - y1));
+
+        let (c1, c2) = (c1.clamp(lo, hi), c2.clamp(lo, hi));
+        if rng.random_bool(0.5) {
+            child1[i] = c2;
+            child2[i] = c1;
+        } else {
+            child1[i] = c1;
+            child2[i] = c2;
+        }
+    }
+
+    (child1, child2)
+}
+
+/// Inverse CDF of the SBX spread factor, truncated at `beta` (the largest
+/// spread that stays within bounds).
+fn sbx_spread(beta: f64, eta: f64, u: f64) -> f64 {
+    let alpha = 2.0 - beta.powf(-(eta + 1.0));
+    if u <= 1.0 / alpha {
+        (u * alpha).powf(1.0 / (eta + 1.0))
+    } else {
+        (1.0 / (2.0 - u * alpha)).powf(1.0 / (eta + 1.0))
+    }
+}
+
+/// BLX-α blend crossover.
+///
+/// Each child gene is drawn uniformly from the parents' interval
+/// `[min, max]` extended by `alpha · (max − min)` on both sides, then
+/// clamped to the bounds. `alpha = 0.5` is the common choice.
+///
+/// # Algorithm (Eshelman & Schaffer, 1993)
+///
+/// For each gene with `d = |x1 − x2|`, draw both children's genes from
+/// `U[min(x1, x2) − α·d, max(x1, x2) + α·d]`.
+///
+/// # Complexity
+/// O(n)
+///
+/// # Panics
+/// Panics if the parents and bounds have different lengths or `alpha` is
+/// negative.
+pub fn blx_alpha_crossover<R: Rng>(
+    parent1: &[f64],
+    parent2: &[f64],
+    bounds: &Bounds,
+    alpha: f64,
+    rng: &mut R,
+) -> (Vec<f64>, Vec<f64>) {
+    check_lengths(parent1, parent2, bounds);
+    assert!(alpha >= 0.0, "alpha must be non-negative");
+
+    let mut child1 = Vec::with_capacity(parent1.len());
+    let mut child2 = Vec::with_capacity(parent1.len());
+
+    for (i, (&x1, &x2)) in parent1.iter().zip(parent2).enumerate() {
+        let d = (x1 - x2).abs();
+        let lo = (x1.min(x2) - alpha * d).max(bounds.lower()[i]);
+        let hi = (x1.max(x2) + alpha * d).min(bounds.upper()[i]);
+        child1.push(lo + rng.random::<f64>() * (hi - lo));
+        child2.push(lo + rng.random::<f64>() * (hi - lo));
+    }
+
+    (child1, child2)
+}
+
+/// Whole arithmetic crossover.
+///
+/// Draws `λ ~ U[0, 1]` and returns `λ·p1 + (1 − λ)·p2` and
+/// `(1 − λ)·p1 + λ·p2`. Children lie on the segment between the parents,
+/// so they respect any bounds the parents respect.
+///
+/// # Complexity
+/// O(n)
+///
+/// # Panics
+/// Panics if the parents have different lengths.
+pub fn arithmetic_crossover<R: Rng>(
+    parent1: &[f64],
+    parent2: &[f64],
+    rng: &mut R,
+) -> (Vec<f64>, Vec<f64>) {
+    assert_eq!(
+        parent1.len(),
+        parent2.len(),
+        "parents must have equal length"
+    );
+
+    let lambda = rng.random::<f64>();
+    parent1
+        .iter()
+        .zip(parent2)
+        .map(|(&a, &b)| {
+            (
+                lambda * a + (1.0 - lambda) * b,
+                (1.0 - lambda) * a + lambda * b,
+            )
+        })
+        .unzip()
+}
+
+/// Uniform crossover: each gene is exchanged between the children with
+/// probability 1/2.
+///
+/// Children only contain parent genes, so they respect any bounds the
+/// parents respect.
+///
+/// # Complexity
+/// O(n)
+///
+/// # Panics
+/// Panics if the parents have different lengths.
+pub fn uniform_crossover<R: Rng>(
+    parent1: &[f64],
+    parent2: &[f64],
+    rng: &mut R,
+) -> (Vec<f64>, Vec<f64>) {
+    assert_eq!(
+        parent1.len(),
+        parent2.len(),
+        "parents must have equal length"
+    );
+
+    parent1
+        .iter()
+        .zip(parent2)
+        .map(|(&a, &b)| if rng.random_bool(0.5) { (b, a) } else { (a, b) })
+        .unzip()
+}
+
+// ============================================================================
+// Mutation operators
+// ============================================================================
+
+/// Polynomial mutation, bounded form.
+///
+/// Each gene mutates with probability `rate` (commonly `1/n`). The
+/// perturbation follows a polynomial distribution scaled to the gene's
+/// range; larger `eta` gives smaller steps (20 is typical).
+///
+/// # Algorithm (Deb & Goyal, 1996)
+///
+/// With `δ1, δ2` the normalized distances to the lower and upper bound and
+/// `u ~ U[0, 1]`:
+///
+/// - `u < 0.5`: `δq = (2u + (1 − 2u)(1 − δ1)^(η+1))^(1/(η+1)) − 1`
+/// - otherwise: `δq = 1 − (2(1 − u) + 2(u − 0.5)(1 − δ2)^(η+1))^(1/(η+1))`
+///
+/// and the gene moves by `δq · (upper − lower)`.
+///
+/// # Complexity
+/// O(n)
+///
+/// # Panics
+/// Panics if `genes` and `bounds` have different lengths.
+pub fn polynomial_mutation<R: Rng>(
+    genes: &mut [f64],
+    bounds: &Bounds,
+    eta: f64,
+    rate: f64,
+    rng: &mut R,
+) {
+    assert_eq!(
+        genes.len(),
+        bounds.len(),
+        "genes and bounds must have equal length"
+    );
+
+    let power = 1.0 / (eta + 1.0);
+    for (i, x) in genes.iter_mut().enumerate() {
+        let (lo, hi) = (bounds.lower()[i], bounds.upper()[i]);
+        if hi <= lo || rng.random::<f64>() >= rate {
+            continue;
+        }
+        let delta1 = (*x - lo) / (hi - lo);
+        let delta2 = (hi - *x) / (hi - lo);
+        let u = rng.random::<f64>();
+
+        let delta_q = if u < 0.5 {
+            let v = 2.0 * u + (1.0 - 2.0 * u) * (1.0 - delta1).powf(eta + 1.0);
+            v.powf(power) - 1.0
+        } else {
+            let v = 2.0 * (1.0 - u) + 2.0 * (u - 0.5) * (1.0 - delta2).powf(eta + 1.0);
+            1.0 - v.powf(power)
+        };
+        *x = (*x + delta_q * (hi - lo)).clamp(lo, hi);
+    }
+}
+
+/// Gaussian mutation with fixed step sizes.
+///
+/// Each gene mutates with probability `rate` by adding `sigma · (upper −
+/// lower) · N(0, 1)`, i.e. `sigma` is relative to the gene's range, and is
+/// then clamped to the bounds.
+///
+/// # Complexity
+/// O(n)
+///
+/// # Panics
+/// Panics if `genes` and `bounds` have different lengths.
+pub fn gaussian_mutation<R: Rng>(
+    genes: &mut [f64],
+    bounds: &Bounds,
+    sigma: f64,
+    rate: f64,
+    rng: &mut R,
+) {
+    assert_eq!(
+        genes.len(),
+        bounds.len(),
+        "genes and bounds must have equal length"
+    );
+
+    for (i, x) in genes.iter_mut().enumerate() {
+        if rng.random::<f64>() >= rate {
+            continue;
+        }
+        let (lo, hi) = (bounds.lower()[i], bounds.upper()[i]);
+        *x = (*x + sigma * (hi - lo) * standard_normal(rng)).clamp(lo, hi);
+    }
+}
+
+/// Gaussian mutation with self-adaptive step sizes.
+///
+/// `sigmas` holds one absolute step size per gene and is part of the
+/// individual: it is mutated first and then used to perturb the genes, so
+/// step sizes that produce good offspring survive with them. Crossover
+/// should recombine `sigmas` along with the genes.
+///
+/// # Algorithm (Schwefel, 1995)
+///
+/// With `τ' = 1/√(2n)` and `τ = 1/√(2√n)`:
+///
+/// 1. `σi ← σi · exp(τ'·N(0, 1) + τ·Ni(0, 1))`, where the first draw is
+///    shared by all genes; floored at a small positive value
+/// 2. `xi ← xi + σi · Ni(0, 1)`, clamped to the bounds
+///
+/// # Complexity
+/// O(n)
+///
+/// # Panics
+/// Panics if `genes`, `sigmas` and `bounds` have different lengths.
+pub fn self_adaptive_gaussian_mutation<R: Rng>(
+    genes: &mut [f64],
+    sigmas: &mut [f64],
+    bounds: &Bounds,
+    rng: &mut R,
+) {
+    assert_eq!(
+        genes.len(),
+        bounds.len(),
+        "genes and bounds must have equal length"
+    );
+    assert_eq!(
+        genes.len(),
+        sigmas.len(),
+        "genes and sigmas must have equal length"
+    );
+    if genes.is_empty() {
+        return;
+    }
+
+    let n = genes.len() as f64;
+    let tau_global = 1.0 / (2.0 * n).sqrt();
+    let tau_local = 1.0 / (2.0 * n.sqrt()).sqrt();
+    let global = tau_global * standard_normal(rng);
+
+    for (i, (x, sigma)) in genes.iter_mut().zip(sigmas.iter_mut()).enumerate() {
+        *sigma = (*sigma * (global + tau_local * standard_normal(rng)).exp()).max(MIN_SIGMA);
+        *x = (*x + *sigma * standard_normal(rng)).clamp(bounds.lower()[i], bounds.upper()[i]);
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+/// Panics unless both parents match the bounds' length.
+fn check_lengths(parent1: &[f64], parent2: &[f64], bounds: &Bounds) {
+    assert_eq!(
+        parent1.len(),
+        parent2.len(),
+        "parents must have equal length"
+    );
+    assert_eq!(
+        parent1.len(),
+        bounds.len(),
+        "parents and bounds must have equal length"
+    );
+}
+
+/// Draws from N(0, 1) with the Box–Muller transform.
+pub(crate) fn standard_normal<R: Rng>(rng: &mut R) -> f64 {
+    // 1 - U[0, 1) lies in (0, 1], keeping the logarithm finite
+    let u1 = 1.0 - rng.random::<f64>();
+    let u2 = rng.random::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use u_numflow::random::create_rng;
+
+    fn within(genes: &[f64], bounds: &Bounds) -> bool {
+        genes
+            .iter()
+            .zip(bounds.lower())
+            .zip(bounds.upper())
+            .all(|((x, lo), hi)| lo <= x && x <= hi)
+    }
+
+    fn test_bounds() -> Bounds {
+        Bounds::new(vec![-5.0, 0.0, 10.0, -1.0], vec![5.0, 1.0, 20.0, -1.0])
+    }
+
+    // ---- Crossover ----
+
+    #[test]
+    fn test_crossovers_stay_within_bounds() {
+        let mut rng = create_rng(42);
+        let bounds = test_bounds();
+
+        for _ in 0..200 {
+            let p1 = bounds.sample(&mut rng);
+            let p2 = bounds.sample(&mut rng);
+            let children = [
+                ("SBX", sbx_crossover(&p1, &p2, &bounds, 2.0, &mut rng)),
+                ("BLX", blx_alpha_crossover(&p1, &p2, &bounds, 0.5, &mut rng)),
+                ("arithmetic", arithmetic_crossover(&p1, &p2, &mut rng)),
+                ("uniform", uniform_crossover(&p1, &p2, &mut rng)),
+            ];
+            for (name, (c1, c2)) in &children {
+                assert!(within(c1, &bounds), "{name} child1 out of bounds: {c1:?}");
+                assert!(within(c2, &bounds), "{name} child2 out of bounds: {c2:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_sbx_identical_parents() {
+        let mut rng = create_rng(42);
+        let bounds = test_bounds();
+        let p = vec![1.0, 0.25, 15.0, -1.0];
+        let (c1, c2) = sbx_crossover(&p, &p, &bounds, 15.0, &mut rng);
+        assert_eq!(c1, p);
+        assert_eq!(c2, p);
+    }
+
+    #[test]
+    fn test_sbx_large_eta_stays_near_parents() {
+        let mut rng = create_rng(7);
+        let bounds = Bounds::uniform(1, -100.0, 100.0);
+        for _ in 0..100 {
+            let (c1, c2) = sbx_crossover(&[-1.0], &[1.0], &bounds, 1000.0, &mut rng);
+            for c in [c1[0], c2[0]] {
+                assert!((c.abs() - 1.0).abs() < 0.05, "SBX child too far: {c}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_blx_zero_alpha_stays_between_parents() {
+        let mut rng = create_rng(42);
+        let bounds = Bounds::uniform(3, -10.0, 10.0);
+        let p1 = vec![-1.0, 2.0, 5.0];
+        let p2 = vec![1.0, 3.0, 5.0];
+        for _ in 0..100 {
+            let (c1, c2) = blx_alpha_crossover(&p1, &p2, &bounds, 0.0, &mut rng);
+            for c in [&c1, &c2] {
+                assert!((-1.0..=1.0).contains(&c[0]));
+                assert!((2.0..=3.0).contains(&c[1]));
+                assert_eq!(c[2], 5.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_preserves_gene_sums() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0.0, 4.0, -2.0];
+        let p2 = vec![2.0, 0.0, 6.0];
+        let (c1, c2) = arithmetic_crossover(&p1, &p2, &mut rng);
+        for (sum, parent_sum) in c1.iter().zip(&c2).map(|(a, b)| a + b).zip([2.0, 4.0, 4.0]) {
+            assert!((sum - parent_sum).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_uniform_exchanges_parent_genes() {
+        let mut rng = create_rng(42);
+        let p1 = vec![0.0, 1.0, 2.0, 3.0];
+        let p2 = vec![10.0, 11.0, 12.0, 13.0];
+        let (c1, c2) = uniform_crossover(&p1, &p2, &mut rng);
+        for i in 0..4 {
+            assert!(
+                (c1[i], c2[i]) == (p1[i], p2[i]) || (c1[i], c2[i]) == (p2[i], p1[i]),
+                "gene {i} not exchanged intact"
+            );
+        }
+    }
+
+    // ---- Mutation ----
+
+    #[test]
+    fn test_mutations_stay_within_bounds() {
+        let mut rng = create_rng(42);
+        let bounds = test_bounds();
+
+        for _ in 0..200 {
+            let mut a = bounds.sample(&mut rng);
+            let mut b = a.clone();
+            let mut c = a.clone();
+            let mut sigmas = vec![1.0; 4];
+            polynomial_mutation(&mut a, &bounds, 20.0, 1.0, &mut rng);
+            gaussian_mutation(&mut b, &bounds, 0.5, 1.0, &mut rng);
+            self_adaptive_gaussian_mutation(&mut c, &mut sigmas, &bounds, &mut rng);
+            assert!(within(&a, &bounds), "polynomial out of bounds: {a:?}");
+            assert!(within(&b, &bounds), "gaussian out of bounds: {b:?}");
+            assert!(within(&c, &bounds), "self-adaptive out of bounds: {c:?}");
+            assert!(sigmas.iter().all(|&s| s >= MIN_SIGMA));
+        }
+    }
+
+    #[test]
+    fn test_mutations_zero_rate_leave_genes_unchanged() {
+        let mut rng = create_rng(42);
+        let bounds = test_bounds();
+        let original = vec![1.0, 0.5, 12.0, -1.0];
+
+        let mut genes = original.clone();
+        polynomial_mutation(&mut genes, &bounds, 20.0, 0.0, &mut rng);
+        gaussian_mutation(&mut genes, &bounds, 0.5, 0.0, &mut rng);
+        assert_eq!(genes, original);
+    }
+
+    #[test]
+    fn test_polynomial_mutation_changes_genes() {
+        let mut rng = create_rng(42);
+        let bounds = Bounds::uniform(5, 0.0, 1.0);
+        let mut genes = vec![0.5; 5];
+        polynomial_mutation(&mut genes, &bounds, 20.0, 1.0, &mut rng);
+        assert!(genes.iter().any(|&x| x != 0.5));
+    }
+
+    #[test]
+    fn test_self_adaptive_sigmas_evolve() {
+        let mut rng = create_rng(42);
+        let bounds = Bounds::uniform(4, -10.0, 10.0);
+        let mut genes = vec![0.0; 4];
+        let mut sigmas = vec![1.0; 4];
+        self_adaptive_gaussian_mutation(&mut genes, &mut sigmas, &bounds, &mut rng);
+        assert!(sigmas.iter().all(|&s| s > 0.0 && s != 1.0));
+    }
+
+    #[test]
+    fn test_standard_normal_moments() {
+        let mut rng = create_rng(42);
+        let n = 20_000;
+        let samples: Vec<f64> = (0..n).map(|_| standard_normal(&mut rng)).collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let var = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+        assert!(mean.abs() < 0.05, "mean {mean}");
+        assert!((var - 1.0).abs() < 0.05, "variance {var}");
+    }
+}
@@ -0,0 +1,98 @@
+//! Replacement strategies for the GA.
+//!
+//! Replacement decides how offspring enter the population: all at once
+//! (generational), one at a time (steady-state), one at a time as
+//! concurrent evaluations complete (asynchronous), or by truncation over
+//! parents and offspring ((μ+λ)) or offspring only ((μ,λ)).
+//!
+//! # References
+//!
+//! - Whitley (1989), "The GENITOR Algorithm and Selection Pressure"
+//!   (steady-state replace-worst)
+//! - Schwefel (1981), *Numerical Optimization of Computer Models*
+//!   ((μ+λ) and (μ,λ) selection)
+//! - Syswerda (1991), "A Study of Reproduction in Generational and
+//!   Steady-State Genetic Algorithms"
+//! - Scott & De Jong (2015), "Understanding Simple Asynchronous
+//!   Evolutionary Algorithms"
+
+/// How offspring replace the current population.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::ga::{GaConfig, Replacement, SteadyStatePolicy};
+///
+/// let config = GaConfig::default()
+///     .with_replacement(Replacement::SteadyState(SteadyStatePolicy::ReplaceWorst));
+/// assert!(config.validate().is_ok());
+///
+/// let config = GaConfig::default()
+///     .with_population_size(20)
+///     .with_replacement(Replacement::MuCommaLambda(140));
+/// assert!(config.validate().is_ok());
+///
+/// let config = GaConfig::default().with_replacement(Replacement::Asynchronous {
+///     workers: 8,
+///     policy: SteadyStatePolicy::ReplaceWorst,
+/// });
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Replacement {
+    /// Offspring replace the whole population except the elite fraction
+    /// (`elite_ratio`).
+    #[default]
+    Generational,
+
+    /// Offspring are evaluated and inserted one at a time, so later parents
+    /// can already be new offspring. A generation still produces
+    /// `population_size` offspring.
+    ///
+    /// Evaluation is sequential; `parallel` has no effect.
+    SteadyState(SteadyStatePolicy),
+
+    /// Steady-state replacement with up to `workers` evaluations in flight.
+    ///
+    /// Whenever an evaluation completes, its offspring is inserted by
+    /// `policy` and a new offspring is bred from the population as it is
+    /// then, so one slow evaluation does not hold up the others. A
+    /// generation still produces `population_size` offspring and waits for
+    /// all of them.
+    ///
+    /// With `parallel` set (and the `parallel` feature enabled) the
+    /// evaluations run on `workers` threads and complete out of order, so
+    /// runs are not reproducible from the seed. Otherwise they complete in
+    /// submission order. Offspring are evaluated one at a time with
+    /// [`GaProblem::evaluate`](super::GaProblem::evaluate); `batch_size`
+    /// has no effect.
+    Asynchronous {
+        /// Maximum number of offspring being evaluated at once (≥ 1).
+        workers: usize,
+        /// Which individual a completed offspring replaces.
+        policy: SteadyStatePolicy,
+    },
+
+    /// (μ+λ): breed λ offspring, then keep the best μ of parents and
+    /// offspring together. Elitist by construction.
+    MuPlusLambda(usize),
+
+    /// (μ,λ): breed λ ≥ μ offspring and keep the best μ of the offspring
+    /// only. Parents never survive, which helps escape local optima; the
+    /// best-so-far solution is still reported.
+    MuCommaLambda(usize),
+}
+
+/// Which individual a steady-state offspring replaces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SteadyStatePolicy {
+    /// Replace the current worst individual.
+    #[default]
+    ReplaceWorst,
+
+    /// Replace a uniformly chosen individual other than the current best.
+    ReplaceRandom,
+
+    /// Replace the offspring's own parent, only if the offspring is better.
+    ParentIfBetter,
+}
@@ -0,0 +1,146 @@
+//! Restarts on stagnation for the GA.
+//!
+//! With [`GaConfig::restart`](super::GaConfig::restart) set, reaching the
+//! stagnation limit restarts the population instead of ending the run, up
+//! to [`Restart::max_restarts`] times. The best individuals survive; the
+//! rest are re-randomized or, as in CHC's cataclysmic mutation, replaced
+//! by heavily mutated copies of the survivors. `max_generations` remains
+//! the budget of the whole run, restarts included.
+//!
+//! # References
+//!
+//! - Eshelman (1991), "The CHC Adaptive Search Algorithm: How to Have Safe
+//!   Search When Engaging in Nontraditional Genetic Recombination"
+
+use super::config::GaConfig;
+use super::constraint::feasibility_cmp;
+use super::runner::{evaluate_population, FitnessCache};
+use super::types::GaProblem;
+use rand::Rng;
+
+/// How the individuals that do not survive a restart are replaced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RestartMode {
+    /// New individuals from
+    /// [`GaProblem::create_individual`](super::GaProblem::create_individual).
+    #[default]
+    Randomize,
+
+    /// Copies of randomly chosen survivors, each mutated `rounds` times
+    /// with [`GaProblem::mutate`](super::GaProblem::mutate).
+    HeavyMutation {
+        /// Mutations applied to each copy, at least 1.
+        rounds: usize,
+    },
+}
+
+/// Restart settings for a GA run.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::ga::{GaConfig, Restart, RestartMode};
+///
+/// let config = GaConfig::default()
+///     .with_stagnation_limit(30)
+///     .with_restart(Restart {
+///         keep: 2,
+///         mode: RestartMode::HeavyMutation { rounds: 10 },
+///         max_restarts: 5,
+///     });
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Restart {
+    /// Number of best individuals that survive a restart, at least 1 and
+    /// less than the population size.
+    pub keep: usize,
+    /// How the other individuals are replaced.
+    pub mode: RestartMode,
+    /// Maximum number of restarts, at least 1. Once they are used up, the
+    /// next stagnation ends the run.
+    pub max_restarts: usize,
+}
+
+impl Restart {
+    pub(super) fn validate(&self, population_size: usize) -> Result<(), String> {
+        if self.keep == 0 || self.keep >= population_size {
+            return Err(format!(
+                "restart keep must be in 1..population_size ({population_size}), got {}",
+                self.keep
+            ));
+        }
+        if self.max_restarts == 0 {
+            return Err("max_restarts must be at least 1".into());
+        }
+        if self.mode == (RestartMode::HeavyMutation { rounds: 0 }) {
+            return Err("heavy mutation rounds must be at least 1".into());
+        }
+        Ok(())
+    }
+}
+
+/// Keeps the `restart.keep` best individuals of `population` and replaces
+/// the others by evaluated new ones.
+pub(super) fn restart_population<P: GaProblem, R: Rng>(
+    problem: &P,
+    population: &mut Vec<P::Individual>,
+    restart: &Restart,
+    config: &GaConfig,
+    cache: &FitnessCache<P::Individual>,
+    rng: &mut R,
+) {
+    population.sort_by(|a, b| feasibility_cmp(a, b, 0.0, config.direction));
+    let keep = restart.keep.min(population.len());
+    let fresh: Vec<P::Individual> = (keep..population.len())
+        .map(|_| match restart.mode {
+            RestartMode::Randomize => problem.create_individual(rng),
+            RestartMode::HeavyMutation { rounds } => {
+                let mut ind = population[rng.random_range(0..keep)].clone();
+                for _ in 0..rounds {
+                    problem.mutate(&mut ind, rng);
+                }
+                ind
+            }
+        })
+        .collect();
+    population.truncate(keep);
+    population.extend(fresh);
+    evaluate_population(problem, &mut population[keep..], config, cache);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        let restart = Restart {
+            keep: 1,
+            mode: RestartMode::Randomize,
+            max_restarts: 3,
+        };
+        assert!(restart.validate(10).is_ok());
+        assert!(Restart { keep: 0, ..restart }.validate(10).is_err());
+        assert!(Restart {
+            keep: 10,
+            ..restart
+        }
+        .validate(10)
+        .is_err());
+        assert!(Restart {
+            max_restarts: 0,
+            ..restart
+        }
+        .validate(10)
+        .is_err());
+        assert!(Restart {
+            mode: RestartMode::HeavyMutation { rounds: 0 },
+            ..restart
+        }
+        .validate(10)
+        .is_err());
+    }
+}
@@ -3,32 +3,58 @@
 //! [`GaRunner`] orchestrates the complete evolutionary process:
 //! initialization → evaluation → selection → crossover → mutation → repeat.
 
+use super::adaptive::{better_parent, OperatorControl, OperatorUsage, Provenance};
 use super::config::GaConfig;
+use super::constraint::{feasibility_cmp, ConstraintState};
+use super::engine::{GaEngine, GaState};
+use super::memetic::LearningMode;
+use super::niching::{closest, duplicate_indices, mean_pairwise_distance, shared_keys, Niching};
+use super::replacement::{Replacement, SteadyStatePolicy};
+use super::selection::{ParentSampler, Selection, SelectionContext};
 use super::types::{Fitness, GaProblem, Individual};
+use crate::cache::LruCache;
+use crate::direction::Direction;
+use crate::hall_of_fame::HallOfFame;
+use crate::observer::{Observer, TerminationReason};
 use rand::Rng;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
+use std::borrow::Borrow;
+use std::collections::VecDeque;
+#[cfg(feature = "parallel")]
+use std::panic::AssertUnwindSafe;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+#[cfg(feature = "parallel")]
+use std::sync::{mpsc, Mutex};
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::Instant;
-use u_numflow::random::create_rng;
+use u_numflow::random::shuffle;
 
 /// Per-generation population statistics.
 ///
 /// Captures fitness distribution metrics for a single generation.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GenerationStats {
     /// Generation number (0-based).
     pub generation: usize,
-    /// Best (lowest) fitness in the population.
+    /// Best fitness in the population, the lowest unless maximizing.
     pub best_fitness: f64,
-    /// Worst (highest) fitness in the population.
+    /// Worst fitness in the population, the highest unless maximizing.
     pub worst_fitness: f64,
     /// Mean fitness of the population.
     pub mean_fitness: f64,
     /// Standard deviation of fitness values.
     pub std_dev: f64,
+    /// Mean pairwise [`GaProblem::distance`](super::GaProblem::distance)
+    /// in the population, a measure of diversity.
+    ///
+    /// Only computed with [`GaConfig::niching`](super::GaConfig::niching)
+    /// or [`GaConfig::diversity_stats`](super::GaConfig::diversity_stats),
+    /// 0 otherwise. Always 0 for BRKGA.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub diversity: f64,
 }
 
 /// Result of a GA optimization run.
@@ -43,9 +69,20 @@ pub struct GaResult<I: Individual> {
     /// Best fitness value (same as `best.fitness()`).
     pub best_fitness: I::Fitness,
 
+    /// The best distinct individuals seen in the population, best first.
+    ///
+    /// Empty unless [`GaConfig::hall_of_fame`](super::GaConfig::hall_of_fame)
+    /// is set. Entries are more than `hall_of_fame_distance` apart by
+    /// [`GaProblem::distance`](super::GaProblem::distance).
+    pub hall_of_fame: Vec<I>,
+
     /// Total number of generations executed.
     pub generations: usize,
 
+    /// Number of restarts on stagnation (see
+    /// [`GaConfig::restart`](super::GaConfig::restart)).
+    pub restarts: usize,
+
     /// Whether the run was terminated due to stagnation.
     pub stagnated: bool,
 
@@ -62,6 +99,23 @@ pub struct GaResult<I: Individual> {
     ///
     /// Empty unless the run completes at least one generation.
     pub generation_stats: Vec<GenerationStats>,
+
+    /// Evaluations answered by the fitness cache.
+    ///
+    /// Always 0 unless `cache_capacity > 0` and the problem implements
+    /// [`GaProblem::individual_hash`](super::GaProblem::individual_hash).
+    pub cache_hits: usize,
+
+    /// Evaluations that missed the fitness cache and called `evaluate`.
+    pub cache_misses: usize,
+
+    /// Usage of each crossover operator registered by
+    /// [`GaProblem::crossover_operators`](super::GaProblem::crossover_operators).
+    pub crossover_usage: Vec<OperatorUsage>,
+
+    /// Usage of each mutation operator registered by
+    /// [`GaProblem::mutation_operators`](super::GaProblem::mutation_operators).
+    pub mutation_usage: Vec<OperatorUsage>,
 }
 
 /// Executes the GA evolutionary loop.
@@ -101,425 +155,2374 @@ impl GaRunner {
         config: &GaConfig,
         cancel: Option<Arc<AtomicBool>>,
     ) -> Result<GaResult<P::Individual>, String> {
-        config.validate()?;
+        Self::run_with_seeds(problem, config, Vec::new(), cancel)
+    }
 
-        let mut rng = match config.seed {
-            Some(seed) => create_rng(seed),
-            None => create_rng(rand::random()),
-        };
+    /// Runs the GA, reporting progress events to `observer`.
+    ///
+    /// See [`GaEngine::with_observer`] for the events emitted; the final
+    /// [`Event::Terminated`](crate::observer::Event::Terminated) also
+    /// reports cancellation and time-outs.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid.
+    pub fn run_observed<P: GaProblem>(
+        problem: &P,
+        config: &GaConfig,
+        cancel: Option<Arc<AtomicBool>>,
+        observer: &mut dyn Observer,
+    ) -> Result<GaResult<P::Individual>, String> {
+        let engine = GaEngine::new(problem, config)?.with_observer(observer);
+        Ok(drive(engine, config, cancel))
+    }
 
-        // 1. Initialize population
-        let mut population: Vec<P::Individual> = (0..config.population_size)
-            .map(|_| problem.create_individual(&mut rng))
-            .collect();
+    /// Runs the GA starting from the given seed individuals.
+    ///
+    /// The initial population is `seeds`, followed by
+    /// [`GaProblem::seed_individuals`], filled up with random individuals
+    /// from [`GaProblem::create_individual`]. Seeds beyond
+    /// `population_size` are dropped. Seeds are (re-)evaluated, so their
+    /// stored fitness need not be set.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid.
+    pub fn run_with_seeds<P: GaProblem>(
+        problem: &P,
+        config: &GaConfig,
+        seeds: Vec<P::Individual>,
+        cancel: Option<Arc<AtomicBool>>,
+    ) -> Result<GaResult<P::Individual>, String> {
+        let engine = GaEngine::with_seeds(problem, config, seeds)?;
+        Ok(drive(engine, config, cancel))
+    }
 
-        // 2. Evaluate initial population
-        evaluate_population(problem, &mut population, config.parallel);
+    /// Continues a run from a checkpoint taken by [`GaProblem::on_checkpoint`].
+    ///
+    /// `config` should be the configuration of the interrupted run;
+    /// `max_generations` may be raised to extend it. The run ends as if it
+    /// had never been interrupted.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration is invalid, or the state's
+    /// population or operators do not match `population_size` and the
+    /// problem.
+    pub fn resume_from<P: GaProblem>(
+        problem: &P,
+        config: &GaConfig,
+        state: GaState<P::Individual>,
+        cancel: Option<Arc<AtomicBool>>,
+    ) -> Result<GaResult<P::Individual>, String> {
+        let engine = GaEngine::resume_from(problem, config, state)?;
+        Ok(drive(engine, config, cancel))
+    }
+}
 
-        // 3. Track best
-        let mut best = find_best(&population).clone();
-        let mut fitness_history = Vec::with_capacity(config.max_generations);
-        fitness_history.push(best.fitness().to_f64());
+/// Steps `engine` until it finishes, is cancelled or runs out of time.
+fn drive<P: GaProblem>(
+    mut engine: GaEngine<'_, P>,
+    config: &GaConfig,
+    cancel: Option<Arc<AtomicBool>>,
+) -> GaResult<P::Individual> {
+    let mut cancelled = false;
+    #[allow(unused_mut)]
+    let mut timed_out = false;
+    #[cfg(not(target_arch = "wasm32"))]
+    let start_time = Instant::now();
+
+    while !engine.is_finished() {
+        // Check cancellation
+        if let Some(ref flag) = cancel {
+            if flag.load(Ordering::Relaxed) {
+                cancelled = true;
+                break;
+            }
+        }
 
-        let mut stagnation_counter = 0usize;
-        let mut cancelled = false;
-        #[allow(unused_mut)]
-        let mut timed_out = false;
-        let mut generation_stats = Vec::with_capacity(config.max_generations);
+        // Check time limit (not available on WASM — no std::time::Instant)
         #[cfg(not(target_arch = "wasm32"))]
-        let start_time = Instant::now();
-
-        // Record initial population stats
-        generation_stats.push(compute_generation_stats(&population, 0));
-
-        // 4. Evolutionary loop
-        for gen in 0..config.max_generations {
-            // Check cancellation
-            if let Some(ref flag) = cancel {
-                if flag.load(Ordering::Relaxed) {
-                    cancelled = true;
-                    break;
-                }
+        if let Some(limit_ms) = config.time_limit_ms {
+            if start_time.elapsed().as_millis() as u64 >= limit_ms {
+                timed_out = true;
+                break;
             }
+        }
 
-            // Check time limit (not available on WASM — no std::time::Instant)
-            #[cfg(not(target_arch = "wasm32"))]
-            if let Some(limit_ms) = config.time_limit_ms {
-                if start_time.elapsed().as_millis() as u64 >= limit_ms {
-                    timed_out = true;
-                    break;
-                }
-            }
+        engine.step();
+    }
 
-            // Sort population by fitness (ascending = best first)
-            population.sort_by(|a, b| {
-                a.fitness()
-                    .partial_cmp(&b.fitness())
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
+    engine.terminate(if cancelled {
+        TerminationReason::Cancelled
+    } else if timed_out {
+        TerminationReason::TimedOut
+    } else {
+        engine.finish_reason()
+    });
+
+    let mut result = engine.into_result();
+    result.cancelled = cancelled;
+    result.timed_out = timed_out;
+    result
+}
+
+/// Builds an unevaluated initial population: explicit seeds, then the
+/// problem's seeds, then random individuals.
+pub(super) fn initial_population<P: GaProblem, R: Rng>(
+    problem: &P,
+    size: usize,
+    mut seeds: Vec<P::Individual>,
+    rng: &mut R,
+) -> Vec<P::Individual> {
+    if seeds.len() < size {
+        seeds.extend(problem.seed_individuals(rng));
+    }
+    seeds.truncate(size);
+    while seeds.len() < size {
+        seeds.push(problem.create_individual(rng));
+    }
+    seeds
+}
+
+/// Advances `population` by one generation according to
+/// `config.niching` or `config.replacement`, eliminates duplicates if
+/// configured, then advances the constraint handler and the adaptive
+/// operator control.
+pub(super) fn next_generation<P: GaProblem, R: Rng>(
+    problem: &P,
+    population: &mut Vec<P::Individual>,
+    config: &GaConfig,
+    constraints: &mut ConstraintState,
+    control: &mut OperatorControl,
+    cache: &FitnessCache<P::Individual>,
+    rng: &mut R,
+) {
+    control.observe(population, config);
+    match (config.niching, config.replacement) {
+        (Niching::DeterministicCrowding, _) => deterministic_crowding(
+            problem,
+            population,
+            config,
+            constraints,
+            control,
+            cache,
+            rng,
+        ),
+        (Niching::RestrictedTournament { window }, _) => restricted_tournament(
+            problem,
+            population,
+            window,
+            config,
+            constraints,
+            control,
+            cache,
+            rng,
+        ),
+        (_, Replacement::Generational) => {
+            let keys = rank_population(population, constraints, rng);
 
             // Elite preservation
             let elite_count = (config.population_size as f64 * config.elite_ratio) as usize;
-            let mut next_gen: Vec<P::Individual> = population[..elite_count].to_vec();
+            let (offspring, provenance) = breed(
+                problem,
+                population,
+                keys.as_deref(),
+                config.population_size - elite_count,
+                constraints.generation(),
+                config,
+                control,
+                rng,
+            );
+            population.truncate(elite_count);
+            population.extend(offspring);
+
+            // Evaluate new individuals (skip elites, they're already evaluated)
+            evaluate_population(problem, &mut population[elite_count..], config, cache);
+            credit_offspring(control, &population[elite_count..], &provenance, config);
+            improve_offspring(
+                problem,
+                &mut population[elite_count..],
+                config.parallel,
+                config,
+                cache,
+                rng,
+            );
+        }
+        (_, Replacement::SteadyState(policy)) => steady_state(
+            problem,
+            population,
+            policy,
+            config,
+            constraints,
+            control,
+            cache,
+            rng,
+        ),
+        (_, Replacement::Asynchronous { workers, policy }) => asynchronous(
+            problem,
+            population,
+            workers,
+            policy,
+            config,
+            constraints,
+            control,
+            cache,
+            rng,
+        ),
+        (_, Replacement::MuPlusLambda(lambda)) => {
+            let keys = constraints.keys(population, rng);
+            let (mut offspring, provenance) = breed(
+                problem,
+                population,
+                keys.as_deref(),
+                lambda,
+                constraints.generation(),
+                config,
+                control,
+                rng,
+            );
+            evaluate_population(problem, &mut offspring, config, cache);
+            credit_offspring(control, &offspring, &provenance, config);
+            improve_offspring(problem, &mut offspring, config.parallel, config, cache, rng);
+            population.extend(offspring);
+            rank_population(population, constraints, rng);
+            population.truncate(config.population_size);
+        }
+        (_, Replacement::MuCommaLambda(lambda)) => {
+            let keys = constraints.keys(population, rng);
+            let (mut offspring, provenance) = breed(
+                problem,
+                population,
+                keys.as_deref(),
+                lambda,
+                constraints.generation(),
+                config,
+                control,
+                rng,
+            );
+            evaluate_population(problem, &mut offspring, config, cache);
+            credit_offspring(control, &offspring, &provenance, config);
+            improve_offspring(problem, &mut offspring, config.parallel, config, cache, rng);
+            rank_population(&mut offspring, constraints, rng);
+            offspring.truncate(config.population_size);
+            *population = offspring;
+        }
+    }
+    if config.eliminate_duplicates {
+        replace_duplicates(problem, population, config, cache, rng);
+    }
+    constraints.end_generation(population);
+    control.end_generation(config);
+}
 
-            // Generate offspring
-            while next_gen.len() < config.population_size {
-                // Selection
-                let p1_idx = config.selection.select(&population, &mut rng);
-                let p2_idx = config.selection.select(&population, &mut rng);
+/// Shared selection keys under fitness sharing, otherwise `None`.
+///
+/// `keys` are the constraint handler's keys, if any.
+fn sharing_keys<P: GaProblem>(
+    problem: &P,
+    population: &[P::Individual],
+    keys: Option<&[f64]>,
+    config: &GaConfig,
+) -> Option<Vec<f64>> {
+    match config.niching {
+        Niching::FitnessSharing { sigma, alpha } => {
+            Some(shared_keys(problem, population, keys, sigma, alpha))
+        }
+        _ => None,
+    }
+}
 
-                // Crossover
-                let children = if rng.random_range(0.0..1.0) < config.crossover_rate {
-                    problem.crossover(&population[p1_idx], &population[p2_idx], &mut rng)
+/// Deterministic crowding: random pairs of parents breed, and each child
+/// replaces the more similar parent if it is better.
+fn deterministic_crowding<P: GaProblem, R: Rng>(
+    problem: &P,
+    population: &mut [P::Individual],
+    config: &GaConfig,
+    constraints: &ConstraintState,
+    control: &mut OperatorControl,
+    cache: &FitnessCache<P::Individual>,
+    rng: &mut R,
+) {
+    let mut order: Vec<usize> = (0..population.len()).collect();
+    shuffle(&mut order, rng);
+
+    // Breed every pair; an odd one out mates with a random partner
+    let mut families = Vec::with_capacity(order.len() / 2 + 1);
+    let mut offspring = Vec::with_capacity(order.len());
+    let mut provenance = Vec::with_capacity(order.len());
+    for pair in order.chunks(2) {
+        let a = pair[0];
+        let b = match pair.get(1) {
+            Some(&b) => b,
+            None => rng.random_range(0..population.len()),
+        };
+        let (children, origin, mutation_rate) = recombine(
+            problem,
+            &population[a],
+            &population[b],
+            config,
+            control,
+            rng,
+        );
+        let count = children.len().min(2);
+        for mut child in children.into_iter().take(2) {
+            let mutation = mutate_child(problem, &mut child, mutation_rate, control, rng);
+            offspring.push(child);
+            provenance.push(Provenance { mutation, ..origin });
+        }
+        families.push((a, b, count));
+    }
+    evaluate_population(problem, &mut offspring, config, cache);
+    credit_offspring(control, &offspring, &provenance, config);
+    improve_offspring(problem, &mut offspring, config.parallel, config, cache, rng);
+
+    let mut children = offspring.into_iter();
+    for (a, b, count) in families {
+        let first = if count >= 1 { children.next() } else { None };
+        let second = if count == 2 { children.next() } else { None };
+        let contests = match (first, second) {
+            (Some(c1), Some(c2)) => {
+                let d = |x: usize, c: &P::Individual| problem.distance(&population[x], c);
+                if d(a, &c1) + d(b, &c2) <= d(a, &c2) + d(b, &c1) {
+                    vec![(a, c1), (b, c2)]
                 } else {
-                    vec![population[p1_idx].clone()]
-                };
+                    vec![(a, c2), (b, c1)]
+                }
+            }
+            (Some(c1), None) => {
+                let nearer = closest(problem, &c1, population, &[a, b]);
+                vec![(nearer, c1)]
+            }
+            _ => Vec::new(),
+        };
+        for (parent, child) in contests {
+            if constraints.less(&child, &population[parent]) {
+                population[parent] = child;
+            }
+        }
+    }
+}
 
-                for mut child in children {
-                    if next_gen.len() >= config.population_size {
-                        break;
-                    }
+/// Restricted tournament selection: each child replaces the most similar
+/// of `window` random individuals if it is better.
+#[allow(clippy::too_many_arguments)]
+fn restricted_tournament<P: GaProblem, R: Rng>(
+    problem: &P,
+    population: &mut [P::Individual],
+    window: usize,
+    config: &GaConfig,
+    constraints: &ConstraintState,
+    control: &mut OperatorControl,
+    cache: &FitnessCache<P::Individual>,
+    rng: &mut R,
+) {
+    let keys = constraints.keys(population, rng);
+    let (mut offspring, provenance) = breed(
+        problem,
+        population,
+        keys.as_deref(),
+        config.population_size,
+        constraints.generation(),
+        config,
+        control,
+        rng,
+    );
+    evaluate_population(problem, &mut offspring, config, cache);
+    credit_offspring(control, &offspring, &provenance, config);
+    improve_offspring(problem, &mut offspring, config.parallel, config, cache, rng);
+
+    for child in offspring {
+        let candidates: Vec<usize> = (0..window)
+            .map(|_| rng.random_range(0..population.len()))
+            .collect();
+        let nearest = closest(problem, &child, population, &candidates);
+        if constraints.less(&child, &population[nearest]) {
+            population[nearest] = child;
+        }
+    }
+}
 
-                    // Mutation
-                    if rng.random_range(0.0..1.0) < config.mutation_rate {
-                        problem.mutate(&mut child, &mut rng);
-                    }
+/// Replaces every duplicate individual with a random, evaluated one.
+fn replace_duplicates<P: GaProblem, R: Rng>(
+    problem: &P,
+    population: &mut [P::Individual],
+    config: &GaConfig,
+    cache: &FitnessCache<P::Individual>,
+    rng: &mut R,
+) {
+    let duplicates = duplicate_indices(problem, population);
+    if duplicates.is_empty() {
+        return;
+    }
+    let mut fresh: Vec<P::Individual> = duplicates
+        .iter()
+        .map(|_| problem.create_individual(rng))
+        .collect();
+    evaluate_population(problem, &mut fresh, config, cache);
+    for (index, ind) in duplicates.into_iter().zip(fresh) {
+        population[index] = ind;
+    }
+}
 
-                    next_gen.push(child);
-                }
+/// Per-individual case errors when lexicase selection is configured,
+/// otherwise empty.
+fn case_errors<P: GaProblem>(
+    problem: &P,
+    population: &[P::Individual],
+    config: &GaConfig,
+) -> Vec<Vec<f64>> {
+    if config.selection != Selection::Lexicase {
+        return Vec::new();
+    }
+    population
+        .iter()
+        .map(|ind| problem.case_errors(ind))
+        .collect()
+}
+
+/// Provenance of an offspring of `P`.
+type ProvenanceOf<P> = Provenance<<<P as GaProblem>::Individual as Individual>::Fitness>;
+
+/// Breeds `count` unevaluated offspring by selection, crossover and mutation.
+///
+/// Parents are selected using constraint-handling keys when present.
+/// Returns the offspring and, aligned with them, the operators that
+/// produced each one.
+#[allow(clippy::too_many_arguments)]
+fn breed<P: GaProblem, R: Rng>(
+    problem: &P,
+    population: &[P::Individual],
+    keys: Option<&[f64]>,
+    count: usize,
+    generation: usize,
+    config: &GaConfig,
+    control: &OperatorControl,
+    rng: &mut R,
+) -> (Vec<P::Individual>, Vec<ProvenanceOf<P>>) {
+    let cases = case_errors(problem, population, config);
+    let context = SelectionContext {
+        generation,
+        cases: &cases,
+    };
+    let shared = sharing_keys(problem, population, keys, config);
+    let keys = shared.as_deref().or(keys);
+    let mut parents = ParentSampler::new(config.selection, population, keys, context, 2 * count);
+
+    let mut offspring = Vec::with_capacity(count);
+    let mut provenance = Vec::with_capacity(count);
+    while offspring.len() < count {
+        // Selection
+        let p1_idx = parents.next(rng);
+        let p2_idx = parents.next(rng);
+
+        // Crossover
+        let (children, origin, mutation_rate) = recombine(
+            problem,
+            &population[p1_idx],
+            &population[p2_idx],
+            config,
+            control,
+            rng,
+        );
+
+        for mut child in children {
+            if offspring.len() >= count {
+                break;
             }
 
-            // Evaluate new individuals (skip elites, they're already evaluated)
-            evaluate_population(problem, &mut next_gen[elite_count..], config.parallel);
-
-            population = next_gen;
-
-            // Update best
-            let gen_best = find_best(&population);
-            if gen_best.fitness() < best.fitness() {
-                // Check if improvement is significant enough
-                let old_f = best.fitness().to_f64();
-                let new_f = gen_best.fitness().to_f64();
-                let improvement = if old_f.abs() > 1e-15 {
-                    (old_f - new_f).abs() / old_f.abs()
-                } else {
-                    // Old fitness is near zero; any change is significant
-                    (old_f - new_f).abs()
-                };
+            // Mutation
+            let mutation = mutate_child(problem, &mut child, mutation_rate, control, rng);
+            offspring.push(child);
+            provenance.push(Provenance { mutation, ..origin });
+        }
+    }
+    (offspring, provenance)
+}
 
-                best = gen_best.clone();
+/// Applies crossover to two parents with the (possibly adaptive) crossover
+/// rate, or clones the first parent.
+///
+/// Also returns the children's provenance (without mutation) and the
+/// mutation rate to apply to them.
+fn recombine<P: GaProblem, R: Rng>(
+    problem: &P,
+    p1: &P::Individual,
+    p2: &P::Individual,
+    config: &GaConfig,
+    control: &OperatorControl,
+    rng: &mut R,
+) -> (Vec<P::Individual>, ProvenanceOf<P>, f64) {
+    let parent = better_parent(p1, p2, config.direction);
+    let parent_cost = config.direction.cost(parent.1.to_f64());
+    let (crossover_rate, mutation_rate) = control.rates(config, parent_cost);
+
+    let mut origin = Provenance {
+        crossover: None,
+        mutation: None,
+        parent,
+    };
+    let children = if rng.random_range(0.0..1.0) < crossover_rate {
+        let operator = control.choose_crossover(rng);
+        origin.crossover = Some(operator);
+        problem.crossover_with(operator, p1, p2, rng)
+    } else {
+        vec![p1.clone()]
+    };
+    (children, origin, mutation_rate)
+}
 
-                if improvement >= config.convergence_threshold {
-                    stagnation_counter = 0;
-                } else {
-                    stagnation_counter += 1;
+/// Mutates `child` with probability `rate` and returns the operator used.
+fn mutate_child<P: GaProblem, R: Rng>(
+    problem: &P,
+    child: &mut P::Individual,
+    rate: f64,
+    control: &OperatorControl,
+    rng: &mut R,
+) -> Option<usize> {
+    if rng.random_range(0.0..1.0) < rate {
+        let operator = control.choose_mutation(rng);
+        problem.mutate_with(operator, child, control.strength(), rng);
+        Some(operator)
+    } else {
+        None
+    }
+}
+
+/// Credits the operators of evaluated offspring.
+fn credit_offspring<I: Individual>(
+    control: &mut OperatorControl,
+    offspring: &[I],
+    provenance: &[Provenance<I::Fitness>],
+    config: &GaConfig,
+) {
+    for (child, origin) in offspring.iter().zip(provenance) {
+        control.credit(child, origin, config);
+    }
+}
+
+/// Produces `population_size` offspring one at a time, inserting each into
+/// the population as soon as it is evaluated.
+#[allow(clippy::too_many_arguments)]
+fn steady_state<P: GaProblem, R: Rng>(
+    problem: &P,
+    population: &mut [P::Individual],
+    policy: SteadyStatePolicy,
+    config: &GaConfig,
+    constraints: &ConstraintState,
+    control: &mut OperatorControl,
+    cache: &FitnessCache<P::Individual>,
+    rng: &mut R,
+) {
+    let mut cases = case_errors(problem, population, config);
+    let mut produced = 0;
+    while produced < config.population_size {
+        let parents = select_parents(problem, population, &cases, config, constraints, rng);
+        let (children, origin, mutation_rate) = recombine(
+            problem,
+            &population[parents[0]],
+            &population[parents[1]],
+            config,
+            control,
+            rng,
+        );
+
+        for (k, mut child) in children.into_iter().enumerate() {
+            if produced >= config.population_size {
+                break;
+            }
+            produced += 1;
+
+            let mutation = mutate_child(problem, &mut child, mutation_rate, control, rng);
+            evaluate_population(problem, std::slice::from_mut(&mut child), config, cache);
+            control.credit(&child, &Provenance { mutation, ..origin }, config);
+            improve_offspring(
+                problem,
+                std::slice::from_mut(&mut child),
+                false,
+                config,
+                cache,
+                rng,
+            );
+
+            let replaced = insert_offspring(
+                population,
+                child,
+                policy,
+                parents[k.min(1)],
+                constraints,
+                rng,
+            );
+
+            // Keep lexicase case errors in step with the population
+            if let Some(index) = replaced {
+                if !cases.is_empty() {
+                    cases[index] = problem.case_errors(&population[index]);
                 }
+            }
+        }
+    }
+}
+
+/// Selects two parents from the current population for steady-state
+/// breeding.
+fn select_parents<P: GaProblem, R: Rng>(
+    problem: &P,
+    population: &[P::Individual],
+    cases: &[Vec<f64>],
+    config: &GaConfig,
+    constraints: &ConstraintState,
+    rng: &mut R,
+) -> [usize; 2] {
+    let keys = constraints.keys(population, rng);
+    let keys = sharing_keys(problem, population, keys.as_deref(), config).or(keys);
+    let context = SelectionContext {
+        generation: constraints.generation(),
+        cases,
+    };
+    let mut sampler = ParentSampler::new(config.selection, population, keys.as_deref(), context, 2);
+    [sampler.next(rng), sampler.next(rng)]
+}
+
+/// Inserts an evaluated steady-state offspring according to `policy`.
+///
+/// `parent` is the population index of the offspring's parent. Returns the
+/// index the offspring took, or `None` if it was discarded.
+fn insert_offspring<I: Individual, R: Rng>(
+    population: &mut [I],
+    child: I,
+    policy: SteadyStatePolicy,
+    parent: usize,
+    constraints: &ConstraintState,
+    rng: &mut R,
+) -> Option<usize> {
+    match policy {
+        SteadyStatePolicy::ReplaceWorst => {
+            let worst = extreme_index(population, |a, b| constraints.less(b, a));
+            population[worst] = child;
+            Some(worst)
+        }
+        SteadyStatePolicy::ReplaceRandom => {
+            let best = extreme_index(population, |a, b| constraints.less(a, b));
+            let mut target = rng.random_range(0..population.len() - 1);
+            if target >= best {
+                target += 1;
+            }
+            population[target] = child;
+            Some(target)
+        }
+        SteadyStatePolicy::ParentIfBetter => {
+            if constraints.less(&child, &population[parent]) {
+                population[parent] = child;
+                Some(parent)
             } else {
-                stagnation_counter += 1;
+                None
             }
+        }
+    }
+}
+
+/// An offspring submitted for evaluation in an asynchronous generation.
+struct Job<I: Individual> {
+    child: I,
+    provenance: Provenance<I::Fitness>,
+    /// Population index of the parent it may replace.
+    parent: usize,
+}
+
+/// Evaluates the offspring of an asynchronous generation.
+trait Evaluations<I: Individual> {
+    /// Starts evaluating `job`.
+    fn submit(&mut self, job: Job<I>);
+
+    /// Waits for some submitted job to finish evaluating and returns it.
+    fn complete(&mut self) -> Job<I>;
+}
+
+/// Evaluates each job as it is submitted, so jobs complete in order.
+struct InOrder<'a, P: GaProblem> {
+    problem: &'a P,
+    cache: &'a FitnessCache<P::Individual>,
+    done: VecDeque<Job<P::Individual>>,
+}
+
+impl<P: GaProblem> Evaluations<P::Individual> for InOrder<'_, P> {
+    fn submit(&mut self, mut job: Job<P::Individual>) {
+        evaluate_individual(self.problem, &mut job.child, self.cache);
+        self.done.push_back(job);
+    }
+
+    fn complete(&mut self) -> Job<P::Individual> {
+        self.done.pop_front().expect("an evaluation is pending")
+    }
+}
+
+/// Worker threads that evaluate jobs concurrently; jobs complete in
+/// whatever order the evaluations finish.
+#[cfg(feature = "parallel")]
+struct Workers<I: Individual> {
+    jobs: mpsc::Sender<Job<I>>,
+    done: mpsc::Receiver<std::thread::Result<Job<I>>>,
+}
+
+#[cfg(feature = "parallel")]
+impl<I: Individual> Evaluations<I> for Workers<I> {
+    fn submit(&mut self, job: Job<I>) {
+        self.jobs.send(job).expect("evaluation workers stopped");
+    }
 
-            fitness_history.push(best.fitness().to_f64());
-            generation_stats.push(compute_generation_stats(&population, gen + 1));
-
-            // Callback
-            problem.on_generation(gen + 1, best.fitness());
-
-            // Stagnation check
-            if config.stagnation_limit > 0 && stagnation_counter >= config.stagnation_limit {
-                return Ok(GaResult {
-                    best_fitness: best.fitness(),
-                    best,
-                    generations: gen + 1,
-                    stagnated: true,
-                    cancelled: false,
-                    timed_out: false,
-                    fitness_history,
-                    generation_stats,
+    fn complete(&mut self) -> Job<I> {
+        match self.done.recv().expect("evaluation workers stopped") {
+            Ok(job) => job,
+            // Re-raise a panic from `evaluate` on the calling thread
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
+    }
+}
+
+/// Asynchronous steady-state generation: keeps up to `workers` offspring
+/// in evaluation and inserts each as soon as its evaluation completes.
+#[allow(clippy::too_many_arguments)]
+fn asynchronous<P: GaProblem, R: Rng>(
+    problem: &P,
+    population: &mut [P::Individual],
+    workers: usize,
+    policy: SteadyStatePolicy,
+    config: &GaConfig,
+    constraints: &ConstraintState,
+    control: &mut OperatorControl,
+    cache: &FitnessCache<P::Individual>,
+    rng: &mut R,
+) {
+    #[cfg(feature = "parallel")]
+    if config.parallel && workers > 1 {
+        // Created before the scope so that the workers may borrow them
+        let (jobs, queue) = mpsc::channel::<Job<P::Individual>>();
+        let (results, done) = mpsc::channel();
+        let queue = Mutex::new(queue);
+        std::thread::scope(|scope| {
+            for _ in 0..workers {
+                let (queue, results) = (&queue, results.clone());
+                scope.spawn(move || loop {
+                    // Release the queue before evaluating
+                    let next = queue.lock().expect("job queue poisoned").recv();
+                    let Ok(mut job) = next else { break };
+                    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+                        evaluate_individual(problem, &mut job.child, cache);
+                        job
+                    }));
+                    if results.send(result).is_err() {
+                        break;
+                    }
                 });
             }
+            drop(results);
+            // Dropping `Workers` at the end of the scope closes the queue
+            // and lets the threads exit.
+            let mut evaluations = Workers { jobs, done };
+            dispatch_offspring(
+                problem,
+                population,
+                workers,
+                policy,
+                config,
+                constraints,
+                control,
+                cache,
+                rng,
+                &mut evaluations,
+            );
+        });
+        return;
+    }
+
+    let mut evaluations = InOrder {
+        problem,
+        cache,
+        done: VecDeque::new(),
+    };
+    dispatch_offspring(
+        problem,
+        population,
+        workers,
+        policy,
+        config,
+        constraints,
+        control,
+        cache,
+        rng,
+        &mut evaluations,
+    );
+}
+
+/// Breeds `population_size` offspring through `evaluations`, keeping up to
+/// `workers` of them in flight and inserting each on completion.
+#[allow(clippy::too_many_arguments)]
+fn dispatch_offspring<P: GaProblem, R: Rng>(
+    problem: &P,
+    population: &mut [P::Individual],
+    workers: usize,
+    policy: SteadyStatePolicy,
+    config: &GaConfig,
+    constraints: &ConstraintState,
+    control: &mut OperatorControl,
+    cache: &FitnessCache<P::Individual>,
+    rng: &mut R,
+    evaluations: &mut dyn Evaluations<P::Individual>,
+) {
+    let mut cases = case_errors(problem, population, config);
+    // Children of the last crossover not yet submitted
+    let mut brood = VecDeque::new();
+    let mut submitted = 0;
+    let mut in_flight = 0;
+    while submitted < config.population_size || in_flight > 0 {
+        if in_flight < workers && submitted < config.population_size {
+            if brood.is_empty() {
+                let parents = select_parents(problem, population, &cases, config, constraints, rng);
+                let (children, origin, mutation_rate) = recombine(
+                    problem,
+                    &population[parents[0]],
+                    &population[parents[1]],
+                    config,
+                    control,
+                    rng,
+                );
+                let wanted = config.population_size - submitted;
+                brood.extend(
+                    children
+                        .into_iter()
+                        .enumerate()
+                        .take(wanted)
+                        .map(|(k, child)| (child, parents[k.min(1)], origin, mutation_rate)),
+                );
+                continue;
+            }
+
+            // Mutate just before submitting, as steady-state does
+            let (mut child, parent, origin, mutation_rate) =
+                brood.pop_front().expect("brood is not empty");
+            let mutation = mutate_child(problem, &mut child, mutation_rate, control, rng);
+            evaluations.submit(Job {
+                child,
+                provenance: Provenance { mutation, ..origin },
+                parent,
+            });
+            submitted += 1;
+            in_flight += 1;
+            continue;
         }
 
-        Ok(GaResult {
-            best_fitness: best.fitness(),
-            best,
-            generations: if cancelled || timed_out {
-                fitness_history.len().saturating_sub(1)
-            } else {
-                config.max_generations
-            },
-            stagnated: false,
-            cancelled,
-            timed_out,
-            fitness_history,
-            generation_stats,
-        })
+        let mut job = evaluations.complete();
+        in_flight -= 1;
+        control.credit(&job.child, &job.provenance, config);
+        improve_offspring(
+            problem,
+            std::slice::from_mut(&mut job.child),
+            false,
+            config,
+            cache,
+            rng,
+        );
+        let replaced =
+            insert_offspring(population, job.child, policy, job.parent, constraints, rng);
+
+        // Keep lexicase case errors in step with the population
+        if let Some(index) = replaced {
+            if !cases.is_empty() {
+                cases[index] = problem.case_errors(&population[index]);
+            }
+        }
     }
 }
 
-/// Evaluate all individuals in the population.
-fn evaluate_population<P: GaProblem>(
+/// Applies local search to each evaluated offspring with probability
+/// `config.local_search.rate`.
+fn improve_offspring<P: GaProblem, R: Rng>(
     problem: &P,
-    population: &mut [P::Individual],
+    offspring: &mut [P::Individual],
     parallel: bool,
+    config: &GaConfig,
+    cache: &FitnessCache<P::Individual>,
+    rng: &mut R,
 ) {
+    let Some(local_search) = config.local_search else {
+        return;
+    };
+    let chosen: Vec<bool> = offspring
+        .iter()
+        .map(|_| rng.random_bool(local_search.rate))
+        .collect();
+
     #[cfg(feature = "parallel")]
     if parallel {
-        population.par_iter_mut().for_each(|ind| {
-            let f = problem.evaluate(ind);
-            ind.set_fitness(f);
-        });
+        offspring
+            .par_iter_mut()
+            .zip(chosen.par_iter())
+            .filter(|(_, &c)| c)
+            .for_each(|(ind, _)| {
+                improve_individual(problem, ind, local_search.mode, config.direction, cache)
+            });
         return;
     }
     let _ = parallel;
-    for ind in population.iter_mut() {
-        let f = problem.evaluate(ind);
-        ind.set_fitness(f);
+    for (ind, _) in offspring.iter_mut().zip(&chosen).filter(|(_, &c)| c) {
+        improve_individual(problem, ind, local_search.mode, config.direction, cache);
     }
 }
 
-/// Find the individual with the best (lowest) fitness.
-fn find_best<I: Individual>(population: &[I]) -> &I {
-    population
-        .iter()
-        .min_by(|a, b| {
+/// Runs [`GaProblem::improve`] on a copy of `ind` and keeps the result
+/// according to `mode` if it is not worse.
+fn improve_individual<P: GaProblem>(
+    problem: &P,
+    ind: &mut P::Individual,
+    mode: LearningMode,
+    direction: Direction,
+    cache: &FitnessCache<P::Individual>,
+) {
+    let mut improved = ind.clone();
+    if !problem.improve(&mut improved) {
+        return;
+    }
+    evaluate_individual(problem, &mut improved, cache);
+    if feasibility_cmp(&improved, ind, 0.0, direction) == std::cmp::Ordering::Greater {
+        return;
+    }
+    match mode {
+        LearningMode::Lamarckian => *ind = improved,
+        LearningMode::Baldwinian => {
+            ind.set_fitness(improved.fitness());
+            ind.set_violation(improved.violation());
+        }
+    }
+}
+
+/// Index of the first individual that no other `precedes`.
+pub(super) fn extreme_index<I: Individual>(
+    population: &[I],
+    precedes: impl Fn(&I, &I) -> bool,
+) -> usize {
+    let mut index = 0;
+    for (i, ind) in population.iter().enumerate().skip(1) {
+        if precedes(ind, &population[index]) {
+            index = i;
+        }
+    }
+    index
+}
+
+/// Sorts the population best first and returns the aligned selection keys.
+///
+/// Without constraint keys this is a plain ascending fitness sort.
+fn rank_population<I: Individual, R: Rng>(
+    population: &mut Vec<I>,
+    constraints: &ConstraintState,
+    rng: &mut R,
+) -> Option<Vec<f64>> {
+    let Some(keys) = constraints.keys(population, rng) else {
+        // Sort population by fitness (ascending = best first)
+        population.sort_by(|a, b| {
             a.fitness()
                 .partial_cmp(&b.fitness())
                 .unwrap_or(std::cmp::Ordering::Equal)
-        })
-        .expect("population must not be empty")
+        });
+        return None;
+    };
+
+    let mut order: Vec<usize> = (0..population.len()).collect();
+    order.sort_by(|&a, &b| {
+        keys[a]
+            .partial_cmp(&keys[b])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let mut slots: Vec<Option<I>> = population.drain(..).map(Some).collect();
+    population.extend(
+        order
+            .iter()
+            .map(|&i| slots[i].take().expect("each index used once")),
+    );
+    Some(order.iter().map(|&i| keys[i]).collect())
 }
 
-/// Computes population statistics for one generation.
-fn compute_generation_stats<I: Individual>(population: &[I], generation: usize) -> GenerationStats {
-    let fitnesses: Vec<f64> = population
-        .iter()
-        .map(|ind| ind.fitness().to_f64())
-        .collect();
-    let n = fitnesses.len() as f64;
-
-    let best = fitnesses.iter().copied().fold(f64::INFINITY, f64::min);
-    let worst = fitnesses.iter().copied().fold(f64::NEG_INFINITY, f64::max);
-    let mean = fitnesses.iter().sum::<f64>() / n;
-    let variance = fitnesses.iter().map(|&f| (f - mean).powi(2)).sum::<f64>() / n;
-    let std_dev = variance.sqrt();
-
-    GenerationStats {
-        generation,
-        best_fitness: best,
-        worst_fitness: worst,
-        mean_fitness: mean,
-        std_dev,
+/// Replaces `best` with `candidate` if it is better under the feasibility
+/// rules. Returns whether this counts as progress for the stagnation counter.
+pub(super) fn update_best<I: Individual>(
+    best: &mut I,
+    candidate: &I,
+    threshold: f64,
+    direction: Direction,
+) -> bool {
+    if feasibility_cmp(candidate, best, 0.0, direction) != std::cmp::Ordering::Less {
+        return false;
     }
+    let significant =
+        best.violation() > 0.0 || candidate.fitness().improves_on(best.fitness(), threshold);
+    *best = candidate.clone();
+    significant
 }
 
-// ============================================================================
-// Tests
-// ============================================================================
+/// Whether moving from `old` to `new` fitness counts as progress for the
+/// stagnation counter, relative to `threshold`.
+pub(super) fn is_significant_improvement(old: f64, new: f64, threshold: f64) -> bool {
+    let improvement = if old.abs() > 1e-15 {
+        (old - new).abs() / old.abs()
+    } else {
+        // Old fitness is near zero; any change is significant
+        (old - new).abs()
+    };
+    improvement >= threshold
+}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::ga::{GaConfig, Selection};
+/// Fitness and constraint violation memoized by
+/// [`GaProblem::individual_hash`].
+pub(super) type FitnessCache<I> = LruCache<u64, (<I as Individual>::Fitness, f64)>;
+
+/// Evaluate all individuals in the population, in batches when
+/// `config.batch_size > 0`.
+pub(super) fn evaluate_population<P: GaProblem>(
+    problem: &P,
+    population: &mut [P::Individual],
+    config: &GaConfig,
+    cache: &FitnessCache<P::Individual>,
+) {
+    if config.batch_size > 0 {
+        evaluate_batches(problem, population, config, cache);
+        return;
+    }
+    #[cfg(feature = "parallel")]
+    if config.parallel {
+        population
+            .par_iter_mut()
+            .for_each(|ind| evaluate_individual(problem, ind, cache));
+        return;
+    }
+    for ind in population.iter_mut() {
+        evaluate_individual(problem, ind, cache);
+    }
+}
+
+/// Evaluates the uncached individuals of `population` with
+/// [`GaProblem::evaluate_batch`].
+fn evaluate_batches<P: GaProblem>(
+    problem: &P,
+    population: &mut [P::Individual],
+    config: &GaConfig,
+    cache: &FitnessCache<P::Individual>,
+) {
+    let mut misses = Vec::with_capacity(population.len());
+    for (i, ind) in population.iter_mut().enumerate() {
+        let key = if cache.is_enabled() {
+            problem.individual_hash(ind)
+        } else {
+            None
+        };
+        match key.and_then(|k| cache.get(&k)) {
+            Some((f, v)) => {
+                ind.set_fitness(f);
+                ind.set_violation(v);
+            }
+            None => misses.push((i, key)),
+        }
+    }
+
+    let fitnesses = if misses.len() == population.len() {
+        batch_fitness(problem, population, config)
+    } else {
+        let pending: Vec<P::Individual> =
+            misses.iter().map(|&(i, _)| population[i].clone()).collect();
+        batch_fitness(problem, &pending, config)
+    };
+    for (&(i, key), f) in misses.iter().zip(fitnesses) {
+        let ind = &mut population[i];
+        let v = problem.constraint_violation(ind);
+        ind.set_fitness(f);
+        ind.set_violation(v);
+        if let Some(k) = key {
+            cache.insert(k, (f, v));
+        }
+    }
+}
+
+/// Fitness of `individuals`, `config.batch_size` at a time.
+fn batch_fitness<P: GaProblem>(
+    problem: &P,
+    individuals: &[P::Individual],
+    config: &GaConfig,
+) -> Vec<<P::Individual as Individual>::Fitness> {
+    let evaluate = |batch: &[P::Individual]| {
+        let fitnesses = problem.evaluate_batch(batch);
+        assert_eq!(
+            fitnesses.len(),
+            batch.len(),
+            "evaluate_batch must return one fitness per individual"
+        );
+        fitnesses
+    };
+    #[cfg(feature = "parallel")]
+    if config.parallel {
+        return individuals
+            .par_chunks(config.batch_size)
+            .flat_map_iter(evaluate)
+            .collect();
+    }
+    individuals
+        .chunks(config.batch_size)
+        .flat_map(evaluate)
+        .collect()
+}
+
+/// Evaluates one individual, consulting the cache when the problem
+/// provides a hash.
+fn evaluate_individual<P: GaProblem>(
+    problem: &P,
+    ind: &mut P::Individual,
+    cache: &FitnessCache<P::Individual>,
+) {
+    let key = if cache.is_enabled() {
+        problem.individual_hash(ind)
+    } else {
+        None
+    };
+    if let Some((f, v)) = key.and_then(|k| cache.get(&k)) {
+        ind.set_fitness(f);
+        ind.set_violation(v);
+        return;
+    }
+
+    let f = problem.evaluate(ind);
+    let v = problem.constraint_violation(ind);
+    ind.set_fitness(f);
+    ind.set_violation(v);
+    if let Some(k) = key {
+        cache.insert(k, (f, v));
+    }
+}
+
+/// Offers `individuals` to the hall of fame, ranked like [`find_best`].
+pub(super) fn record_hall_of_fame<P: GaProblem>(
+    problem: &P,
+    hall_of_fame: &mut HallOfFame<P::Individual>,
+    individuals: &[P::Individual],
+    direction: Direction,
+) {
+    for ind in individuals {
+        hall_of_fame.offer(
+            |entry| feasibility_cmp(ind, entry, 0.0, direction) == std::cmp::Ordering::Less,
+            |entry| problem.distance(ind, entry),
+            || ind.clone(),
+        );
+    }
+}
+
+/// Find the best individual: feasible before infeasible, then best
+/// fitness (or lowest violation among infeasible ones).
+pub(super) fn find_best<I: Individual>(population: &[I], direction: Direction) -> &I {
+    population
+        .iter()
+        .min_by(|a, b| feasibility_cmp(*a, *b, 0.0, direction))
+        .expect("population must not be empty")
+}
+
+/// Computes population statistics for one generation.
+///
+/// Diversity is only computed when the configuration asks for it.
+pub(super) fn compute_generation_stats<P: GaProblem, B: Borrow<P::Individual>>(
+    problem: &P,
+    population: &[B],
+    generation: usize,
+    config: &GaConfig,
+) -> GenerationStats {
+    let fitnesses: Vec<f64> = population
+        .iter()
+        .map(|ind| ind.borrow().fitness().to_f64())
+        .collect();
+    let diversity = if config.diversity_stats || config.niching != Niching::None {
+        mean_pairwise_distance(problem, population)
+    } else {
+        0.0
+    };
+    GenerationStats {
+        diversity,
+        ..GenerationStats::from_costs(&fitnesses, generation, config.direction)
+    }
+}
+
+impl GenerationStats {
+    /// Computes the statistics of one generation's fitness (or cost) values.
+    ///
+    /// `diversity` is left at 0.
+    pub(crate) fn from_costs(fitnesses: &[f64], generation: usize, direction: Direction) -> Self {
+        let n = fitnesses.len() as f64;
+
+        let min = fitnesses.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = fitnesses.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let (best, worst) = match direction {
+            Direction::Minimize => (min, max),
+            Direction::Maximize => (max, min),
+        };
+        let mean = fitnesses.iter().sum::<f64>() / n;
+        let variance = fitnesses.iter().map(|&f| (f - mean).powi(2)).sum::<f64>() / n;
+        let std_dev = variance.sqrt();
+
+        GenerationStats {
+            generation,
+            best_fitness: best,
+            worst_fitness: worst,
+            mean_fitness: mean,
+            std_dev,
+            diversity: 0.0,
+        }
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ga::{
+        ConstraintHandling, FeasibilityFirst, GaConfig, LearningMode, Lexicographic, LocalSearch,
+        OneFifthRule, OperatorSelection, RateControl, Restart, RestartMode, Selection,
+    };
 
     // ---- OneMax problem: maximize sum of bits (minimize negative sum) ----
 
     #[derive(Clone, Debug)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     struct BitString {
         bits: Vec<bool>,
         fitness: f64,
     }
 
-    impl Individual for BitString {
-        type Fitness = f64;
-        fn fitness(&self) -> f64 {
-            self.fitness
-        }
-        fn set_fitness(&mut self, f: f64) {
-            self.fitness = f;
+    impl Individual for BitString {
+        type Fitness = f64;
+        fn fitness(&self) -> f64 {
+            self.fitness
+        }
+        fn set_fitness(&mut self, f: f64) {
+            self.fitness = f;
+        }
+    }
+
+    struct OneMaxProblem {
+        n: usize,
+    }
+
+    impl GaProblem for OneMaxProblem {
+        type Individual = BitString;
+
+        fn create_individual<R: Rng>(&self, rng: &mut R) -> BitString {
+            let bits: Vec<bool> = (0..self.n).map(|_| rng.random_bool(0.5)).collect();
+            BitString {
+                bits,
+                fitness: f64::INFINITY,
+            }
+        }
+
+        fn evaluate(&self, ind: &BitString) -> f64 {
+            // Minimize negative count of true bits
+            -(ind.bits.iter().filter(|&&b| b).count() as f64)
+        }
+
+        fn individual_hash(&self, ind: &BitString) -> Option<u64> {
+            use std::hash::{Hash, Hasher};
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            ind.bits.hash(&mut hasher);
+            Some(hasher.finish())
+        }
+
+        fn distance(&self, a: &BitString, b: &BitString) -> f64 {
+            // Hamming distance
+            a.bits.iter().zip(&b.bits).filter(|(x, y)| x != y).count() as f64
+        }
+
+        fn case_errors(&self, ind: &BitString) -> Vec<f64> {
+            // One case per bit
+            ind.bits
+                .iter()
+                .map(|&b| if b { 0.0 } else { 1.0 })
+                .collect()
+        }
+
+        fn crossover<R: Rng>(&self, p1: &BitString, p2: &BitString, rng: &mut R) -> Vec<BitString> {
+            // Single-point crossover
+            let point = rng.random_range(0..self.n);
+            let mut c1_bits = Vec::with_capacity(self.n);
+            let mut c2_bits = Vec::with_capacity(self.n);
+            for i in 0..self.n {
+                if i < point {
+                    c1_bits.push(p1.bits[i]);
+                    c2_bits.push(p2.bits[i]);
+                } else {
+                    c1_bits.push(p2.bits[i]);
+                    c2_bits.push(p1.bits[i]);
+                }
+            }
+            vec![
+                BitString {
+                    bits: c1_bits,
+                    fitness: f64::INFINITY,
+                },
+                BitString {
+                    bits: c2_bits,
+                    fitness: f64::INFINITY,
+                },
+            ]
+        }
+
+        fn mutate<R: Rng>(&self, ind: &mut BitString, rng: &mut R) {
+            // Flip one random bit
+            let idx = rng.random_range(0..self.n);
+            ind.bits[idx] = !ind.bits[idx];
+        }
+    }
+
+    #[test]
+    fn test_onemax_convergence() {
+        let problem = OneMaxProblem { n: 20 };
+        let config = GaConfig::default()
+            .with_population_size(50)
+            .with_max_generations(200)
+            .with_mutation_rate(0.3)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let result = GaRunner::run(&problem, &config).unwrap();
+
+        // Should find near-optimal solution (all true = fitness -20)
+        assert!(
+            result.best_fitness <= -15.0,
+            "expected fitness <= -15.0 for 20-bit OneMax, got {}",
+            result.best_fitness
+        );
+    }
+
+    /// OneMax as a maximization of the number of true bits.
+    struct CountOnesProblem(OneMaxProblem);
+
+    impl GaProblem for CountOnesProblem {
+        type Individual = BitString;
+
+        fn create_individual<R: Rng>(&self, rng: &mut R) -> BitString {
+            self.0.create_individual(rng)
+        }
+
+        fn evaluate(&self, ind: &BitString) -> f64 {
+            -self.0.evaluate(ind)
+        }
+
+        fn crossover<R: Rng>(&self, p1: &BitString, p2: &BitString, rng: &mut R) -> Vec<BitString> {
+            self.0.crossover(p1, p2, rng)
+        }
+
+        fn mutate<R: Rng>(&self, ind: &mut BitString, rng: &mut R) {
+            self.0.mutate(ind, rng)
+        }
+    }
+
+    #[test]
+    fn test_maximize_mirrors_minimize() {
+        let config = GaConfig::default()
+            .with_population_size(30)
+            .with_max_generations(40)
+            .with_mutation_rate(0.3)
+            .with_seed(42)
+            .with_parallel(false);
+        let min = GaRunner::run(&OneMaxProblem { n: 20 }, &config).unwrap();
+        let max = GaRunner::run(
+            &CountOnesProblem(OneMaxProblem { n: 20 }),
+            &config.with_direction(Direction::Maximize),
+        )
+        .unwrap();
+
+        assert_eq!(max.best.bits, min.best.bits);
+        assert_eq!(max.best_fitness, -min.best_fitness);
+        let negated: Vec<f64> = min.fitness_history.iter().map(|f| -f).collect();
+        assert_eq!(max.fitness_history, negated);
+        for (a, b) in max.generation_stats.iter().zip(&min.generation_stats) {
+            assert_eq!(a.best_fitness, -b.best_fitness);
+            assert_eq!(a.worst_fitness, -b.worst_fitness);
+        }
+    }
+
+    #[test]
+    fn test_stagnation_termination() {
+        let problem = OneMaxProblem { n: 5 };
+        let config = GaConfig::default()
+            .with_population_size(20)
+            .with_max_generations(1000)
+            .with_stagnation_limit(10)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let result = GaRunner::run(&problem, &config).unwrap();
+
+        // Should stop early due to convergence
+        assert!(
+            result.stagnated || result.generations < 1000,
+            "expected stagnation or early stop"
+        );
+    }
+
+    #[test]
+    fn test_cancellation() {
+        let problem = OneMaxProblem { n: 20 };
+        let config = GaConfig::default()
+            .with_population_size(50)
+            .with_max_generations(10000)
+            .with_stagnation_limit(0) // disable stagnation
+            .with_seed(42)
+            .with_parallel(false);
+
+        let cancel = Arc::new(AtomicBool::new(false));
+
+        // Cancel after a few generations
+        let cancel_clone = cancel.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            cancel_clone.store(true, Ordering::Relaxed);
+        });
+
+        let result = GaRunner::run_with_cancel(&problem, &config, Some(cancel)).unwrap();
+
+        assert!(result.cancelled, "expected cancelled result");
+        assert!(result.generations < 10000, "should have stopped early");
+    }
+
+    #[test]
+    fn test_elite_preservation() {
+        let problem = OneMaxProblem { n: 10 };
+        let config = GaConfig::default()
+            .with_population_size(20)
+            .with_max_generations(50)
+            .with_elite_ratio(0.2)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let result = GaRunner::run(&problem, &config).unwrap();
+
+        // Fitness should never get worse across generations
+        for window in result.fitness_history.windows(2) {
+            assert!(
+                window[1] <= window[0],
+                "fitness should be monotonically non-increasing with elitism: {} > {}",
+                window[1],
+                window[0]
+            );
+        }
+    }
+
+    #[test]
+    fn test_fitness_history() {
+        let problem = OneMaxProblem { n: 10 };
+        let config = GaConfig::default()
+            .with_population_size(20)
+            .with_max_generations(30)
+            .with_stagnation_limit(0)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let result = GaRunner::run(&problem, &config).unwrap();
+
+        // History should have max_generations + 1 entries (initial + each gen)
+        assert_eq!(result.fitness_history.len(), 31);
+    }
+
+    #[test]
+    fn test_fitness_cache_preserves_results() {
+        let problem = OneMaxProblem { n: 12 };
+        let config = GaConfig::default()
+            .with_population_size(30)
+            .with_max_generations(60)
+            .with_stagnation_limit(0)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let plain = GaRunner::run(&problem, &config).unwrap();
+        let cached = GaRunner::run(&problem, &config.clone().with_cache_capacity(500)).unwrap();
+
+        assert_eq!(plain.fitness_history, cached.fitness_history);
+        assert_eq!(plain.cache_hits + plain.cache_misses, 0);
+        assert!(
+            cached.cache_hits > 0,
+            "converged population repeats genotypes"
+        );
+        // Initial population plus non-elites of every generation
+        assert_eq!(cached.cache_hits + cached.cache_misses, 30 + 60 * 27);
+    }
+
+    #[test]
+    fn test_hall_of_fame_keeps_distinct_individuals() {
+        let problem = OneMaxProblem { n: 30 };
+        let config = GaConfig::default()
+            .with_population_size(30)
+            .with_max_generations(60)
+            .with_hall_of_fame(6, 2.0)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let result = GaRunner::run(&problem, &config).unwrap();
+        let hall_of_fame = &result.hall_of_fame;
+
+        assert!((1..=6).contains(&hall_of_fame.len()));
+        assert_eq!(hall_of_fame[0].fitness, result.best_fitness);
+        assert!(hall_of_fame
+            .windows(2)
+            .all(|w| w[0].fitness <= w[1].fitness));
+        for (i, a) in hall_of_fame.iter().enumerate() {
+            assert_eq!(problem.evaluate(a), a.fitness);
+            for b in &hall_of_fame[i + 1..] {
+                assert!(problem.distance(a, b) > 2.0);
+            }
+        }
+
+        // Recording the hall of fame does not change the run
+        let plain = GaRunner::run(&problem, &config.with_hall_of_fame(0, 0.0)).unwrap();
+        assert!(plain.hall_of_fame.is_empty());
+        assert_eq!(plain.fitness_history, result.fitness_history);
+    }
+
+    #[test]
+    fn test_hall_of_fame_maximize() {
+        let problem = CountOnesProblem(OneMaxProblem { n: 20 });
+        let config = GaConfig::default()
+            .with_population_size(20)
+            .with_max_generations(20)
+            .with_hall_of_fame(3, 0.0)
+            .with_direction(Direction::Maximize)
+            .with_seed(1)
+            .with_parallel(false);
+
+        let result = GaRunner::run(&problem, &config).unwrap();
+
+        // The default distance keeps one individual per fitness
+        assert_eq!(result.hall_of_fame.len(), 3);
+        assert_eq!(result.hall_of_fame[0].fitness, result.best_fitness);
+        assert!(result
+            .hall_of_fame
+            .windows(2)
+            .all(|w| w[0].fitness > w[1].fitness));
+    }
+
+    /// OneMax that records the size of every batch it evaluates.
+    struct BatchedOneMax {
+        inner: OneMaxProblem,
+        batches: std::sync::Mutex<Vec<usize>>,
+    }
+
+    impl GaProblem for BatchedOneMax {
+        type Individual = BitString;
+
+        fn create_individual<R: Rng>(&self, rng: &mut R) -> BitString {
+            self.inner.create_individual(rng)
+        }
+
+        fn evaluate(&self, ind: &BitString) -> f64 {
+            self.inner.evaluate(ind)
+        }
+
+        fn evaluate_batch(&self, individuals: &[BitString]) -> Vec<f64> {
+            self.batches.lock().unwrap().push(individuals.len());
+            individuals.iter().map(|ind| self.evaluate(ind)).collect()
+        }
+
+        fn individual_hash(&self, ind: &BitString) -> Option<u64> {
+            self.inner.individual_hash(ind)
+        }
+
+        fn crossover<R: Rng>(&self, p1: &BitString, p2: &BitString, rng: &mut R) -> Vec<BitString> {
+            self.inner.crossover(p1, p2, rng)
+        }
+
+        fn mutate<R: Rng>(&self, ind: &mut BitString, rng: &mut R) {
+            self.inner.mutate(ind, rng)
+        }
+    }
+
+    #[test]
+    fn test_batch_evaluation_matches_individual() {
+        let problem = BatchedOneMax {
+            inner: OneMaxProblem { n: 12 },
+            batches: std::sync::Mutex::new(Vec::new()),
+        };
+        let config = GaConfig::default()
+            .with_population_size(30)
+            .with_max_generations(40)
+            .with_stagnation_limit(0)
+            .with_cache_capacity(500)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let plain = GaRunner::run(&problem, &config).unwrap();
+        assert!(problem.batches.lock().unwrap().is_empty());
+
+        let batched = GaRunner::run(&problem, &config.with_batch_size(8)).unwrap();
+        let batches = problem.batches.lock().unwrap();
+
+        assert_eq!(plain.fitness_history, batched.fitness_history);
+        assert_eq!(batches[..4], [8, 8, 8, 6], "initial population");
+        assert!(batches.iter().all(|&size| (1..=8).contains(&size)));
+        // Cache hits never reach the problem
+        assert_eq!(batches.iter().sum::<usize>(), batched.cache_misses);
+    }
+
+    #[test]
+    fn test_run_observed_events() {
+        use crate::observer::Event;
+
+        let problem = OneMaxProblem { n: 20 };
+        let config = GaConfig::default()
+            .with_population_size(20)
+            .with_max_generations(100)
+            .with_stagnation_limit(10)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let mut generations = Vec::new();
+        let mut bests = Vec::new();
+        let mut terminated = Vec::new();
+        let result =
+            GaRunner::run_observed(
+                &problem,
+                &config,
+                None,
+                &mut |event: &Event<'_>| match *event {
+                    Event::GenerationFinished { stats } => generations.push(stats.generation),
+                    Event::NewBest { cost, .. } => bests.push(cost),
+                    Event::Terminated {
+                        reason, iterations, ..
+                    } => terminated.push((reason, iterations)),
+                    _ => {}
+                },
+            )
+            .unwrap();
+
+        assert_eq!(generations.len(), result.generations);
+        assert!(bests.windows(2).all(|w| w[1] < w[0]));
+        let expected = if result.stagnated {
+            TerminationReason::Stagnated
+        } else {
+            TerminationReason::MaxIterations
+        };
+        assert_eq!(terminated, vec![(expected, result.generations)]);
+    }
+
+    #[test]
+    fn test_run_observed_reports_cancellation() {
+        use crate::observer::Event;
+
+        let cancel = Arc::new(AtomicBool::new(true));
+        let mut reason = None;
+        let result = GaRunner::run_observed(
+            &OneMaxProblem { n: 10 },
+            &GaConfig::default().with_seed(1).with_parallel(false),
+            Some(cancel),
+            &mut |event: &Event<'_>| {
+                if let Event::Terminated { reason: r, .. } = *event {
+                    reason = Some(r);
+                }
+            },
+        )
+        .unwrap();
+
+        assert!(result.cancelled);
+        assert_eq!(reason, Some(TerminationReason::Cancelled));
+    }
+
+    #[test]
+    fn test_run_with_seeds_injects_solutions() {
+        let problem = OneMaxProblem { n: 30 };
+        let optimum = BitString {
+            bits: vec![true; 30],
+            fitness: f64::INFINITY, // re-evaluated by the runner
+        };
+        let config = GaConfig::default()
+            .with_population_size(20)
+            .with_max_generations(5)
+            .with_seed(42)
+            .with_parallel(false);
+
+        let result = GaRunner::run_with_seeds(&problem, &config, vec![optimum], None).unwrap();
+
+        assert_eq!(result.fitness_history[0], -30.0);
+        assert_eq!(result.best_fitness, -30.0);
+    }
+
+    #[test]
+    fn test_seed_individuals_hook_and_truncation() {
+        struct SeededOneMax(OneMaxProblem);
+
+        impl GaProblem for SeededOneMax {
+            type Individual = BitString;
+
+            fn create_individual<R: Rng>(&self, rng: &mut R) -> BitString {
+                self.0.create_individual(rng)
+            }
+
+            fn seed_individuals<R: Rng>(&self, _rng: &mut R) -> Vec<BitString> {
+                let seed = BitString {
+                    bits: vec![true; self.0.n],
+                    fitness: f64::INFINITY,
+                };
+                vec![seed; 50] // more than the population holds
+            }
+
+            fn evaluate(&self, ind: &BitString) -> f64 {
+                self.0.evaluate(ind)
+            }
+        }
+
+        let config = GaConfig::default()
+            .with_population_size(10)
+            .with_max_generations(1)
+            .with_seed(1)
+            .with_parallel(false);
+
+        let result = GaRunner::run(&SeededOneMax(OneMaxProblem { n: 8 }), &config).unwrap();
+
+        let initial = &result.generation_stats[0];
+        assert_eq!(initial.best_fitness, -8.0);
+        assert_eq!(initial.worst_fitness, -8.0, "whole population is seeded");
+    }
+
+    /// OneMax that records every checkpoint it receives.
+    struct CheckpointedOneMax {
+        inner: OneMaxProblem,
+        states: std::sync::Mutex<Vec<GaState<BitString>>>,
+    }
+
+    impl CheckpointedOneMax {
+        fn new(n: usize) -> Self {
+            Self {
+                inner: OneMaxProblem { n },
+                states: std::sync::Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    impl GaProblem for CheckpointedOneMax {
+        type Individual = BitString;
+
+        fn create_individual<R: Rng>(&self, rng: &mut R) -> BitString {
+            self.inner.create_individual(rng)
+        }
+
+        fn evaluate(&self, ind: &BitString) -> f64 {
+            self.inner.evaluate(ind)
+        }
+
+        fn crossover<R: Rng>(&self, p1: &BitString, p2: &BitString, rng: &mut R) -> Vec<BitString> {
+            self.inner.crossover(p1, p2, rng)
+        }
+
+        fn mutate<R: Rng>(&self, ind: &mut BitString, rng: &mut R) {
+            self.inner.mutate(ind, rng)
+        }
+
+        fn on_checkpoint(&self, state: &GaState<BitString>) {
+            self.states.lock().unwrap().push(state.clone());
+        }
+    }
+
+    fn checkpoint_config() -> GaConfig {
+        GaConfig::default()
+            .with_population_size(20)
+            .with_max_generations(30)
+            .with_stagnation_limit(0)
+            .with_checkpoint_interval(10)
+            .with_seed(7)
+            .with_parallel(false)
+    }
+
+    #[test]
+    fn test_resume_from_checkpoint_matches_uninterrupted_run() {
+        let problem = CheckpointedOneMax::new(40);
+        let config = checkpoint_config().with_hall_of_fame(4, 0.0);
+        let full = GaRunner::run(&problem, &config).unwrap();
+
+        let states = problem.states.into_inner().unwrap();
+        assert_eq!(
+            states.iter().map(|s| s.generation).collect::<Vec<_>>(),
+            vec![10, 20, 30]
+        );
+
+        let state = states[0].clone();
+        assert_eq!(state.fitness_history.len(), 11);
+        let resumed =
+            GaRunner::resume_from(&CheckpointedOneMax::new(40), &config, state, None).unwrap();
+
+        assert_eq!(resumed.generations, full.generations);
+        assert_eq!(resumed.fitness_history, full.fitness_history);
+        assert_eq!(resumed.best.bits, full.best.bits);
+        assert_eq!(resumed.generation_stats.len(), full.generation_stats.len());
+        let bits = |result: &GaResult<BitString>| {
+            result
+                .hall_of_fame
+                .iter()
+                .map(|ind| ind.bits.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(bits(&resumed), bits(&full));
+    }
+
+    #[test]
+    fn test_checkpoints_do_not_change_the_run() {
+        let config = checkpoint_config();
+        let with = GaRunner::run(&CheckpointedOneMax::new(40), &config).unwrap();
+        let without = GaRunner::run(
+            &CheckpointedOneMax::new(40),
+            &config.clone().with_checkpoint_interval(0),
+        )
+        .unwrap();
+
+        assert_eq!(with.fitness_history, without.fitness_history);
+        assert_eq!(with.best.bits, without.best.bits);
+    }
+
+    #[test]
+    fn test_resume_from_rejects_mismatched_population() {
+        let problem = CheckpointedOneMax::new(10);
+        let config = checkpoint_config();
+        GaRunner::run(&problem, &config).unwrap();
+        let state = problem.states.into_inner().unwrap().remove(0);
+
+        let bigger = config.with_population_size(30);
+        assert!(GaRunner::resume_from(&CheckpointedOneMax::new(10), &bigger, state, None).is_err());
+    }
+
+    #[test]
+    fn test_restarts_on_stagnation() {
+        let problem = OneMaxProblem { n: 5 };
+        let config = GaConfig::default()
+            .with_population_size(20)
+            .with_max_generations(500)
+            .with_stagnation_limit(5)
+            .with_seed(42)
+            .with_parallel(false);
+        let plain = GaRunner::run(&problem, &config).unwrap();
+        assert_eq!(plain.restarts, 0);
+
+        for mode in [
+            RestartMode::Randomize,
+            RestartMode::HeavyMutation { rounds: 5 },
+        ] {
+            let result = GaRunner::run(
+                &problem,
+                &config.clone().with_restart(Restart {
+                    keep: 2,
+                    mode,
+                    max_restarts: 3,
+                }),
+            )
+            .unwrap();
+
+            assert_eq!(result.restarts, 3, "{mode:?}");
+            assert!(result.stagnated);
+            // Identical up to the first stagnation, then 3 more stagnations
+            assert!(result.generations >= plain.generations + 15);
+            assert!(result.best_fitness <= plain.best_fitness);
+            assert!(result.fitness_history.windows(2).all(|w| w[1] <= w[0]));
+        }
+    }
+
+    #[test]
+    fn test_resume_from_checkpoint_with_restarts() {
+        let problem = CheckpointedOneMax::new(5);
+        let config = checkpoint_config()
+            .with_checkpoint_interval(1)
+            .with_stagnation_limit(3)
+            .with_restart(Restart {
+                keep: 1,
+                mode: RestartMode::Randomize,
+                max_restarts: 100,
+            });
+        let full = GaRunner::run(&problem, &config).unwrap();
+        assert!(full.restarts > 0);
+
+        for state in problem.states.into_inner().unwrap() {
+            let resumed =
+                GaRunner::resume_from(&CheckpointedOneMax::new(5), &config, state, None).unwrap();
+            assert_eq!(resumed.fitness_history, full.fitness_history);
+            assert_eq!(resumed.restarts, full.restarts);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_checkpoint_serde_roundtrip() {
+        let problem = CheckpointedOneMax::new(40);
+        let config = checkpoint_config();
+        let full = GaRunner::run(&problem, &config).unwrap();
+        let state = problem.states.into_inner().unwrap().remove(1);
+
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: GaState<BitString> = serde_json::from_str(&json).unwrap();
+        let resumed =
+            GaRunner::resume_from(&CheckpointedOneMax::new(40), &config, restored, None).unwrap();
+
+        assert_eq!(resumed.fitness_history, full.fitness_history);
+        assert_eq!(resumed.best.bits, full.best.bits);
+    }
+
+    #[test]
+    fn test_all_selection_strategies() {
+        let problem = OneMaxProblem { n: 10 };
+
+        for selection in [
+            Selection::Tournament(3),
+            Selection::Roulette,
+            Selection::Rank,
+            Selection::LinearRank(1.8),
+            Selection::StochasticUniversal,
+            Selection::Truncation(0.5),
+            Selection::Boltzmann {
+                temperature: 2.0,
+                cooling_rate: 0.95,
+            },
+            Selection::Lexicase,
+        ] {
+            let config = GaConfig::default()
+                .with_population_size(30)
+                .with_max_generations(50)
+                .with_selection(selection)
+                .with_seed(42)
+                .with_parallel(false);
+
+            let result = GaRunner::run(&problem, &config).unwrap();
+
+            assert!(
+                result.best_fitness < 0.0,
+                "selection {:?} should find some true bits, got fitness {}",
+                selection,
+                result.best_fitness
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_selection_strategies_steady_state() {
+        let problem = OneMaxProblem { n: 10 };
+
+        for selection in [
+            Selection::StochasticUniversal,
+            Selection::Truncation(0.3),
+            Selection::Lexicase,
+        ] {
+            let config = GaConfig::default()
+                .with_population_size(20)
+                .with_max_generations(30)
+                .with_selection(selection)
+                .with_replacement(Replacement::SteadyState(SteadyStatePolicy::ReplaceWorst))
+                .with_seed(7)
+                .with_parallel(false);
+
+            let result = GaRunner::run(&problem, &config).unwrap();
+            assert!(
+                result.best_fitness <= -8.0,
+                "selection {selection:?} should nearly solve OneMax, got {}",
+                result.best_fitness
+            );
+        }
+    }
+
+    /// OneMax with a helpful and a useless crossover and a helpful and a
+    /// harmful mutation.
+    struct MultiOperatorOneMax(OneMaxProblem);
+
+    impl GaProblem for MultiOperatorOneMax {
+        type Individual = BitString;
+
+        fn create_individual<R: Rng>(&self, rng: &mut R) -> BitString {
+            self.0.create_individual(rng)
+        }
+
+        fn evaluate(&self, ind: &BitString) -> f64 {
+            self.0.evaluate(ind)
+        }
+
+        fn crossover_operators(&self) -> usize {
+            2
+        }
+
+        fn crossover_with<R: Rng>(
+            &self,
+            operator: usize,
+            p1: &BitString,
+            p2: &BitString,
+            rng: &mut R,
+        ) -> Vec<BitString> {
+            match operator {
+                0 => self.0.crossover(p1, p2, rng),
+                _ => vec![p1.clone(), p2.clone()],
+            }
+        }
+
+        fn mutation_operators(&self) -> usize {
+            2
+        }
+
+        fn mutate_with<R: Rng>(
+            &self,
+            operator: usize,
+            ind: &mut BitString,
+            _strength: f64,
+            rng: &mut R,
+        ) {
+            // Operator 0 sets a random bit, operator 1 clears one
+            let idx = rng.random_range(0..self.0.n);
+            ind.bits[idx] = operator == 0;
+        }
+    }
+
+    #[test]
+    fn test_adaptive_operator_selection_prefers_helpful_operators() {
+        let problem = MultiOperatorOneMax(OneMaxProblem { n: 60 });
+
+        for selection in [
+            OperatorSelection::ProbabilityMatching {
+                p_min: 0.1,
+                alpha: 0.3,
+            },
+            OperatorSelection::AdaptivePursuit {
+                p_min: 0.1,
+                alpha: 0.3,
+                beta: 0.3,
+            },
+        ] {
+            let config = GaConfig::default()
+                .with_population_size(30)
+                .with_max_generations(15)
+                .with_mutation_rate(0.8)
+                .with_operator_selection(selection)
+                .with_seed(42)
+                .with_parallel(false);
+
+            let result = GaRunner::run(&problem, &config).unwrap();
+            let mutation = &result.mutation_usage;
+            assert_eq!(mutation.len(), 2);
+            assert!(
+                mutation[0].applications > mutation[1].applications,
+                "{selection:?}: {mutation:?}"
+            );
+            assert!(mutation[0].successes > mutation[1].successes);
+            assert!(mutation[0].probability > mutation[1].probability);
+            assert_eq!(result.crossover_usage.len(), 2);
+            let total: f64 = result.crossover_usage.iter().map(|u| u.probability).sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_adaptive_rates_and_one_fifth_rule() {
+        let problem = OneMaxProblem { n: 20 };
+        let config = GaConfig::default()
+            .with_population_size(30)
+            .with_max_generations(100)
+            .with_rate_control(RateControl::srinivas_patnaik())
+            .with_one_fifth_rule(OneFifthRule::default())
+            .with_seed(42)
+            .with_parallel(false);
+
+        let result = GaRunner::run(&problem, &config).unwrap();
+
+        assert!(
+            result.best_fitness <= -15.0,
+            "adaptive rates should make progress, got {}",
+            result.best_fitness
+        );
+        // A single registered operator is always picked
+        assert_eq!(result.mutation_usage.len(), 1);
+        assert_eq!(result.mutation_usage[0].probability, 1.0);
+        assert!(result.crossover_usage[0].applications > 0);
+    }
+
+    #[test]
+    fn test_niching_methods() {
+        let problem = OneMaxProblem { n: 20 };
+
+        for niching in [
+            Niching::FitnessSharing {
+                sigma: 4.0,
+                alpha: 1.0,
+            },
+            Niching::DeterministicCrowding,
+            Niching::RestrictedTournament { window: 6 },
+        ] {
+            let config = GaConfig::default()
+                .with_population_size(30)
+                .with_max_generations(100)
+                .with_mutation_rate(0.3)
+                .with_niching(niching)
+                .with_seed(42)
+                .with_parallel(false);
+
+            let result = GaRunner::run(&problem, &config).unwrap();
+
+            assert!(
+                result.best_fitness <= -15.0,
+                "{niching:?} should make progress, got fitness {}",
+                result.best_fitness
+            );
+            if niching.replaces_population() {
+                for window in result.generation_stats.windows(2) {
+                    assert!(
+                        window[1].best_fitness <= window[0].best_fitness,
+                        "{niching:?} lost the population best"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_duplicate_elimination_and_diversity() {
+        let problem = OneMaxProblem { n: 40 };
+        let config = GaConfig::default()
+            .with_population_size(20)
+            .with_max_generations(30)
+            .with_stagnation_limit(0)
+            .with_duplicate_elimination(true)
+            .with_diversity_stats(true)
+            .with_seed(5)
+            .with_parallel(false);
+
+        let mut engine = GaEngine::new(&problem, &config).unwrap();
+        assert!(engine.next().unwrap().diversity > 0.0);
+        while let Some(stats) = engine.step() {
+            assert!(duplicate_indices(&problem, engine.population()).is_empty());
+            // 20 distinct bit strings differ in at least one bit each
+            assert!(stats.diversity >= 1.0, "{stats:?}");
         }
+        let result = engine.into_result();
+        assert!(result.generation_stats.iter().all(|s| s.diversity > 0.0));
+
+        // Not computed unless asked for
+        let result = GaRunner::run(&problem, &config.with_diversity_stats(false)).unwrap();
+        assert!(result.generation_stats.iter().all(|s| s.diversity == 0.0));
     }
 
-    struct OneMaxProblem {
-        n: usize,
+    /// OneMax whose local search sets the first unset bit.
+    struct MemeticOneMax {
+        inner: OneMaxProblem,
+        calls: std::sync::atomic::AtomicUsize,
     }
 
-    impl GaProblem for OneMaxProblem {
+    impl GaProblem for MemeticOneMax {
         type Individual = BitString;
 
         fn create_individual<R: Rng>(&self, rng: &mut R) -> BitString {
-            let bits: Vec<bool> = (0..self.n).map(|_| rng.random_bool(0.5)).collect();
-            BitString {
-                bits,
-                fitness: f64::INFINITY,
-            }
+            self.inner.create_individual(rng)
         }
 
         fn evaluate(&self, ind: &BitString) -> f64 {
-            // Minimize negative count of true bits
-            -(ind.bits.iter().filter(|&&b| b).count() as f64)
+            self.inner.evaluate(ind)
         }
 
         fn crossover<R: Rng>(&self, p1: &BitString, p2: &BitString, rng: &mut R) -> Vec<BitString> {
-            // Single-point crossover
-            let point = rng.random_range(0..self.n);
-            let mut c1_bits = Vec::with_capacity(self.n);
-            let mut c2_bits = Vec::with_capacity(self.n);
-            for i in 0..self.n {
-                if i < point {
-                    c1_bits.push(p1.bits[i]);
-                    c2_bits.push(p2.bits[i]);
-                } else {
-                    c1_bits.push(p2.bits[i]);
-                    c2_bits.push(p1.bits[i]);
+            self.inner.crossover(p1, p2, rng)
+        }
+
+        fn improve(&self, ind: &mut BitString) -> bool {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            match ind.bits.iter().position(|&b| !b) {
+                Some(i) => {
+                    ind.bits[i] = true;
+                    true
                 }
+                None => false,
             }
-            vec![
-                BitString {
-                    bits: c1_bits,
-                    fitness: f64::INFINITY,
-                },
-                BitString {
-                    bits: c2_bits,
-                    fitness: f64::INFINITY,
-                },
-            ]
         }
+    }
 
-        fn mutate<R: Rng>(&self, ind: &mut BitString, rng: &mut R) {
-            // Flip one random bit
-            let idx = rng.random_range(0..self.n);
-            ind.bits[idx] = !ind.bits[idx];
+    fn memetic(n: usize) -> MemeticOneMax {
+        MemeticOneMax {
+            inner: OneMaxProblem { n },
+            calls: std::sync::atomic::AtomicUsize::new(0),
         }
     }
 
     #[test]
-    fn test_onemax_convergence() {
-        let problem = OneMaxProblem { n: 20 };
+    fn test_lamarckian_local_search() {
         let config = GaConfig::default()
-            .with_population_size(50)
-            .with_max_generations(200)
-            .with_mutation_rate(0.3)
-            .with_seed(42)
-            .with_parallel(false);
-
-        let result = GaRunner::run(&problem, &config).unwrap();
-
-        // Should find near-optimal solution (all true = fitness -20)
-        assert!(
-            result.best_fitness <= -15.0,
-            "expected fitness <= -15.0 for 20-bit OneMax, got {}",
-            result.best_fitness
-        );
+            .with_population_size(20)
+            .with_max_generations(20)
+            .with_stagnation_limit(0)
+            .with_seed(42);
+
+        let plain = GaRunner::run(&memetic(40), &config).unwrap();
+        let problem = memetic(40);
+        let result = GaRunner::run(
+            &problem,
+            &config.clone().with_local_search(LocalSearch {
+                rate: 0.5,
+                mode: LearningMode::Lamarckian,
+            }),
+        )
+        .unwrap();
+
+        assert!(result.best_fitness < plain.best_fitness);
+        // The improved genotype was written back
+        assert_eq!(problem.evaluate(&result.best), result.best_fitness);
+        let calls = problem.calls.load(Ordering::Relaxed);
+        let offspring = 20 * (20 - 2); // two elites per generation
+        assert!(calls > 0 && calls < offspring, "{calls} local searches");
     }
 
     #[test]
-    fn test_stagnation_termination() {
-        let problem = OneMaxProblem { n: 5 };
+    fn test_baldwinian_local_search_keeps_genotype() {
+        let problem = memetic(40);
         let config = GaConfig::default()
             .with_population_size(20)
-            .with_max_generations(1000)
-            .with_stagnation_limit(10)
+            .with_max_generations(20)
+            .with_stagnation_limit(0)
+            .with_local_search(LocalSearch {
+                rate: 1.0,
+                mode: LearningMode::Baldwinian,
+            })
             .with_seed(42)
             .with_parallel(false);
 
         let result = GaRunner::run(&problem, &config).unwrap();
 
-        // Should stop early due to convergence
-        assert!(
-            result.stagnated || result.generations < 1000,
-            "expected stagnation or early stop"
-        );
+        // The best carries the fitness of its improved version, one bit better
+        assert_eq!(problem.evaluate(&result.best), result.best_fitness + 1.0);
     }
 
     #[test]
-    fn test_cancellation() {
+    fn test_all_replacement_strategies() {
         let problem = OneMaxProblem { n: 20 };
-        let config = GaConfig::default()
-            .with_population_size(50)
-            .with_max_generations(10000)
-            .with_stagnation_limit(0) // disable stagnation
-            .with_seed(42)
-            .with_parallel(false);
 
-        let cancel = Arc::new(AtomicBool::new(false));
+        for replacement in [
+            Replacement::Generational,
+            Replacement::SteadyState(SteadyStatePolicy::ReplaceWorst),
+            Replacement::SteadyState(SteadyStatePolicy::ReplaceRandom),
+            Replacement::SteadyState(SteadyStatePolicy::ParentIfBetter),
+            Replacement::Asynchronous {
+                workers: 4,
+                policy: SteadyStatePolicy::ReplaceWorst,
+            },
+            Replacement::MuPlusLambda(30),
+            Replacement::MuCommaLambda(60),
+        ] {
+            let config = GaConfig::default()
+                .with_population_size(30)
+                .with_max_generations(100)
+                .with_mutation_rate(0.3)
+                .with_replacement(replacement)
+                .with_seed(42)
+                .with_parallel(false);
 
-        // Cancel after a few generations
-        let cancel_clone = cancel.clone();
-        std::thread::spawn(move || {
-            std::thread::sleep(std::time::Duration::from_millis(10));
-            cancel_clone.store(true, Ordering::Relaxed);
-        });
+            let result = GaRunner::run(&problem, &config).unwrap();
 
-        let result = GaRunner::run_with_cancel(&problem, &config, Some(cancel)).unwrap();
+            assert!(
+                result.best_fitness <= -15.0,
+                "replacement {:?} should make progress, got fitness {}",
+                replacement,
+                result.best_fitness
+            );
+            assert!(result
+                .generation_stats
+                .iter()
+                .all(|s| s.best_fitness.is_finite() && s.worst_fitness.is_finite()));
+        }
+    }
 
-        assert!(result.cancelled, "expected cancelled result");
-        assert!(result.generations < 10000, "should have stopped early");
+    #[test]
+    fn test_elitist_replacements_never_lose_population_best() {
+        let problem = OneMaxProblem { n: 30 };
+
+        for replacement in [
+            Replacement::SteadyState(SteadyStatePolicy::ReplaceWorst),
+            Replacement::SteadyState(SteadyStatePolicy::ReplaceRandom),
+            Replacement::SteadyState(SteadyStatePolicy::ParentIfBetter),
+            Replacement::Asynchronous {
+                workers: 4,
+                policy: SteadyStatePolicy::ReplaceWorst,
+            },
+            Replacement::MuPlusLambda(10),
+        ] {
+            let config = GaConfig::default()
+                .with_population_size(20)
+                .with_max_generations(40)
+                .with_stagnation_limit(0)
+                .with_replacement(replacement)
+                .with_seed(7)
+                .with_parallel(false);
+
+            let result = GaRunner::run(&problem, &config).unwrap();
+
+            for window in result.generation_stats.windows(2) {
+                assert!(
+                    window[1].best_fitness <= window[0].best_fitness,
+                    "{replacement:?} lost the population best"
+                );
+            }
+        }
     }
 
     #[test]
-    fn test_elite_preservation() {
-        let problem = OneMaxProblem { n: 10 };
+    fn test_parent_if_better_never_worsens_population() {
+        let problem = OneMaxProblem { n: 30 };
         let config = GaConfig::default()
             .with_population_size(20)
-            .with_max_generations(50)
-            .with_elite_ratio(0.2)
-            .with_seed(42)
+            .with_max_generations(30)
+            .with_stagnation_limit(0)
+            .with_replacement(Replacement::SteadyState(SteadyStatePolicy::ParentIfBetter))
+            .with_seed(3)
             .with_parallel(false);
 
         let result = GaRunner::run(&problem, &config).unwrap();
 
-        // Fitness should never get worse across generations
-        for window in result.fitness_history.windows(2) {
-            assert!(
-                window[1] <= window[0],
-                "fitness should be monotonically non-increasing with elitism: {} > {}",
-                window[1],
-                window[0]
-            );
+        for window in result.generation_stats.windows(2) {
+            assert!(window[1].mean_fitness <= window[0].mean_fitness);
+            assert!(window[1].worst_fitness <= window[0].worst_fitness);
         }
     }
 
     #[test]
-    fn test_fitness_history() {
-        let problem = OneMaxProblem { n: 10 };
+    fn test_asynchronous_with_one_worker_is_steady_state() {
+        let problem = OneMaxProblem { n: 30 };
         let config = GaConfig::default()
             .with_population_size(20)
             .with_max_generations(30)
-            .with_stagnation_limit(0)
-            .with_seed(42)
+            .with_seed(5)
             .with_parallel(false);
 
-        let result = GaRunner::run(&problem, &config).unwrap();
-
-        // History should have max_generations + 1 entries (initial + each gen)
-        assert_eq!(result.fitness_history.len(), 31);
+        let steady = GaRunner::run(
+            &problem,
+            &config
+                .clone()
+                .with_replacement(Replacement::SteadyState(SteadyStatePolicy::ReplaceRandom)),
+        )
+        .unwrap();
+        let asynchronous = GaRunner::run(
+            &problem,
+            &config.with_replacement(Replacement::Asynchronous {
+                workers: 1,
+                policy: SteadyStatePolicy::ReplaceRandom,
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(steady.fitness_history, asynchronous.fitness_history);
     }
 
+    #[cfg(feature = "parallel")]
     #[test]
-    fn test_all_selection_strategies() {
-        let problem = OneMaxProblem { n: 10 };
-
-        for selection in [
-            Selection::Tournament(3),
-            Selection::Roulette,
-            Selection::Rank,
-        ] {
-            let config = GaConfig::default()
-                .with_population_size(30)
-                .with_max_generations(50)
-                .with_selection(selection)
-                .with_seed(42)
-                .with_parallel(false);
+    fn test_asynchronous_parallel_workers() {
+        let problem = OneMaxProblem { n: 20 };
+        let config = GaConfig::default()
+            .with_population_size(30)
+            .with_max_generations(100)
+            .with_mutation_rate(0.3)
+            .with_replacement(Replacement::Asynchronous {
+                workers: 4,
+                policy: SteadyStatePolicy::ReplaceWorst,
+            })
+            .with_seed(42);
 
-            let result = GaRunner::run(&problem, &config).unwrap();
+        let result = GaRunner::run(&problem, &config).unwrap();
 
-            assert!(
-                result.best_fitness < 0.0,
-                "selection {:?} should find some true bits, got fitness {}",
-                selection,
-                result.best_fitness
-            );
-        }
+        assert!(result.best_fitness <= -15.0, "got {}", result.best_fitness);
+        assert_eq!(problem.evaluate(&result.best), result.best_fitness);
     }
 
     #[test]
@@ -852,4 +2855,217 @@ mod tests {
         // Stats count should match fitness_history
         assert_eq!(result.generation_stats.len(), result.fitness_history.len());
     }
+
+    // ---- Constrained problem: minimize Σx subject to Σx ≥ 5, x ∈ [0, 10]ⁿ ----
+
+    #[derive(Clone, Debug)]
+    struct Constrained {
+        genes: Vec<f64>,
+        fitness: f64,
+        violation: f64,
+    }
+
+    impl Individual for Constrained {
+        type Fitness = f64;
+        fn fitness(&self) -> f64 {
+            self.fitness
+        }
+        fn set_fitness(&mut self, f: f64) {
+            self.fitness = f;
+        }
+        fn violation(&self) -> f64 {
+            self.violation
+        }
+        fn set_violation(&mut self, v: f64) {
+            self.violation = v;
+        }
+    }
+
+    struct MinSumProblem;
+
+    impl GaProblem for MinSumProblem {
+        type Individual = Constrained;
+
+        fn create_individual<R: Rng>(&self, rng: &mut R) -> Constrained {
+            Constrained {
+                genes: (0..4).map(|_| rng.random_range(0.0..10.0)).collect(),
+                fitness: f64::INFINITY,
+                violation: 0.0,
+            }
+        }
+
+        fn evaluate(&self, ind: &Constrained) -> f64 {
+            ind.genes.iter().sum()
+        }
+
+        fn constraint_violation(&self, ind: &Constrained) -> f64 {
+            (5.0 - ind.genes.iter().sum::<f64>()).max(0.0)
+        }
+
+        fn crossover<R: Rng>(
+            &self,
+            a: &Constrained,
+            b: &Constrained,
+            rng: &mut R,
+        ) -> Vec<Constrained> {
+            let genes = a
+                .genes
+                .iter()
+                .zip(&b.genes)
+                .map(|(&x, &y)| {
+                    let t: f64 = rng.random_range(0.0..1.0);
+                    x + t * (y - x)
+                })
+                .collect();
+            vec![Constrained {
+                genes,
+                fitness: f64::INFINITY,
+                violation: 0.0,
+            }]
+        }
+
+        fn mutate<R: Rng>(&self, ind: &mut Constrained, rng: &mut R) {
+            let i = rng.random_range(0..ind.genes.len());
+            ind.genes[i] = (ind.genes[i] + rng.random_range(-1.0..1.0)).clamp(0.0, 10.0);
+        }
+    }
+
+    #[test]
+    fn test_constraint_handlers_find_feasible_optimum() {
+        for handling in [
+            ConstraintHandling::FeasibilityRules,
+            ConstraintHandling::StaticPenalty(100.0),
+            ConstraintHandling::DynamicPenalty { c: 0.5, alpha: 2.0 },
+            ConstraintHandling::AdaptivePenalty {
+                initial: 1.0,
+                beta: 2.0,
+                window: 3,
+            },
+            ConstraintHandling::EpsilonConstrained {
+                epsilon: 2.0,
+                exponent: 2.0,
+                control_generations: 50,
+            },
+            ConstraintHandling::StochasticRanking(0.45),
+        ] {
+            let config = GaConfig::default()
+                .with_population_size(50)
+                .with_max_generations(150)
+                .with_stagnation_limit(0)
+                .with_mutation_rate(0.5)
+                .with_constraint_handling(handling)
+                .with_seed(42)
+                .with_parallel(false);
+
+            let result = GaRunner::run(&MinSumProblem, &config).unwrap();
+
+            assert_eq!(
+                result.best.violation, 0.0,
+                "{handling:?} best is infeasible"
+            );
+            assert!(
+                result.best_fitness < 5.5,
+                "{handling:?} should approach the boundary Σx = 5, got {}",
+                result.best_fitness
+            );
+        }
+    }
+
+    // ---- Hierarchical fitness ----
+
+    #[derive(Clone, Debug)]
+    struct Ranked {
+        fitness: Lexicographic<2>,
+    }
+
+    impl Individual for Ranked {
+        type Fitness = Lexicographic<2>;
+        fn fitness(&self) -> Lexicographic<2> {
+            self.fitness
+        }
+        fn set_fitness(&mut self, f: Lexicographic<2>) {
+            self.fitness = f;
+        }
+    }
+
+    #[test]
+    fn test_update_best_with_lexicographic_fitness() {
+        let ranked = |tardy, makespan| Ranked {
+            fitness: Lexicographic([tardy, makespan]),
+        };
+        let mut best = ranked(1.0, 100.0);
+
+        // 10% shorter makespan counts although to_f64 barely moves
+        assert!(update_best(
+            &mut best,
+            &ranked(1.0, 90.0),
+            0.05,
+            Direction::Minimize
+        ));
+        assert!(!update_best(
+            &mut best,
+            &ranked(1.0, 89.9),
+            0.05,
+            Direction::Minimize
+        ));
+        assert_eq!(best.fitness, Lexicographic([1.0, 89.9]));
+        // The first component decides, whatever the second
+        assert!(update_best(
+            &mut best,
+            &ranked(0.0, 500.0),
+            0.05,
+            Direction::Minimize
+        ));
+        assert!(!update_best(
+            &mut best,
+            &ranked(1.0, 1.0),
+            0.0,
+            Direction::Minimize
+        ));
+        assert_eq!(best.fitness, Lexicographic([0.0, 500.0]));
+    }
+
+    #[test]
+    fn test_feasibility_first_fitness() {
+        let feasible = FeasibilityFirst::new(0.0, 10.0);
+        assert!(feasible < FeasibilityFirst::new(0.1, -100.0));
+        assert!(FeasibilityFirst::new(0.1, 5.0) < FeasibilityFirst::new(0.2, 0.0));
+        assert_eq!(FeasibilityFirst::new(2.0, 1.0).to_f64(), 2_000_001.0);
+        assert_eq!(FeasibilityFirst::<f64>::worst().to_f64(), f64::INFINITY);
+
+        // Reaching feasibility always counts; then the threshold applies
+        assert!(feasible.improves_on(FeasibilityFirst::new(0.1, 10.0), 0.5));
+        assert!(!FeasibilityFirst::new(0.0, 9.9).improves_on(feasible, 0.5));
+        assert!(FeasibilityFirst::new(0.0, 4.0).improves_on(feasible, 0.5));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_lexicographic_serde_roundtrip() {
+        let fitness = Lexicographic([1.0, 2.5]);
+        let json = serde_json::to_string(&fitness).unwrap();
+        assert_eq!(json, "[1.0,2.5]");
+        assert_eq!(
+            serde_json::from_str::<Lexicographic<2>>(&json).unwrap(),
+            fitness
+        );
+        assert!(serde_json::from_str::<Lexicographic<3>>(&json).is_err());
+    }
+
+    #[test]
+    fn test_find_best_prefers_feasible() {
+        let population = vec![
+            Constrained {
+                genes: vec![],
+                fitness: 0.0,
+                violation: 1.0,
+            },
+            Constrained {
+                genes: vec![],
+                fitness: 9.0,
+                violation: 0.0,
+            },
+        ];
+        assert_eq!(find_best(&population, Direction::Minimize).fitness, 9.0);
+    }
 }
@@ -5,17 +5,29 @@
 //!
 //! # References
 //!
+//! - Baker (1987), "Reducing Bias and Inefficiency in the Selection Algorithm"
 //! - Blickle & Thiele (1996), "A Comparison of Selection Schemes used in
 //!   Evolutionary Algorithms"
 //! - Goldberg & Deb (1991), "A Comparative Analysis of Selection Schemes
 //!   Used in Genetic Algorithms"
+//! - Spector (2012), "Assessment of Problem Modality by Differential
+//!   Performance of Lexicase Selection in Genetic Programming"
 
 use super::types::{Fitness, Individual};
+use crate::direction::Direction;
 use rand::Rng;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use u_numflow::random::shuffle;
+
+/// Temperatures below this are treated as this value by Boltzmann selection.
+const MIN_TEMPERATURE: f64 = 1e-12;
 
 /// Selection strategy for choosing parents.
 ///
-/// All strategies assume **minimization** (lower fitness = better).
+/// All strategies assume **minimization** (lower fitness = better); the
+/// runners select on negated fitness when
+/// [`GaConfig::direction`](super::GaConfig::direction) maximizes.
 ///
 /// # Examples
 ///
@@ -28,7 +40,7 @@ use rand::Rng;
 /// // Roulette wheel (fitness-proportionate)
 /// let sel = Selection::Roulette;
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Selection {
     /// Tournament selection: pick `k` individuals at random, select the best.
     ///
@@ -65,9 +77,88 @@ pub enum Selection {
     /// Reference: Baker (1985), "Adaptive Selection Methods for Genetic
     /// Algorithms"
     ///
+    /// The weights are fixed at `n - i`; use [`Selection::LinearRank`] to
+    /// choose the pressure.
+    ///
     /// # Complexity
     /// O(n log n) per generation (sort), O(n) per selection
     Rank,
+
+    /// Linear ranking with selection pressure `s` in `[1, 2]`.
+    ///
+    /// P(i) = (2 - s)/n + 2·(n - 1 - i)·(s - 1)/(n·(n - 1)) for rank `i`
+    /// (0 = best). `s = 1` selects uniformly; `s = 2` gives the worst
+    /// individual zero probability.
+    ///
+    /// # Complexity
+    /// O(n log n) per selection (sort)
+    LinearRank(f64),
+
+    /// Stochastic universal sampling (SUS).
+    ///
+    /// Fitness-proportionate like [`Selection::Roulette`], but a whole
+    /// mating pool is drawn with one spin of a wheel with equally spaced
+    /// pointers, so each individual is selected within one of its expected
+    /// number of copies. The GA draws one pool per generation; a single
+    /// [`select`](Selection::select) call is a roulette spin.
+    ///
+    /// Reference: Baker (1987)
+    ///
+    /// # Complexity
+    /// O(n + m) per pool of `m` parents
+    StochasticUniversal,
+
+    /// Truncation selection: pick uniformly among the best fraction of the
+    /// population.
+    ///
+    /// The fraction must be in `(0, 1]`; `0.5` keeps the better half.
+    ///
+    /// # Complexity
+    /// O(n log n) per selection (sort)
+    Truncation(f64),
+
+    /// Boltzmann selection: P(i) ∝ exp(-(fᵢ - f_min) / T).
+    ///
+    /// The temperature follows the schedule
+    /// `T(g) = temperature · cooling_rate^g` over generations `g`, so
+    /// pressure starts low and rises as the run cools. Temperatures are in
+    /// fitness units.
+    ///
+    /// Reference: de la Maza & Tidor (1993), "An Analysis of Selection
+    /// Procedures with Particular Attention Paid to Proportional and
+    /// Boltzmann Selection"
+    ///
+    /// # Complexity
+    /// O(n) per selection
+    Boltzmann {
+        /// Initial temperature `T(0)`, must be positive.
+        temperature: f64,
+        /// Per-generation cooling factor in `(0, 1]`.
+        cooling_rate: f64,
+    },
+
+    /// Lexicase selection for problems evaluated on many test cases.
+    ///
+    /// Starting with the whole population, the cases are visited in random
+    /// order and only the individuals with the lowest error on each case
+    /// are kept, until one remains or the cases run out (then one of the
+    /// survivors is picked at random). Case errors come from
+    /// [`GaProblem::case_errors`](super::GaProblem::case_errors); without
+    /// them, selection falls back to fitness.
+    ///
+    /// # Complexity
+    /// O(n · c) per selection for `c` cases
+    Lexicase,
+}
+
+/// Per-generation inputs that some selection schemes need.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SelectionContext<'a> {
+    /// Number of completed generations; drives the Boltzmann temperature.
+    pub generation: usize,
+    /// Per-individual case errors for lexicase selection, indexed like the
+    /// selection keys. Empty when unused.
+    pub cases: &'a [Vec<f64>],
 }
 
 impl Default for Selection {
@@ -86,22 +177,224 @@ impl Selection {
         }
 
         match self {
-            Selection::Tournament(k) => tournament(population, *k, rng),
-            Selection::Roulette => roulette(population, rng),
-            Selection::Rank => rank(population, rng),
+            Selection::Tournament(k) => tournament(population.len(), *k, rng, |a, b| {
+                population[a].fitness() < population[b].fitness()
+            }),
+            _ => self.select_in(
+                &fitness_keys(&fitnesses(population), Direction::Minimize),
+                &SelectionContext::default(),
+                rng,
+            ),
+        }
+    }
+
+    /// Select a parent index using precomputed selection keys (lower is
+    /// better) instead of individual fitness.
+    ///
+    /// Used when constraint handling replaces raw fitness with a penalized
+    /// or ranked value. Returns `0` if `keys` is empty.
+    pub fn select_keyed<R: Rng>(&self, keys: &[f64], rng: &mut R) -> usize {
+        self.select_in(keys, &SelectionContext::default(), rng)
+    }
+
+    /// Select a mating pool of `count` parent indices at once.
+    ///
+    /// [`Selection::StochasticUniversal`] spreads the pool over the
+    /// population with a single spin; every other scheme makes `count`
+    /// independent selections. `keys` are lower-is-better selection keys.
+    /// Returns an empty pool if `keys` is empty.
+    pub fn select_pool<R: Rng>(
+        &self,
+        keys: &[f64],
+        count: usize,
+        context: &SelectionContext<'_>,
+        rng: &mut R,
+    ) -> Vec<usize> {
+        if keys.is_empty() {
+            return Vec::new();
+        }
+
+        match self {
+            Selection::StochasticUniversal => {
+                let mut pool = stochastic_universal(keys, count, rng);
+                // Pointers visit the population in order; shuffle so that
+                // consecutive parents are not neighbors.
+                shuffle(&mut pool, rng);
+                pool
+            }
+            _ => (0..count)
+                .map(|_| self.select_in(keys, context, rng))
+                .collect(),
+        }
+    }
+
+    /// Checks that the scheme's parameters are in range.
+    pub(super) fn validate(&self) -> Result<(), String> {
+        match *self {
+            Selection::LinearRank(s) if !(1.0..=2.0).contains(&s) => Err(format!(
+                "linear ranking pressure must be in [1, 2], got {s}"
+            )),
+            Selection::Truncation(fraction) if fraction <= 0.0 || fraction > 1.0 => Err(format!(
+                "truncation fraction must be in (0, 1], got {fraction}"
+            )),
+            Selection::Boltzmann {
+                temperature,
+                cooling_rate,
+            } if temperature <= 0.0 || cooling_rate <= 0.0 || cooling_rate > 1.0 => {
+                Err("Boltzmann selection needs temperature > 0 and cooling_rate in (0, 1]".into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Select one parent index from `keys` (lower is better).
+    fn select_in<R: Rng>(
+        &self,
+        keys: &[f64],
+        context: &SelectionContext<'_>,
+        rng: &mut R,
+    ) -> usize {
+        if keys.is_empty() {
+            return 0;
+        }
+
+        match *self {
+            Selection::Tournament(k) => tournament(keys.len(), k, rng, |a, b| keys[a] < keys[b]),
+            Selection::Roulette | Selection::StochasticUniversal => roulette(keys, rng),
+            Selection::Rank => rank(keys, rng),
+            Selection::LinearRank(pressure) => linear_rank(keys, pressure, rng),
+            Selection::Truncation(fraction) => truncation(keys, fraction, rng),
+            Selection::Boltzmann {
+                temperature,
+                cooling_rate,
+            } => {
+                let t = temperature * cooling_rate.powf(context.generation as f64);
+                boltzmann(keys, t.max(MIN_TEMPERATURE), rng)
+            }
+            Selection::Lexicase if context.cases.len() == keys.len() => {
+                lexicase(context.cases, rng)
+            }
+            Selection::Lexicase => lexicase_on_keys(keys, rng),
+        }
+    }
+}
+
+/// Draws parents for one breeding round of the GA.
+///
+/// Stochastic universal sampling draws its pool in one go; every other
+/// scheme selects parents one at a time as they are requested.
+pub(super) struct ParentSampler<'a, I> {
+    selection: Selection,
+    population: &'a [I],
+    /// Selection keys; filled with fitness on first use when not given.
+    keys: Option<Cow<'a, [f64]>>,
+    context: SelectionContext<'a>,
+    pool: Vec<usize>,
+    pool_size: usize,
+}
+
+impl<'a, I: Individual> ParentSampler<'a, I> {
+    /// Samples from `population`, using `keys` when given and fitness
+    /// otherwise. `pool_size` is the expected number of parents.
+    pub(super) fn new(
+        selection: Selection,
+        population: &'a [I],
+        keys: Option<&'a [f64]>,
+        context: SelectionContext<'a>,
+        pool_size: usize,
+    ) -> Self {
+        Self {
+            selection,
+            population,
+            keys: keys.map(Cow::Borrowed),
+            context,
+            pool: Vec::new(),
+            pool_size,
+        }
+    }
+
+    /// Index of the next parent.
+    pub(super) fn next<R: Rng>(&mut self, rng: &mut R) -> usize {
+        if let (Selection::Tournament(_), None) = (self.selection, &self.keys) {
+            // Compares fitness directly; no keys needed
+            return self.selection.select(self.population, rng);
+        }
+        let keys = self.keys.get_or_insert_with(|| {
+            Cow::Owned(fitness_keys(
+                &fitnesses(self.population),
+                Direction::Minimize,
+            ))
+        });
+
+        if self.selection != Selection::StochasticUniversal {
+            return self.selection.select_in(keys, &self.context, rng);
+        }
+        if self.pool.is_empty() {
+            self.pool = self
+                .selection
+                .select_pool(keys, self.pool_size.max(2), &self.context, rng);
         }
+        self.pool.pop().unwrap_or(0)
     }
 }
 
+pub(super) fn fitnesses<I: Individual>(population: &[I]) -> Vec<I::Fitness> {
+    population.iter().map(Individual::fitness).collect()
+}
+
+/// Selection keys (lower is better) that follow the fitness order.
+///
+/// The keys are the fitness values, negated when maximizing, as long as
+/// [`to_f64`](Fitness::to_f64) agrees with the fitness comparison on
+/// `fitnesses`, so that fitness-proportionate schemes see magnitudes.
+/// Otherwise, e.g. for [`Lexicographic`](super::Lexicographic) components
+/// beyond its scale, they are ranks: 0 for the best, with equal fitnesses
+/// sharing a rank.
+pub(super) fn fitness_keys<F: Fitness>(fitnesses: &[F], direction: Direction) -> Vec<f64> {
+    let cmp = |a: usize, b: usize| {
+        direction
+            .compare(&fitnesses[a], &fitnesses[b])
+            .unwrap_or(Ordering::Equal)
+    };
+    let mut order: Vec<usize> = (0..fitnesses.len()).collect();
+    order.sort_by(|&a, &b| cmp(a, b));
+
+    let values: Vec<f64> = fitnesses
+        .iter()
+        .map(|f| direction.cost(f.to_f64()))
+        .collect();
+    let consistent = order.windows(2).all(|w| match cmp(w[0], w[1]) {
+        Ordering::Equal => values[w[0]] == values[w[1]],
+        _ => values[w[0]] < values[w[1]],
+    });
+    if consistent {
+        return values;
+    }
+
+    let mut ranks = vec![0.0; fitnesses.len()];
+    let mut rank = 0;
+    for (position, &i) in order.iter().enumerate() {
+        if position > 0 && cmp(order[position - 1], i) != Ordering::Equal {
+            rank = position;
+        }
+        ranks[i] = rank as f64;
+    }
+    ranks
+}
+
 /// Tournament selection: pick k random individuals, return best.
-fn tournament<I: Individual, R: Rng>(population: &[I], k: usize, rng: &mut R) -> usize {
+fn tournament<R: Rng>(
+    n: usize,
+    k: usize,
+    rng: &mut R,
+    better: impl Fn(usize, usize) -> bool,
+) -> usize {
     let k = k.max(1);
-    let n = population.len();
 
     let mut best_idx = rng.random_range(0..n);
     for _ in 1..k {
         let idx = rng.random_range(0..n);
-        if population[idx].fitness() < population[best_idx].fitness() {
+        if better(idx, best_idx) {
             best_idx = idx;
         }
     }
@@ -112,34 +405,13 @@ fn tournament<I: Individual, R: Rng>(population: &[I], k: usize, rng: &mut R) ->
 ///
 /// For minimization: weight_i = max_fitness - fitness_i + epsilon
 /// This ensures the best (lowest fitness) individual gets the highest weight.
-fn roulette<I: Individual, R: Rng>(population: &[I], rng: &mut R) -> usize {
-    let n = population.len();
+fn roulette<R: Rng>(fitnesses: &[f64], rng: &mut R) -> usize {
+    let n = fitnesses.len();
     if n == 1 {
         return 0;
     }
 
-    let fitnesses: Vec<f64> = population
-        .iter()
-        .map(|ind| ind.fitness().to_f64())
-        .collect();
-
-    // Find max fitness for inversion
-    let max_fitness = fitnesses.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
-
-    let epsilon = 1e-10;
-
-    // Invert: lower fitness -> higher weight
-    let weights: Vec<f64> = fitnesses
-        .iter()
-        .map(|&f| {
-            let w = max_fitness - f + epsilon;
-            if w > 0.0 {
-                w
-            } else {
-                epsilon
-            }
-        })
-        .collect();
+    let weights = roulette_weights(fitnesses);
 
     let total: f64 = weights.iter().sum();
     if total <= 0.0 {
@@ -158,22 +430,39 @@ fn roulette<I: Individual, R: Rng>(population: &[I], rng: &mut R) -> usize {
     n - 1 // floating-point fallback
 }
 
+/// Roulette weights for minimization: `max_fitness - fitness_i + epsilon`.
+fn roulette_weights(fitnesses: &[f64]) -> Vec<f64> {
+    // Find max fitness for inversion
+    let max_fitness = fitnesses.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+    let epsilon = 1e-10;
+
+    // Invert: lower fitness -> higher weight
+    fitnesses
+        .iter()
+        .map(|&f| {
+            let w = max_fitness - f + epsilon;
+            if w > 0.0 {
+                w
+            } else {
+                epsilon
+            }
+        })
+        .collect()
+}
+
 /// Rank-based selection using linear ranking.
 ///
 /// Individuals are sorted by fitness (best first), then selection
 /// probability is proportional to rank.
-fn rank<I: Individual, R: Rng>(population: &[I], rng: &mut R) -> usize {
-    let n = population.len();
+fn rank<R: Rng>(fitnesses: &[f64], rng: &mut R) -> usize {
+    let n = fitnesses.len();
     if n == 1 {
         return 0;
     }
 
     // Build (index, fitness) pairs and sort by fitness ascending (best first)
-    let mut indexed: Vec<(usize, f64)> = population
-        .iter()
-        .enumerate()
-        .map(|(i, ind)| (i, ind.fitness().to_f64()))
-        .collect();
+    let mut indexed: Vec<(usize, f64)> = fitnesses.iter().copied().enumerate().collect();
     indexed.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
 
     // Linear ranking: rank 0 (best) gets highest weight
@@ -193,6 +482,151 @@ fn rank<I: Individual, R: Rng>(population: &[I], rng: &mut R) -> usize {
     indexed.last().expect("population has n >= 2 elements").0 // fallback
 }
 
+/// Indices of `keys` sorted best (lowest) first.
+fn ranked_indices(keys: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..keys.len()).collect();
+    order.sort_by(|&a, &b| {
+        keys[a]
+            .partial_cmp(&keys[b])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    order
+}
+
+/// Linear ranking with selection pressure `pressure` (Baker, 1985).
+fn linear_rank<R: Rng>(keys: &[f64], pressure: f64, rng: &mut R) -> usize {
+    let n = keys.len();
+    if n == 1 {
+        return 0;
+    }
+
+    let s = pressure.clamp(1.0, 2.0);
+    let nf = n as f64;
+    let order = ranked_indices(keys);
+    let threshold = rng.random_range(0.0..1.0);
+    let mut cumulative = 0.0;
+
+    for (rank, &idx) in order.iter().enumerate() {
+        let worse = (n - 1 - rank) as f64;
+        cumulative += (2.0 - s) / nf + 2.0 * worse * (s - 1.0) / (nf * (nf - 1.0));
+        if cumulative > threshold {
+            return idx;
+        }
+    }
+
+    *order.last().expect("population has n >= 2 elements") // floating-point fallback
+}
+
+/// Truncation selection: uniform among the best `fraction` of `keys`.
+fn truncation<R: Rng>(keys: &[f64], fraction: f64, rng: &mut R) -> usize {
+    let order = ranked_indices(keys);
+    let kept = ((keys.len() as f64 * fraction).ceil() as usize).clamp(1, keys.len());
+    order[rng.random_range(0..kept)]
+}
+
+/// Boltzmann selection at temperature `t`.
+fn boltzmann<R: Rng>(keys: &[f64], t: f64, rng: &mut R) -> usize {
+    let n = keys.len();
+    let min_key = keys.iter().cloned().fold(f64::INFINITY, f64::min);
+
+    // Shift by the best key so the best individual has weight 1
+    let weights: Vec<f64> = keys
+        .iter()
+        .map(|&k| {
+            let w = (-(k - min_key) / t).exp();
+            if w.is_finite() {
+                w
+            } else {
+                0.0
+            }
+        })
+        .collect();
+
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return rng.random_range(0..n);
+    }
+
+    let threshold = rng.random_range(0.0..total);
+    let mut cumulative = 0.0;
+    for (i, &w) in weights.iter().enumerate() {
+        cumulative += w;
+        if cumulative > threshold {
+            return i;
+        }
+    }
+
+    n - 1 // floating-point fallback
+}
+
+/// Stochastic universal sampling: `count` equally spaced pointers over the
+/// roulette wheel of [`roulette_weights`].
+fn stochastic_universal<R: Rng>(keys: &[f64], count: usize, rng: &mut R) -> Vec<usize> {
+    let weights = roulette_weights(keys);
+    let total: f64 = weights.iter().sum();
+    if count == 0 {
+        return Vec::new();
+    }
+    if !total.is_finite() || total <= 0.0 {
+        return (0..count)
+            .map(|_| rng.random_range(0..keys.len()))
+            .collect();
+    }
+
+    let spacing = total / count as f64;
+    let mut pointer = rng.random_range(0.0..spacing);
+    let mut pool = Vec::with_capacity(count);
+    let mut cumulative = weights[0];
+    let mut i = 0;
+
+    while pool.len() < count {
+        while cumulative <= pointer && i + 1 < weights.len() {
+            i += 1;
+            cumulative += weights[i];
+        }
+        pool.push(i);
+        pointer += spacing;
+    }
+
+    pool
+}
+
+/// Lexicase selection over per-individual case errors.
+fn lexicase<R: Rng>(cases: &[Vec<f64>], rng: &mut R) -> usize {
+    let case_count = cases.iter().map(Vec::len).min().unwrap_or(0);
+    let mut order: Vec<usize> = (0..case_count).collect();
+    shuffle(&mut order, rng);
+
+    let mut candidates: Vec<usize> = (0..cases.len()).collect();
+    for case in order {
+        if candidates.len() <= 1 {
+            break;
+        }
+        let best = candidates
+            .iter()
+            .map(|&i| cases[i][case])
+            .fold(f64::INFINITY, f64::min);
+        candidates.retain(|&i| cases[i][case] <= best);
+    }
+
+    if candidates.is_empty() {
+        // Every remaining error was NaN
+        return rng.random_range(0..cases.len());
+    }
+    candidates[rng.random_range(0..candidates.len())]
+}
+
+/// Lexicase selection with the keys as the only case: a random best
+/// individual.
+fn lexicase_on_keys<R: Rng>(keys: &[f64], rng: &mut R) -> usize {
+    let best = keys.iter().cloned().fold(f64::INFINITY, f64::min);
+    let ties: Vec<usize> = (0..keys.len()).filter(|&i| keys[i] <= best).collect();
+    if ties.is_empty() {
+        return rng.random_range(0..keys.len());
+    }
+    ties[rng.random_range(0..ties.len())]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -418,4 +852,201 @@ mod tests {
             trials
         );
     }
+
+    // ---- Linear ranking ----
+
+    #[test]
+    fn test_linear_rank_pressure() {
+        let keys = [4.0, 1.0, 3.0, 2.0];
+        let mut rng = u_numflow::random::create_rng(42);
+        let trials = 20_000;
+
+        // s = 2: worst (idx 0) has zero probability, best gets 2/n
+        let mut counts = [0u32; 4];
+        for _ in 0..trials {
+            counts[Selection::LinearRank(2.0).select_keyed(&keys, &mut rng)] += 1;
+        }
+        assert_eq!(counts[0], 0, "worst must never be selected: {counts:?}");
+        let best_share = counts[1] as f64 / trials as f64;
+        assert!((best_share - 0.5).abs() < 0.02, "best share {best_share}");
+
+        // s = 1: uniform
+        let mut counts = [0u32; 4];
+        for _ in 0..trials {
+            counts[Selection::LinearRank(1.0).select_keyed(&keys, &mut rng)] += 1;
+        }
+        for &c in &counts {
+            assert!((c as f64 / trials as f64 - 0.25).abs() < 0.02, "{counts:?}");
+        }
+    }
+
+    // ---- Stochastic universal sampling ----
+
+    #[test]
+    fn test_sus_pool_matches_expected_copies() {
+        // Roulette weights ≈ [3, 2, 1, 0] of 6; a pool of 12 gives ≈ [6, 4, 2, 0]
+        let keys = [1.0, 2.0, 3.0, 4.0];
+        let mut rng = u_numflow::random::create_rng(42);
+
+        for _ in 0..100 {
+            let pool = Selection::StochasticUniversal.select_pool(
+                &keys,
+                12,
+                &SelectionContext::default(),
+                &mut rng,
+            );
+            assert_eq!(pool.len(), 12);
+            let mut counts = [0i32; 4];
+            for &i in &pool {
+                counts[i] += 1;
+            }
+            for (&c, expected) in counts.iter().zip([6, 4, 2, 0]) {
+                assert!((c - expected).abs() <= 1, "SUS counts {counts:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_select_pool_sizes() {
+        let keys = [1.0, 2.0, 3.0];
+        let mut rng = u_numflow::random::create_rng(42);
+        let context = SelectionContext::default();
+
+        for selection in [Selection::Tournament(2), Selection::StochasticUniversal] {
+            assert_eq!(selection.select_pool(&keys, 7, &context, &mut rng).len(), 7);
+            assert!(selection.select_pool(&[], 7, &context, &mut rng).is_empty());
+        }
+    }
+
+    // ---- Truncation ----
+
+    #[test]
+    fn test_truncation_keeps_best_fraction() {
+        let keys = [5.0, 1.0, 4.0, 2.0, 3.0, 6.0];
+        let mut rng = u_numflow::random::create_rng(42);
+        let mut counts = [0u32; 6];
+        for _ in 0..3000 {
+            counts[Selection::Truncation(0.5).select_keyed(&keys, &mut rng)] += 1;
+        }
+        // Best half: indices 1, 3, 4
+        assert_eq!(counts[0] + counts[2] + counts[5], 0, "{counts:?}");
+        assert!(counts[1] > 800 && counts[3] > 800 && counts[4] > 800);
+    }
+
+    // ---- Boltzmann ----
+
+    #[test]
+    fn test_boltzmann_temperature_controls_pressure() {
+        let keys = [0.0, 1.0, 2.0, 3.0];
+        let selection = Selection::Boltzmann {
+            temperature: 100.0,
+            cooling_rate: 0.5,
+        };
+        let mut rng = u_numflow::random::create_rng(42);
+        let best_share =
+            |pool: &[usize]| pool.iter().filter(|&&i| i == 0).count() as f64 / pool.len() as f64;
+
+        // T = 100: nearly uniform
+        let hot = SelectionContext {
+            generation: 0,
+            cases: &[],
+        };
+        let pool = selection.select_pool(&keys, 10_000, &hot, &mut rng);
+        assert!((best_share(&pool) - 0.25).abs() < 0.03);
+
+        // T = 100 · 0.5^12 ≈ 0.024: only the best survives
+        let cold = SelectionContext {
+            generation: 12,
+            cases: &[],
+        };
+        let pool = selection.select_pool(&keys, 10_000, &cold, &mut rng);
+        assert!(best_share(&pool) > 0.99);
+    }
+
+    // ---- Lexicase ----
+
+    #[test]
+    fn test_lexicase_selects_specialists() {
+        // Individuals 0 and 1 are each perfect on one case; 2 is the best
+        // on average but never the best on any case.
+        let cases = vec![vec![0.0, 10.0], vec![10.0, 0.0], vec![4.0, 4.0]];
+        let context = SelectionContext {
+            generation: 0,
+            cases: &cases,
+        };
+        let keys = [10.0, 10.0, 8.0];
+        let mut rng = u_numflow::random::create_rng(42);
+
+        let pool = Selection::Lexicase.select_pool(&keys, 2000, &context, &mut rng);
+        let mut counts = [0u32; 3];
+        for &i in &pool {
+            counts[i] += 1;
+        }
+        assert_eq!(counts[2], 0, "generalist must not be selected: {counts:?}");
+        assert!(counts[0] > 800 && counts[1] > 800, "{counts:?}");
+    }
+
+    #[test]
+    fn test_lexicase_without_cases_uses_keys() {
+        let keys = [3.0, 1.0, 2.0, 1.0];
+        let mut rng = u_numflow::random::create_rng(42);
+        let mut counts = [0u32; 4];
+        for _ in 0..1000 {
+            counts[Selection::Lexicase.select_keyed(&keys, &mut rng)] += 1;
+        }
+        assert_eq!(counts[0] + counts[2], 0);
+        assert!(
+            counts[1] > 400 && counts[3] > 400,
+            "ties broken at random: {counts:?}"
+        );
+    }
+
+    // ---- Validation ----
+
+    #[test]
+    fn test_validate_parameters() {
+        assert!(Selection::LinearRank(1.5).validate().is_ok());
+        assert!(Selection::LinearRank(2.5).validate().is_err());
+        assert!(Selection::Truncation(0.0).validate().is_err());
+        assert!(Selection::Truncation(1.0).validate().is_ok());
+        assert!(Selection::Boltzmann {
+            temperature: 0.0,
+            cooling_rate: 0.9
+        }
+        .validate()
+        .is_err());
+        assert!(Selection::Boltzmann {
+            temperature: 1.0,
+            cooling_rate: 1.5
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_select_keyed_matches_select() {
+        let fitnesses = [10.0, 5.0, 1.0, 8.0];
+        let pop = make_population(&fitnesses);
+
+        for selection in [
+            Selection::Tournament(3),
+            Selection::Roulette,
+            Selection::Rank,
+            Selection::LinearRank(1.5),
+            Selection::Truncation(0.5),
+            Selection::Boltzmann {
+                temperature: 1.0,
+                cooling_rate: 0.9,
+            },
+        ] {
+            let mut rng_a = u_numflow::random::create_rng(9);
+            let mut rng_b = u_numflow::random::create_rng(9);
+            for _ in 0..100 {
+                assert_eq!(
+                    selection.select(&pop, &mut rng_a),
+                    selection.select_keyed(&fitnesses, &mut rng_b)
+                );
+            }
+        }
+    }
 }
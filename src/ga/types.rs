@@ -4,15 +4,19 @@
 //! contract between the generic GA engine and domain-specific problem
 //! implementations.
 
+use super::engine::GaState;
+use super::runner::is_significant_improvement;
 use rand::Rng;
 
 /// Marker trait for fitness values.
 ///
 /// Fitness must support comparison and be cheaply copyable.
-/// Lower fitness is considered better (minimization).
+/// Lower fitness is considered better unless
+/// [`GaConfig::direction`](super::GaConfig::direction) maximizes.
 ///
-/// Built-in implementations exist for `f64` and `f32`.
-/// For maximization problems, negate the fitness or use a wrapper type.
+/// Built-in implementations exist for `f64` and `f32`, for hierarchical
+/// objectives ([`Lexicographic`]) and for constraint violation folded into
+/// the fitness ([`FeasibilityFirst`]).
 pub trait Fitness: PartialOrd + Copy + Send + Sync + std::fmt::Debug + 'static {
     /// Returns a value representing the worst possible fitness.
     ///
@@ -21,6 +25,17 @@ pub trait Fitness: PartialOrd + Copy + Send + Sync + std::fmt::Debug + 'static {
 
     /// Converts the fitness to `f64` for logging and statistics.
     fn to_f64(self) -> f64;
+
+    /// Whether `self`, which is better than `old`, improves on it enough
+    /// to reset the stagnation counter under
+    /// [`GaConfig::convergence_threshold`](super::GaConfig::convergence_threshold).
+    ///
+    /// The default requires a relative change of
+    /// [`to_f64`](Fitness::to_f64) of at least `threshold`. Override it if
+    /// `to_f64` does not reflect every improvement.
+    fn improves_on(self, old: Self, threshold: f64) -> bool {
+        is_significant_improvement(old.to_f64(), self.to_f64(), threshold)
+    }
 }
 
 impl Fitness for f64 {
@@ -43,6 +58,147 @@ impl Fitness for f32 {
     }
 }
 
+/// Fitness of hierarchical objectives, compared component by component.
+///
+/// A later component only decides between fitnesses that are equal in all
+/// earlier ones, e.g. `Lexicographic([tardy_jobs, makespan])` prefers fewer
+/// tardy jobs whatever the makespan. The convergence threshold applies to
+/// the first component that differs.
+///
+/// [`to_f64`](Fitness::to_f64) scalarizes the components as
+/// `Σᵢ cᵢ · SCALE^(N−1−i)`, which preserves the order as long as every
+/// component after the first lies in `[0, SCALE)`. It is used for
+/// statistics, logging and the default [`GaProblem::distance`]; selection
+/// only takes its magnitudes while they agree with the component order and
+/// ranks the population otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::ga::{Fitness, Lexicographic};
+///
+/// let a = Lexicographic([1.0, 500.0]);
+/// let b = Lexicographic([2.0, 100.0]);
+/// assert!(a < b);
+/// assert_eq!(a.to_f64(), 1_000_500.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "Vec<f64>", try_from = "Vec<f64>")
+)]
+pub struct Lexicographic<const N: usize>(pub [f64; N]);
+
+impl<const N: usize> Lexicographic<N> {
+    /// Weight ratio between consecutive components in
+    /// [`to_f64`](Fitness::to_f64).
+    pub const SCALE: f64 = 1e6;
+}
+
+impl<const N: usize> From<Lexicographic<N>> for Vec<f64> {
+    fn from(fitness: Lexicographic<N>) -> Self {
+        fitness.0.to_vec()
+    }
+}
+
+impl<const N: usize> TryFrom<Vec<f64>> for Lexicographic<N> {
+    type Error = String;
+
+    fn try_from(components: Vec<f64>) -> Result<Self, String> {
+        let len = components.len();
+        components
+            .try_into()
+            .map(Self)
+            .map_err(|_| format!("expected {N} components, got {len}"))
+    }
+}
+
+impl<const N: usize> Fitness for Lexicographic<N> {
+    fn worst() -> Self {
+        Self([f64::INFINITY; N])
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0.iter().fold(0.0, |acc, &c| acc * Self::SCALE + c)
+    }
+
+    fn improves_on(self, old: Self, threshold: f64) -> bool {
+        self.0
+            .iter()
+            .zip(&old.0)
+            .find(|(a, b)| a != b)
+            .is_some_and(|(&new, &old)| is_significant_improvement(old, new, threshold))
+    }
+}
+
+/// Fitness that ranks every feasible solution before every infeasible one.
+///
+/// Compares `violation` first (0 means feasible, less is better), then
+/// `value`. Use it to fold constraint violation into the fitness itself;
+/// alternatively report it through [`GaProblem::constraint_violation`] and
+/// let [`GaConfig::constraint_handling`](super::GaConfig::constraint_handling)
+/// decide.
+///
+/// [`to_f64`](Fitness::to_f64) is `value` for feasible solutions and
+/// `value + PENALTY · violation` otherwise.
+///
+/// Maximizing would also prefer larger violations, so use this type only
+/// with the default [`Direction::Minimize`](crate::direction::Direction::Minimize).
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::ga::FeasibilityFirst;
+///
+/// let feasible = FeasibilityFirst::new(0.0, 120.0);
+/// let infeasible = FeasibilityFirst::new(0.5, 80.0);
+/// assert!(feasible < infeasible);
+/// assert!(feasible.is_feasible());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeasibilityFirst<F> {
+    /// Total constraint violation, 0 for feasible solutions.
+    pub violation: f64,
+    /// Objective value, compared between equally infeasible solutions.
+    pub value: F,
+}
+
+impl<F> FeasibilityFirst<F> {
+    /// Penalty per unit of violation in [`to_f64`](Fitness::to_f64).
+    pub const PENALTY: f64 = 1e6;
+
+    /// Creates a fitness from a violation and an objective value.
+    pub fn new(violation: f64, value: F) -> Self {
+        Self { violation, value }
+    }
+
+    /// Whether the violation is 0.
+    pub fn is_feasible(&self) -> bool {
+        self.violation <= 0.0
+    }
+}
+
+impl<F: Fitness> Fitness for FeasibilityFirst<F> {
+    fn worst() -> Self {
+        Self::new(f64::INFINITY, F::worst())
+    }
+
+    fn to_f64(self) -> f64 {
+        if self.is_feasible() {
+            self.value.to_f64()
+        } else {
+            self.value.to_f64() + Self::PENALTY * self.violation
+        }
+    }
+
+    fn improves_on(self, old: Self, threshold: f64) -> bool {
+        // Any progress while infeasible counts, as for constraint handling
+        !old.is_feasible() || self.value.improves_on(old.value, threshold)
+    }
+}
+
 /// A candidate solution in the GA population.
 ///
 /// Individuals carry their own fitness value. The GA framework calls
@@ -75,6 +231,20 @@ pub trait Individual: Clone + Send + Sync {
     ///
     /// Called by the GA framework after evaluation.
     fn set_fitness(&mut self, fitness: Self::Fitness);
+
+    /// Returns the total constraint violation (`0.0` = feasible).
+    ///
+    /// The default reports every individual as feasible. Override together
+    /// with [`set_violation`](Individual::set_violation) and
+    /// [`GaProblem::constraint_violation`] to enable constraint handling.
+    fn violation(&self) -> f64 {
+        0.0
+    }
+
+    /// Stores the constraint violation.
+    ///
+    /// Called by the GA framework after evaluation. The default discards it.
+    fn set_violation(&mut self, _violation: f64) {}
 }
 
 /// Defines a GA optimization problem.
@@ -106,14 +276,95 @@ pub trait GaProblem: Send + Sync {
     /// produce a valid (but not necessarily good) solution.
     fn create_individual<R: Rng>(&self, rng: &mut R) -> Self::Individual;
 
+    /// Returns individuals to inject into the initial population.
+    ///
+    /// Override this to seed the population with domain-specific heuristic
+    /// solutions (e.g. from a dispatching rule or a previous run). Seeds are
+    /// evaluated like any other individual; the remainder of the population
+    /// is filled with [`create_individual`](GaProblem::create_individual).
+    /// Seeds beyond the population size are ignored. The default returns no
+    /// seeds.
+    fn seed_individuals<R: Rng>(&self, _rng: &mut R) -> Vec<Self::Individual> {
+        Vec::new()
+    }
+
     /// Evaluates an individual and returns its fitness.
     ///
     /// This is typically the most expensive operation. The GA framework
     /// may call this in parallel across the population.
     ///
-    /// Lower fitness values are considered better (minimization).
+    /// Lower fitness values are considered better (minimization) unless
+    /// [`GaConfig::direction`](super::GaConfig::direction) maximizes.
     fn evaluate(&self, individual: &Self::Individual) -> <Self::Individual as Individual>::Fitness;
 
+    /// Evaluates several individuals at once, returning one fitness per
+    /// individual in the same order.
+    ///
+    /// Called instead of [`evaluate`](GaProblem::evaluate) when
+    /// [`GaConfig::batch_size`](super::GaConfig::batch_size) is set, for
+    /// evaluators (simulators, GPU kernels, remote services) that are more
+    /// efficient on batches. The default evaluates the individuals one by
+    /// one.
+    ///
+    /// # Panics
+    /// The runner panics if the result has the wrong length.
+    fn evaluate_batch(
+        &self,
+        individuals: &[Self::Individual],
+    ) -> Vec<<Self::Individual as Individual>::Fitness> {
+        individuals.iter().map(|ind| self.evaluate(ind)).collect()
+    }
+
+    /// Returns the total constraint violation of an individual.
+    ///
+    /// `0.0` means feasible; larger values mean more infeasible (typically
+    /// the sum of `max(0, gᵢ(x))` over inequality constraints). How the
+    /// violation affects selection is set by
+    /// [`GaConfig::constraint_handling`](super::GaConfig::constraint_handling).
+    ///
+    /// The default reports every individual as feasible.
+    fn constraint_violation(&self, _individual: &Self::Individual) -> f64 {
+        0.0
+    }
+
+    /// Returns the individual's error on each test case (lower is better),
+    /// for [`Selection::Lexicase`](super::Selection::Lexicase).
+    ///
+    /// Only called under lexicase selection, once per individual and
+    /// generation (and for each individual entering a steady-state
+    /// population). Every individual should report the same cases in the
+    /// same order. The default reports no cases, and lexicase selection
+    /// then falls back to fitness.
+    fn case_errors(&self, _individual: &Self::Individual) -> Vec<f64> {
+        Vec::new()
+    }
+
+    /// Returns a hash identifying the individual's genotype, enabling the
+    /// fitness cache ([`GaConfig::cache_capacity`](super::GaConfig::cache_capacity)).
+    ///
+    /// Individuals with equal hashes are assumed to have equal fitness and
+    /// violation, so the hash should cover everything `evaluate` reads.
+    /// The default returns `None`, which bypasses the cache.
+    fn individual_hash(&self, _individual: &Self::Individual) -> Option<u64> {
+        None
+    }
+
+    /// Returns the distance between two individuals.
+    ///
+    /// Used by [`GaConfig::niching`](super::GaConfig::niching), duplicate
+    /// elimination, the hall of fame and
+    /// [`GenerationStats::diversity`](super::GenerationStats::diversity).
+    /// It should be symmetric, non-negative and 0 only for identical
+    /// genotypes, e.g. the Hamming distance of bit strings or the number of
+    /// positions at which two permutations differ.
+    ///
+    /// The default is the absolute fitness difference, which needs no
+    /// knowledge of the encoding but treats individuals of equal fitness
+    /// as identical.
+    fn distance(&self, a: &Self::Individual, b: &Self::Individual) -> f64 {
+        (a.fitness().to_f64() - b.fitness().to_f64()).abs()
+    }
+
     /// Produces one or two offspring by recombining two parents.
     ///
     /// Returns a `Vec` of 1 or 2 children. The framework handles sizing.
@@ -133,14 +384,134 @@ pub trait GaProblem: Send + Sync {
     /// The default implementation is a no-op.
     fn mutate<R: Rng>(&self, _individual: &mut Self::Individual, _rng: &mut R) {}
 
+    /// Number of crossover operators available to
+    /// [`crossover_with`](GaProblem::crossover_with).
+    ///
+    /// With more than one, [`GaConfig::operator_selection`](super::GaConfig::operator_selection)
+    /// decides which is applied to each pair of parents. The default is 1.
+    fn crossover_operators(&self) -> usize {
+        1
+    }
+
+    /// Recombines two parents with crossover operator `operator`
+    /// (`0..crossover_operators()`).
+    ///
+    /// The default ignores `operator` and calls
+    /// [`crossover`](GaProblem::crossover).
+    fn crossover_with<R: Rng>(
+        &self,
+        _operator: usize,
+        parent1: &Self::Individual,
+        parent2: &Self::Individual,
+        rng: &mut R,
+    ) -> Vec<Self::Individual> {
+        self.crossover(parent1, parent2, rng)
+    }
+
+    /// Number of mutation operators available to
+    /// [`mutate_with`](GaProblem::mutate_with). The default is 1.
+    fn mutation_operators(&self) -> usize {
+        1
+    }
+
+    /// Mutates an individual with mutation operator `operator`
+    /// (`0..mutation_operators()`).
+    ///
+    /// `strength` scales the step size. It is 1.0 unless
+    /// [`GaConfig::one_fifth_rule`](super::GaConfig::one_fifth_rule) adapts
+    /// it. The default ignores both and calls [`mutate`](GaProblem::mutate).
+    fn mutate_with<R: Rng>(
+        &self,
+        _operator: usize,
+        individual: &mut Self::Individual,
+        _strength: f64,
+        rng: &mut R,
+    ) {
+        self.mutate(individual, rng)
+    }
+
+    /// Improves an evaluated individual in place by local search.
+    ///
+    /// Called on a fraction of the offspring when
+    /// [`GaConfig::local_search`](super::GaConfig::local_search) is set.
+    /// Return `true` if the individual was changed; the runner then
+    /// re-evaluates it and keeps the result only if it is not worse. With
+    /// the `parallel` feature, calls may run concurrently. The default
+    /// changes nothing.
+    fn improve(&self, _individual: &mut Self::Individual) -> bool {
+        false
+    }
+
     /// Called at the end of each generation with the current best fitness.
     ///
     /// Useful for logging, adaptive parameter control, or external
-    /// communication. The default implementation is a no-op.
+    /// communication. For structured progress events that work the same
+    /// across all algorithms, use [`GaRunner::run_observed`](super::GaRunner::run_observed)
+    /// with an [`Observer`](crate::observer::Observer) instead. The default
+    /// implementation is a no-op.
     fn on_generation(
         &self,
         _generation: usize,
         _best_fitness: <Self::Individual as Individual>::Fitness,
     ) {
     }
+
+    /// Called every [`GaConfig::checkpoint_interval`](super::GaConfig::checkpoint_interval)
+    /// generations with a snapshot of the run.
+    ///
+    /// Persist the snapshot (e.g. with the `serde` feature) and pass it to
+    /// [`GaRunner::resume_from`](super::GaRunner::resume_from) to continue
+    /// an interrupted run. The default implementation is a no-op.
+    fn on_checkpoint(&self, _state: &GaState<Self::Individual>) {}
+}
+
+/// Defines a multi-objective optimization problem.
+///
+/// The multi-objective counterpart of [`GaProblem`]: instead of a scalar
+/// fitness, [`evaluate`](MultiObjectiveProblem::evaluate) returns one value
+/// per objective. All objectives are **minimized**.
+///
+/// Used by [`Nsga2Runner`](super::Nsga2Runner).
+///
+/// # Thread Safety
+///
+/// `MultiObjectiveProblem` must be `Send + Sync` because the runner may
+/// evaluate solutions in parallel using rayon.
+pub trait MultiObjectiveProblem: Send + Sync {
+    /// The solution representation type.
+    type Solution: Clone + Send + Sync;
+
+    /// Number of objectives returned by [`evaluate`](Self::evaluate).
+    fn objective_count(&self) -> usize;
+
+    /// Creates a random solution.
+    fn create_solution<R: Rng>(&self, rng: &mut R) -> Self::Solution;
+
+    /// Evaluates a solution and returns its objective vector.
+    ///
+    /// The returned vector must have length
+    /// [`objective_count`](Self::objective_count). Lower values are better.
+    fn evaluate(&self, solution: &Self::Solution) -> Vec<f64>;
+
+    /// Produces one or two offspring by recombining two parents.
+    ///
+    /// The default implementation clones parent1 (no crossover).
+    fn crossover<R: Rng>(
+        &self,
+        parent1: &Self::Solution,
+        _parent2: &Self::Solution,
+        _rng: &mut R,
+    ) -> Vec<Self::Solution> {
+        vec![parent1.clone()]
+    }
+
+    /// Mutates a solution in place.
+    ///
+    /// The default implementation is a no-op.
+    fn mutate<R: Rng>(&self, _solution: &mut Self::Solution, _rng: &mut R) {}
+
+    /// Called at the end of each generation with the size of the first front.
+    ///
+    /// The default implementation is a no-op.
+    fn on_generation(&self, _generation: usize, _front_size: usize) {}
 }
@@ -0,0 +1,215 @@
+//! Bounded collection of the best distinct solutions seen during a run.
+//!
+//! Shared by the GA, BRKGA, SA and ALNS runners, which offer every solution
+//! they evaluate and return the entries with their result. A capacity of
+//! zero disables the hall of fame.
+//!
+//! Entries are kept best first and are pairwise more than `min_distance`
+//! apart. A candidate within `min_distance` of some entries is admitted
+//! only if it is better than all of them, and then takes their place; with
+//! `min_distance = 0` this only rejects duplicates.
+
+use crate::direction::Direction;
+
+/// The best solutions seen so far, best first.
+#[derive(Debug, Clone)]
+pub(crate) struct HallOfFame<T> {
+    capacity: usize,
+    min_distance: f64,
+    entries: Vec<T>,
+}
+
+impl<T> HallOfFame<T> {
+    /// Creates a hall of fame holding at most `capacity` entries
+    /// (0 = disabled).
+    pub(crate) fn new(capacity: usize, min_distance: f64) -> Self {
+        Self {
+            capacity,
+            min_distance,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Restores the entries of a checkpoint, which must already be sorted
+    /// best first and far enough apart.
+    pub(crate) fn with_entries(mut self, mut entries: Vec<T>) -> Self {
+        entries.truncate(self.capacity);
+        self.entries = entries;
+        self
+    }
+
+    /// The entries, best first.
+    pub(crate) fn entries(&self) -> &[T] {
+        &self.entries
+    }
+
+    /// Consumes the hall of fame and returns its entries, best first.
+    pub(crate) fn into_entries(self) -> Vec<T> {
+        self.entries
+    }
+
+    /// Offers a candidate and returns whether it was admitted.
+    ///
+    /// `beats(entry)` tells whether the candidate is strictly better than
+    /// `entry`, `distance(entry)` how far the candidate is from it, and
+    /// `make()` builds the entry, so the candidate is only cloned when it
+    /// is admitted. `distance` is only called for candidates good enough
+    /// to enter.
+    pub(crate) fn offer(
+        &mut self,
+        beats: impl Fn(&T) -> bool,
+        distance: impl Fn(&T) -> f64,
+        make: impl FnOnce() -> T,
+    ) -> bool {
+        if self.capacity == 0 {
+            return false;
+        }
+        if self.entries.len() == self.capacity && !self.entries.last().is_some_and(&beats) {
+            return false;
+        }
+
+        let mut close = Vec::new();
+        for (i, entry) in self.entries.iter().enumerate() {
+            if distance(entry) <= self.min_distance {
+                if !beats(entry) {
+                    return false;
+                }
+                close.push(i);
+            }
+        }
+        for &i in close.iter().rev() {
+            self.entries.remove(i);
+        }
+
+        let position = self
+            .entries
+            .iter()
+            .position(&beats)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(position, make());
+        self.entries.truncate(self.capacity);
+        true
+    }
+}
+
+impl<S: Clone> HallOfFame<(S, f64)> {
+    /// Offers `solution` with its internal, lower-is-better `cost`.
+    ///
+    /// Where `distance` returns `None`, the distance is the difference
+    /// between the stored costs, so no solution is evaluated again.
+    pub(crate) fn offer_solution(
+        &mut self,
+        solution: &S,
+        cost: f64,
+        distance: impl Fn(&S, &S) -> Option<f64>,
+    ) -> bool {
+        self.offer(
+            |&(_, entry_cost)| cost < entry_cost,
+            |(entry, entry_cost)| {
+                distance(solution, entry).unwrap_or_else(|| (cost - entry_cost).abs())
+            },
+            || (solution.clone(), cost),
+        )
+    }
+
+    /// Consumes the hall of fame and returns its solutions with their
+    /// costs in the configured direction, best first.
+    pub(crate) fn into_values(self, direction: Direction) -> Vec<(S, f64)> {
+        self.entries
+            .into_iter()
+            .map(|(solution, cost)| (solution, direction.value(cost)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Offers a `(cost, position)` pair to a minimizing hall of fame whose
+    /// distance is the difference in position.
+    fn offer(hall_of_fame: &mut HallOfFame<(f64, f64)>, cost: f64, position: f64) -> bool {
+        hall_of_fame.offer(
+            |&(c, _)| cost < c,
+            |&(_, p)| (position - p).abs(),
+            || (cost, position),
+        )
+    }
+
+    fn costs(hall_of_fame: &HallOfFame<(f64, f64)>) -> Vec<f64> {
+        hall_of_fame.entries().iter().map(|&(c, _)| c).collect()
+    }
+
+    #[test]
+    fn test_keeps_best_first() {
+        let mut hall_of_fame = HallOfFame::new(3, 0.0);
+        for (i, cost) in [5.0, 3.0, 8.0, 1.0, 4.0].into_iter().enumerate() {
+            offer(&mut hall_of_fame, cost, i as f64);
+        }
+        assert_eq!(costs(&hall_of_fame), [1.0, 3.0, 4.0]);
+        assert!(!offer(&mut hall_of_fame, 6.0, 10.0));
+    }
+
+    #[test]
+    fn test_rejects_duplicates() {
+        let mut hall_of_fame = HallOfFame::new(3, 0.0);
+        assert!(offer(&mut hall_of_fame, 2.0, 0.0));
+        assert!(!offer(&mut hall_of_fame, 2.0, 0.0));
+        assert!(offer(&mut hall_of_fame, 2.0, 1.0));
+        assert_eq!(hall_of_fame.entries(), [(2.0, 0.0), (2.0, 1.0)]);
+    }
+
+    #[test]
+    fn test_min_distance() {
+        let mut hall_of_fame = HallOfFame::new(3, 1.5);
+        assert!(offer(&mut hall_of_fame, 1.0, 0.0));
+        assert!(
+            !offer(&mut hall_of_fame, 2.0, 1.0),
+            "too close to a better entry"
+        );
+        assert!(offer(&mut hall_of_fame, 3.0, 2.0));
+        assert_eq!(costs(&hall_of_fame), [1.0, 3.0]);
+
+        // A better candidate takes the place of the entries it is close to
+        assert!(offer(&mut hall_of_fame, 0.0, 1.0));
+        assert_eq!(hall_of_fame.entries(), [(0.0, 1.0)]);
+    }
+
+    #[test]
+    fn test_full_hall_of_fame_drops_worst() {
+        let mut hall_of_fame = HallOfFame::new(2, 0.5);
+        offer(&mut hall_of_fame, 1.0, 0.0);
+        offer(&mut hall_of_fame, 2.0, 1.0);
+        assert!(offer(&mut hall_of_fame, 1.5, 2.0));
+        assert_eq!(hall_of_fame.entries(), [(1.0, 0.0), (1.5, 2.0)]);
+    }
+
+    #[test]
+    fn test_solutions_in_configured_direction() {
+        // Internal costs of a maximizing run are negated values
+        let mut hall_of_fame = HallOfFame::new(2, 0.0);
+        for value in [3.0, 7.0, 5.0] {
+            hall_of_fame.offer_solution(&value, -value, |a, b| Some((a - b).abs()));
+        }
+        assert_eq!(
+            hall_of_fame.into_values(Direction::Maximize),
+            [(7.0, 7.0), (5.0, 5.0)]
+        );
+    }
+
+    #[test]
+    fn test_solutions_default_to_cost_difference() {
+        let mut hall_of_fame = HallOfFame::new(3, 1.0);
+        for (solution, cost) in [('a', 1.0), ('b', 1.5), ('c', 3.0)] {
+            hall_of_fame.offer_solution(&solution, cost, |_, _| None);
+        }
+        assert_eq!(hall_of_fame.entries(), [('a', 1.0), ('c', 3.0)]);
+    }
+
+    #[test]
+    fn test_disabled() {
+        let mut hall_of_fame = HallOfFame::new(0, 0.0);
+        assert!(!offer(&mut hall_of_fame, 1.0, 0.0));
+        assert!(hall_of_fame.into_entries().is_empty());
+    }
+}
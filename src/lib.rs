@@ -18,6 +18,16 @@
 //!   short-term memory (tabu list) to escape local optima.
 //! - **Variable Neighborhood Search (VNS)**: Systematic neighborhood
 //!   switching for escaping local optima via diversified perturbation.
+//! - **Differential Evolution (DE)**: Continuous optimization over bounded
+//!   real vectors, with JADE/SHADE-style strategies and parameter adaptation.
+//! - **Particle Swarm Optimization (PSO)**: Continuous optimization with
+//!   global- or ring-topology swarms; random-key PSO reuses BRKGA decoders.
+//!
+//! Every runner can report progress to an [`observer::Observer`] through
+//! its `run_observed` entry point.
+//!
+//! All algorithms minimize by default. Set the configuration's
+//! [`direction::Direction`] to maximize without negating objective values.
 //!
 //! # Architecture
 //!
@@ -28,9 +38,17 @@
 
 pub mod alns;
 pub mod brkga;
+mod cache;
+pub mod continuous;
 pub mod cp;
+pub mod de;
+pub mod direction;
 pub mod dispatching;
 pub mod ga;
+mod hall_of_fame;
+pub mod observer;
+pub mod pso;
+pub mod rng;
 pub mod sa;
 pub mod tabu;
 pub mod vns;
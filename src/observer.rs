@@ -0,0 +1,159 @@
+//! Progress reporting shared by all runners.
+//!
+//! Every runner has a `run_observed` entry point that reports structured
+//! [`Event`]s to an [`Observer`]: new best solutions, finished generations
+//! or iterations, operator choices, temperature changes and termination.
+//! One observer implementation can therefore stream progress from any
+//! algorithm to logs or dashboards.
+//!
+//! Closures taking `&Event` implement [`Observer`]:
+//!
+//! ```ignore
+//! let mut log = |event: &Event<'_>| {
+//!     if let Event::NewBest { iteration, cost } = event {
+//!         println!("iteration {iteration}: new best {cost}");
+//!     }
+//! };
+//! SaRunner::run_observed(&problem, &config, None, &mut log);
+//! ```
+//!
+//! Events are emitted on the thread that called the runner; iteration
+//! numbers count completed iterations (or generations), starting at 1.
+
+use crate::ga::GenerationStats;
+
+/// Why a run stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminationReason {
+    /// The iteration or generation limit was reached.
+    MaxIterations,
+    /// No improvement within the stagnation limit.
+    Stagnated,
+    /// The cancellation flag was set.
+    Cancelled,
+    /// The wall-clock time limit elapsed.
+    TimedOut,
+    /// The SA temperature fell to its minimum.
+    MinTemperature,
+    /// The tabu search neighborhood was empty.
+    NoMoves,
+}
+
+/// What an [`Event::OperatorSelected`] refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperatorKind {
+    /// ALNS destroy operator.
+    Destroy,
+    /// ALNS repair operator.
+    Repair,
+    /// VNS shaking neighborhood.
+    Neighborhood,
+    /// Tabu search move.
+    Move,
+}
+
+/// A progress event reported by a runner.
+#[derive(Debug, Clone, Copy)]
+pub enum Event<'a> {
+    /// A solution better than every previous one was found.
+    NewBest {
+        /// Iteration (or generation) that produced it.
+        iteration: usize,
+        /// Its cost (fitness for the GA).
+        cost: f64,
+    },
+
+    /// A GA, BRKGA or DE generation, or a PSO iteration, finished.
+    GenerationFinished {
+        /// Population statistics of the new generation.
+        stats: &'a GenerationStats,
+    },
+
+    /// An iteration of a single-solution method (SA, ALNS, Tabu, VNS)
+    /// finished.
+    IterationFinished {
+        /// Number of completed iterations.
+        iteration: usize,
+        /// Cost of the current solution.
+        current_cost: f64,
+        /// Cost of the best solution so far.
+        best_cost: f64,
+    },
+
+    /// An operator, neighborhood or move was chosen.
+    OperatorSelected {
+        /// Iteration in which it is applied.
+        iteration: usize,
+        /// What was selected.
+        kind: OperatorKind,
+        /// Index of the operator or neighborhood, or of the move within
+        /// the generated neighborhood.
+        index: usize,
+        /// Operator name or tabu move key, when there is one.
+        name: Option<&'a str>,
+    },
+
+    /// The annealing temperature changed (SA, ALNS).
+    TemperatureChanged {
+        /// Number of completed iterations.
+        iteration: usize,
+        /// The new temperature.
+        temperature: f64,
+    },
+
+    /// The run ended. Always the last event.
+    Terminated {
+        /// Total iterations (or generations) executed.
+        iterations: usize,
+        /// Cost of the best solution found.
+        best_cost: f64,
+        /// Why the run stopped.
+        reason: TerminationReason,
+    },
+}
+
+/// Receives [`Event`]s from a runner.
+pub trait Observer: Send {
+    /// Handles one event.
+    fn on_event(&mut self, event: &Event<'_>);
+}
+
+impl<F: FnMut(&Event<'_>) + Send> Observer for F {
+    fn on_event(&mut self, event: &Event<'_>) {
+        self(event)
+    }
+}
+
+/// Observer that ignores every event; used by the unobserved entry points.
+pub(crate) struct NoopObserver;
+
+impl Observer for NoopObserver {
+    fn on_event(&mut self, _event: &Event<'_>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closure_observer() {
+        let mut count = 0;
+        {
+            let mut observer = |event: &Event<'_>| {
+                if let Event::NewBest { .. } = event {
+                    count += 1;
+                }
+            };
+            let observer: &mut dyn Observer = &mut observer;
+            observer.on_event(&Event::NewBest {
+                iteration: 1,
+                cost: 3.0,
+            });
+            observer.on_event(&Event::TemperatureChanged {
+                iteration: 1,
+                temperature: 10.0,
+            });
+        }
+        assert_eq!(count, 1);
+    }
+}
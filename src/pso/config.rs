@@ -0,0 +1,354 @@
+//! PSO configuration, topologies and inertia weight schedules.
+
+use crate::continuous::BoundHandling;
+use crate::direction::Direction;
+
+/// Which particles share their best positions.
+///
+/// # References
+///
+/// Kennedy & Mendes (2002), "Population Structure and Particle Swarm
+/// Performance"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Topology {
+    /// Global best (gbest): every particle follows the best position found
+    /// by the whole swarm. Converges fast.
+    #[default]
+    Global,
+
+    /// Local best (lbest) ring: particle `i` follows the best position
+    /// found by particles `i - neighbors ..= i + neighbors` (wrapping
+    /// around). Spreads information slowly, which helps on multimodal
+    /// problems.
+    Ring {
+        /// Neighbors on each side. 1 is the classic lbest ring.
+        neighbors: usize,
+    },
+}
+
+/// Inertia weight `w` scaling the previous velocity.
+///
+/// # References
+///
+/// - Constant, Linear: Shi & Eberhart (1998)
+/// - Random: Eberhart & Shi (2001), "Tracking and Optimizing Dynamic
+///   Systems with Particle Swarms"
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InertiaWeight {
+    /// The same weight at every iteration. 0.729 with `c1 = c2 = 1.49445`
+    /// matches the constriction factor.
+    Constant {
+        /// Inertia weight, typically in [0.4, 0.9].
+        weight: f64,
+    },
+
+    /// Linearly decreasing from `start` at the first iteration to `end` at
+    /// `max_iterations`: exploration first, exploitation later. Typically
+    /// 0.9 to 0.4.
+    Linear {
+        /// Weight at the first iteration.
+        start: f64,
+        /// Weight at the last iteration.
+        end: f64,
+    },
+
+    /// A weight drawn uniformly from [0.5, 1) at each iteration.
+    Random,
+}
+
+impl Default for InertiaWeight {
+    fn default() -> Self {
+        InertiaWeight::Constant { weight: 0.729 }
+    }
+}
+
+/// Configuration for Particle Swarm Optimization.
+///
+/// Each iteration, every velocity component is updated as
+///
+/// `v ← w·v + c1·r1·(p − x) + c2·r2·(l − x)`
+///
+/// where `p` is the particle's best position, `l` its neighborhood's best
+/// position and `r1`, `r2` are uniform in [0, 1). With `constriction`, the
+/// update is `v ← χ·(v + c1·r1·(p − x) + c2·r2·(l − x))` instead.
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::pso::{InertiaWeight, PsoConfig, Topology};
+///
+/// let config = PsoConfig::default()
+///     .with_swarm_size(40)
+///     .with_max_iterations(500)
+///     .with_topology(Topology::Ring { neighbors: 1 })
+///     .with_inertia(InertiaWeight::Linear { start: 0.9, end: 0.4 });
+/// assert!(config.validate().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PsoConfig {
+    /// Number of particles. Typically 20–50.
+    pub swarm_size: usize,
+
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+
+    /// Neighborhood topology.
+    pub topology: Topology,
+
+    /// Inertia weight schedule. Ignored with `constriction`.
+    pub inertia: InertiaWeight,
+
+    /// Acceleration toward the particle's own best position (`c1`).
+    pub cognitive: f64,
+
+    /// Acceleration toward the neighborhood's best position (`c2`).
+    pub social: f64,
+
+    /// Use the Clerc–Kennedy constriction factor
+    /// `χ = 2 / |2 − φ − √(φ² − 4φ)|` with `φ = c1 + c2`, which must
+    /// exceed 4 (typically `c1 = c2 = 2.05`, giving `χ ≈ 0.7298`).
+    pub constriction: bool,
+
+    /// Largest velocity component, as a fraction of the variable's range
+    /// (`None` disables clamping).
+    pub velocity_clamp: Option<f64>,
+
+    /// Repair of particles that leave the bounds, with the position before
+    /// the move as reference. Repaired components reverse their velocity
+    /// under [`BoundHandling::Reflect`] and stop otherwise. Defaults to
+    /// [`BoundHandling::Clamp`] (absorbing walls).
+    pub bound_handling: BoundHandling,
+
+    /// Whether to evaluate particles in parallel using rayon.
+    pub parallel: bool,
+
+    /// Whether [`PsoProblem::cost`](super::PsoProblem::cost) is minimized
+    /// (the default) or maximized.
+    pub direction: Direction,
+
+    /// Random seed for reproducibility.
+    pub seed: Option<u64>,
+
+    /// Wall-clock time limit in milliseconds, checked between iterations.
+    ///
+    /// `None` disables time-based termination (the default).
+    pub time_limit_ms: Option<u64>,
+}
+
+impl Default for PsoConfig {
+    fn default() -> Self {
+        Self {
+            swarm_size: 30,
+            max_iterations: 1000,
+            topology: Topology::default(),
+            inertia: InertiaWeight::default(),
+            cognitive: 1.49445,
+            social: 1.49445,
+            constriction: false,
+            velocity_clamp: Some(0.5),
+            bound_handling: BoundHandling::Clamp,
+            parallel: true,
+            direction: Direction::Minimize,
+            seed: None,
+            time_limit_ms: None,
+        }
+    }
+}
+
+impl PsoConfig {
+    /// Sets the number of particles.
+    pub fn with_swarm_size(mut self, size: usize) -> Self {
+        self.swarm_size = size;
+        self
+    }
+
+    /// Sets the maximum number of iterations.
+    pub fn with_max_iterations(mut self, n: usize) -> Self {
+        self.max_iterations = n;
+        self
+    }
+
+    /// Sets the neighborhood topology.
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Sets the inertia weight schedule.
+    pub fn with_inertia(mut self, inertia: InertiaWeight) -> Self {
+        self.inertia = inertia;
+        self
+    }
+
+    /// Sets the cognitive (`c1`) and social (`c2`) acceleration
+    /// coefficients.
+    pub fn with_acceleration(mut self, cognitive: f64, social: f64) -> Self {
+        self.cognitive = cognitive;
+        self.social = social;
+        self
+    }
+
+    /// Uses the constriction factor with `c1 = c2 = 2.05` instead of an
+    /// inertia weight.
+    pub fn with_constriction(mut self) -> Self {
+        self.constriction = true;
+        self.cognitive = 2.05;
+        self.social = 2.05;
+        self
+    }
+
+    /// Sets the largest velocity component as a fraction of each
+    /// variable's range.
+    pub fn with_velocity_clamp(mut self, fraction: f64) -> Self {
+        self.velocity_clamp = Some(fraction);
+        self
+    }
+
+    /// Disables velocity clamping.
+    pub fn without_velocity_clamp(mut self) -> Self {
+        self.velocity_clamp = None;
+        self
+    }
+
+    /// Sets how particles that leave the bounds are repaired.
+    pub fn with_bound_handling(mut self, handling: BoundHandling) -> Self {
+        self.bound_handling = handling;
+        self
+    }
+
+    /// Enables or disables parallel evaluation.
+    pub fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Sets whether costs are minimized or maximized.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the random seed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the wall-clock time limit in milliseconds.
+    pub fn with_time_limit_ms(mut self, ms: u64) -> Self {
+        self.time_limit_ms = Some(ms);
+        self
+    }
+
+    /// The constriction factor `χ` for the configured coefficients.
+    pub fn constriction_factor(&self) -> f64 {
+        let phi = self.cognitive + self.social;
+        2.0 / (2.0 - phi - (phi * phi - 4.0 * phi).sqrt()).abs()
+    }
+
+    /// Validates the configuration.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.swarm_size < 2 {
+            return Err("swarm_size must be at least 2".into());
+        }
+        if self.max_iterations == 0 {
+            return Err("max_iterations must be at least 1".into());
+        }
+        if let Topology::Ring { neighbors: 0 } = self.topology {
+            return Err("ring neighbors must be at least 1".into());
+        }
+        for (name, c) in [("cognitive", self.cognitive), ("social", self.social)] {
+            if c.is_nan() || c < 0.0 {
+                return Err(format!("{name} must be non-negative, got {c}"));
+            }
+        }
+        if self.constriction {
+            let phi = self.cognitive + self.social;
+            if phi <= 4.0 {
+                return Err(format!(
+                    "constriction requires cognitive + social > 4, got {phi}"
+                ));
+            }
+        } else {
+            match self.inertia {
+                InertiaWeight::Constant { weight } => {
+                    if weight.is_nan() || weight < 0.0 {
+                        return Err(format!("inertia weight must be non-negative, got {weight}"));
+                    }
+                }
+                InertiaWeight::Linear { start, end } => {
+                    if start.is_nan() || start < 0.0 || end.is_nan() || end < 0.0 {
+                        return Err("linear inertia weights must be non-negative".into());
+                    }
+                }
+                InertiaWeight::Random => {}
+            }
+        }
+        if let Some(fraction) = self.velocity_clamp {
+            if fraction.is_nan() || fraction <= 0.0 {
+                return Err(format!("velocity_clamp must be positive, got {fraction}"));
+            }
+        }
+        if self.time_limit_ms == Some(0) {
+            return Err("time_limit_ms must be positive or None".into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = PsoConfig::default();
+        assert_eq!(config.swarm_size, 30);
+        assert_eq!(config.topology, Topology::Global);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_constriction_factor() {
+        let config = PsoConfig::default().with_constriction();
+        assert!((config.constriction_factor() - 0.729_843_788).abs() < 1e-6);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_constriction_needs_phi_above_four() {
+        let config = PsoConfig::default()
+            .with_constriction()
+            .with_acceleration(2.0, 2.0);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_bad_parameters() {
+        assert!(PsoConfig::default().with_swarm_size(1).validate().is_err());
+        assert!(PsoConfig::default()
+            .with_max_iterations(0)
+            .validate()
+            .is_err());
+        assert!(PsoConfig::default()
+            .with_topology(Topology::Ring { neighbors: 0 })
+            .validate()
+            .is_err());
+        assert!(PsoConfig::default()
+            .with_acceleration(-1.0, 1.0)
+            .validate()
+            .is_err());
+        assert!(PsoConfig::default()
+            .with_inertia(InertiaWeight::Constant { weight: f64::NAN })
+            .validate()
+            .is_err());
+        assert!(PsoConfig::default()
+            .with_velocity_clamp(0.0)
+            .validate()
+            .is_err());
+        assert!(PsoConfig::default()
+            .with_time_limit_ms(0)
+            .validate()
+            .is_err());
+    }
+}
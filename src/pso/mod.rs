@@ -0,0 +1,28 @@
+//! Particle Swarm Optimization (PSO).
+//!
+//! A population-based method for continuous optimization over a box of
+//! real-valued variables. Each particle moves with a velocity pulled
+//! toward the best position it has visited and the best position found
+//! by its neighborhood (the whole swarm, or its neighbors on a ring).
+//!
+//! The user implements only [`PsoProblem`]. Discrete problems can be
+//! solved through a random-key encoding by wrapping a
+//! [`BrkgaDecoder`](crate::brkga::BrkgaDecoder) in a [`RandomKeyProblem`].
+//!
+//! # References
+//!
+//! - Kennedy & Eberhart (1995), "Particle Swarm Optimization"
+//! - Shi & Eberhart (1998), "A Modified Particle Swarm Optimizer"
+//! - Clerc & Kennedy (2002), "The Particle Swarm — Explosion, Stability,
+//!   and Convergence in a Multidimensional Complex Space"
+//! - Tasgetiren, Liang, Sevkli & Gencyilmaz (2007), "A particle swarm
+//!   optimization algorithm for makespan and total flowtime minimization
+//!   in the permutation flowshop sequencing problem"
+
+mod config;
+mod runner;
+mod types;
+
+pub use config::{InertiaWeight, PsoConfig, Topology};
+pub use runner::{PsoResult, PsoRunner};
+pub use types::{PsoProblem, RandomKeyProblem};
@@ -0,0 +1,567 @@
+//! Particle Swarm Optimization loop.
+
+use super::config::{InertiaWeight, PsoConfig, Topology};
+use super::types::PsoProblem;
+use crate::continuous::{evaluate_vectors, BoundHandling};
+use crate::ga::GenerationStats;
+use crate::observer::{Event, NoopObserver, Observer, TerminationReason};
+use rand::Rng;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+use u_numflow::random::create_rng;
+
+/// Result of a Particle Swarm Optimization run.
+#[derive(Debug, Clone)]
+pub struct PsoResult {
+    /// The best position found.
+    pub best: Vec<f64>,
+
+    /// Cost of the best position.
+    pub best_cost: f64,
+
+    /// Number of iterations executed.
+    pub iterations: usize,
+
+    /// Number of cost evaluations, including the initial swarm.
+    pub evaluations: usize,
+
+    /// Whether cancelled externally.
+    pub cancelled: bool,
+
+    /// Whether the run stopped because `time_limit_ms` elapsed.
+    pub timed_out: bool,
+
+    /// Best cost at the start and at the end of each iteration.
+    pub cost_history: Vec<f64>,
+}
+
+/// Executes the Particle Swarm Optimization algorithm.
+pub struct PsoRunner;
+
+impl PsoRunner {
+    /// Runs PSO optimization.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration or the problem's bounds are
+    /// invalid.
+    pub fn run<P: PsoProblem>(problem: &P, config: &PsoConfig) -> Result<PsoResult, String> {
+        Self::run_with_cancel(problem, config, None)
+    }
+
+    /// Runs PSO with an optional cancellation token.
+    ///
+    /// If `cancel` is `Some` and the flag is set to `true`, the run stops
+    /// at the end of the current iteration and returns the best position
+    /// found so far.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration or the problem's bounds are
+    /// invalid.
+    pub fn run_with_cancel<P: PsoProblem>(
+        problem: &P,
+        config: &PsoConfig,
+        cancel: Option<Arc<AtomicBool>>,
+    ) -> Result<PsoResult, String> {
+        Self::run_observed(problem, config, cancel, &mut NoopObserver)
+    }
+
+    /// Runs PSO, reporting progress events to `observer`.
+    ///
+    /// Emits [`Event::NewBest`], [`Event::GenerationFinished`] after every
+    /// iteration (statistics over the particles' current costs) and
+    /// [`Event::Terminated`].
+    ///
+    /// # Errors
+    /// Returns an error if the configuration or the problem's bounds are
+    /// invalid.
+    pub fn run_observed<P: PsoProblem>(
+        problem: &P,
+        config: &PsoConfig,
+        cancel: Option<Arc<AtomicBool>>,
+        observer: &mut dyn Observer,
+    ) -> Result<PsoResult, String> {
+        config.validate()?;
+        let bounds = problem.bounds();
+        bounds.validate()?;
+
+        let mut rng = match config.seed {
+            Some(seed) => create_rng(seed),
+            None => create_rng(rand::random()),
+        };
+        let direction = config.direction;
+        let cost = |x: &[f64]| direction.cost(problem.cost(x));
+        let n = config.swarm_size;
+        let lower = bounds.lower();
+        let upper = bounds.upper();
+        let max_velocity: Vec<f64> = match config.velocity_clamp {
+            Some(fraction) => lower
+                .iter()
+                .zip(upper)
+                .map(|(lo, hi)| fraction * (hi - lo))
+                .collect(),
+            None => vec![f64::INFINITY; bounds.len()],
+        };
+        let constriction = config.constriction_factor();
+
+        // Initialize the swarm; velocities point halfway to random positions
+        let mut positions: Vec<Vec<f64>> = (0..n).map(|_| bounds.sample(&mut rng)).collect();
+        let mut velocities: Vec<Vec<f64>> = positions
+            .iter()
+            .map(|x| {
+                let target = bounds.sample(&mut rng);
+                x.iter().zip(target).map(|(a, b)| (b - a) / 2.0).collect()
+            })
+            .collect();
+        let costs = evaluate_vectors(&positions, config.parallel, cost);
+        let mut evaluations = n;
+        let mut best_positions = positions.clone();
+        let mut best_costs = costs;
+        let mut best = best_index(&best_costs);
+
+        let mut cost_history = Vec::with_capacity(config.max_iterations + 1);
+        cost_history.push(direction.value(best_costs[best]));
+
+        let mut iteration = 0;
+        let mut cancelled = false;
+        #[allow(unused_mut)]
+        let mut timed_out = false;
+        #[cfg(not(target_arch = "wasm32"))]
+        let start_time = Instant::now();
+
+        while iteration < config.max_iterations {
+            if let Some(ref flag) = cancel {
+                if flag.load(Ordering::Relaxed) {
+                    cancelled = true;
+                    break;
+                }
+            }
+
+            // Time limit (not available on WASM — no std::time::Instant)
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(limit_ms) = config.time_limit_ms {
+                if start_time.elapsed().as_millis() as u64 >= limit_ms {
+                    timed_out = true;
+                    break;
+                }
+            }
+
+            // Move every particle toward its own and its leader's best
+            let weight = inertia_weight(config.inertia, iteration, config.max_iterations, &mut rng);
+            let leaders = leaders(&best_costs, config.topology);
+            for (i, (x, v)) in positions.iter_mut().zip(&mut velocities).enumerate() {
+                let own = &best_positions[i];
+                let leader = &best_positions[leaders[i]];
+                for (j, (xj, vj)) in x.iter_mut().zip(v.iter_mut()).enumerate() {
+                    let pull = config.cognitive * rng.random::<f64>() * (own[j] - *xj)
+                        + config.social * rng.random::<f64>() * (leader[j] - *xj);
+                    let velocity = if config.constriction {
+                        constriction * (*vj + pull)
+                    } else {
+                        weight * *vj + pull
+                    };
+                    *vj = velocity.clamp(-max_velocity[j], max_velocity[j]);
+                    let previous = *xj;
+                    *xj += *vj;
+                    confine(
+                        xj,
+                        vj,
+                        previous,
+                        (lower[j], upper[j]),
+                        config.bound_handling,
+                        &mut rng,
+                    );
+                }
+            }
+
+            let costs = evaluate_vectors(&positions, config.parallel, cost);
+            evaluations += n;
+            let previous_best = best_costs[best];
+            for (i, &cost) in costs.iter().enumerate() {
+                if cost < best_costs[i] || best_costs[i].is_nan() {
+                    best_costs[i] = cost;
+                    best_positions[i].clone_from(&positions[i]);
+                }
+            }
+            iteration += 1;
+
+            best = best_index(&best_costs);
+            if best_costs[best] < previous_best {
+                observer.on_event(&Event::NewBest {
+                    iteration,
+                    cost: direction.value(best_costs[best]),
+                });
+            }
+            cost_history.push(direction.value(best_costs[best]));
+            let values: Vec<f64> = costs.iter().map(|&c| direction.value(c)).collect();
+            observer.on_event(&Event::GenerationFinished {
+                stats: &GenerationStats::from_costs(&values, iteration, direction),
+            });
+        }
+
+        observer.on_event(&Event::Terminated {
+            iterations: iteration,
+            best_cost: direction.value(best_costs[best]),
+            reason: if cancelled {
+                TerminationReason::Cancelled
+            } else if timed_out {
+                TerminationReason::TimedOut
+            } else {
+                TerminationReason::MaxIterations
+            },
+        });
+
+        Ok(PsoResult {
+            best_cost: direction.value(best_costs[best]),
+            best: best_positions.swap_remove(best),
+            iterations: iteration,
+            evaluations,
+            cancelled,
+            timed_out,
+            cost_history,
+        })
+    }
+}
+
+/// The inertia weight for `iteration` (counted from 0).
+fn inertia_weight<R: Rng>(
+    inertia: InertiaWeight,
+    iteration: usize,
+    max_iterations: usize,
+    rng: &mut R,
+) -> f64 {
+    match inertia {
+        InertiaWeight::Constant { weight } => weight,
+        InertiaWeight::Linear { start, end } => {
+            start + (end - start) * iteration as f64 / max_iterations as f64
+        }
+        InertiaWeight::Random => 0.5 + rng.random::<f64>() / 2.0,
+    }
+}
+
+/// For each particle, the index of the particle whose best position it
+/// follows.
+fn leaders(best_costs: &[f64], topology: Topology) -> Vec<usize> {
+    let n = best_costs.len();
+    match topology {
+        Topology::Global => vec![best_index(best_costs); n],
+        Topology::Ring { neighbors } => {
+            let k = neighbors.min(n / 2);
+            (0..n)
+                .map(|i| {
+                    (i + n - k..=i + n + k)
+                        .map(|j| j % n)
+                        .min_by(|&a, &b| {
+                            best_costs[a]
+                                .partial_cmp(&best_costs[b])
+                                .unwrap_or(std::cmp::Ordering::Equal)
+                        })
+                        .unwrap_or(i)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Brings a position component outside its bounds `(lo, hi)` back, with
+/// `previous`, its value before the move, as reference, and adjusts its
+/// velocity.
+fn confine<R: Rng>(
+    x: &mut f64,
+    v: &mut f64,
+    previous: f64,
+    (lo, hi): (f64, f64),
+    handling: BoundHandling,
+    rng: &mut R,
+) {
+    if (lo..=hi).contains(x) {
+        return;
+    }
+    *x = handling.repair(*x, previous, lo, hi, rng);
+    *v = match handling {
+        BoundHandling::Reflect => -*v,
+        BoundHandling::Clamp | BoundHandling::Reinitialize | BoundHandling::Midpoint => 0.0,
+    };
+}
+
+/// Index of the lowest cost (the first one on ties).
+fn best_index(costs: &[f64]) -> usize {
+    (0..costs.len())
+        .min_by(|&a, &b| {
+            costs[a]
+                .partial_cmp(&costs[b])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brkga::BrkgaDecoder;
+    use crate::continuous::Bounds;
+    use crate::direction::Direction;
+    use crate::pso::RandomKeyProblem;
+
+    struct Sphere {
+        dim: usize,
+    }
+
+    impl PsoProblem for Sphere {
+        fn bounds(&self) -> Bounds {
+            Bounds::uniform(self.dim, -5.0, 5.0)
+        }
+
+        fn cost(&self, x: &[f64]) -> f64 {
+            x.iter().map(|v| v * v).sum()
+        }
+    }
+
+    /// Negated sphere, to be maximized.
+    struct NegatedSphere;
+
+    impl PsoProblem for NegatedSphere {
+        fn bounds(&self) -> Bounds {
+            Bounds::uniform(4, -5.0, 5.0)
+        }
+
+        fn cost(&self, x: &[f64]) -> f64 {
+            -Sphere { dim: 4 }.cost(x)
+        }
+    }
+
+    /// Sum of the variables over [1, 2]^3: the optimum lies on the lower
+    /// bound. Panics on out-of-bounds positions.
+    struct Corner;
+
+    impl PsoProblem for Corner {
+        fn bounds(&self) -> Bounds {
+            Bounds::uniform(3, 1.0, 2.0)
+        }
+
+        fn cost(&self, x: &[f64]) -> f64 {
+            assert!(
+                x.iter().all(|v| (1.0..=2.0).contains(v)),
+                "out of bounds: {x:?}"
+            );
+            x.iter().sum()
+        }
+    }
+
+    /// Decodes keys as the permutation sorting them; cost = positions out
+    /// of place.
+    struct SortingDecoder {
+        n: usize,
+    }
+
+    impl BrkgaDecoder for SortingDecoder {
+        fn decode(&self, keys: &[f64]) -> f64 {
+            assert!(keys.iter().all(|k| (0.0..1.0).contains(k)));
+            let mut perm: Vec<usize> = (0..self.n).collect();
+            perm.sort_by(|&a, &b| keys[a].partial_cmp(&keys[b]).unwrap());
+            perm.iter().enumerate().filter(|&(i, &p)| i != p).count() as f64
+        }
+    }
+
+    fn sphere_config() -> PsoConfig {
+        PsoConfig::default()
+            .with_max_iterations(400)
+            .with_seed(42)
+            .with_parallel(false)
+    }
+
+    fn assert_solves_sphere(config: &PsoConfig) {
+        let result = PsoRunner::run(&Sphere { dim: 5 }, config).unwrap();
+        assert!(
+            result.best_cost < 1e-4,
+            "expected cost < 1e-4, got {}",
+            result.best_cost
+        );
+        assert_eq!(result.best.len(), 5);
+        assert_eq!(result.iterations, 400);
+        assert_eq!(result.evaluations, 30 * 401);
+        assert!(result.cost_history.windows(2).all(|w| w[1] <= w[0]));
+    }
+
+    #[test]
+    fn test_pso_sphere_global() {
+        assert_solves_sphere(&sphere_config());
+    }
+
+    #[test]
+    fn test_pso_sphere_ring() {
+        assert_solves_sphere(&sphere_config().with_topology(Topology::Ring { neighbors: 1 }));
+    }
+
+    #[test]
+    fn test_pso_sphere_inertia_schedules() {
+        assert_solves_sphere(&sphere_config().with_inertia(InertiaWeight::Linear {
+            start: 0.9,
+            end: 0.4,
+        }));
+        assert_solves_sphere(&sphere_config().with_inertia(InertiaWeight::Random));
+    }
+
+    #[test]
+    fn test_pso_sphere_constriction() {
+        assert_solves_sphere(&sphere_config().with_constriction().without_velocity_clamp());
+    }
+
+    #[test]
+    fn test_pso_bound_handling() {
+        let handlers = [
+            BoundHandling::Clamp,
+            BoundHandling::Reflect,
+            BoundHandling::Reinitialize,
+            BoundHandling::Midpoint,
+        ];
+        for handling in handlers {
+            for seed in 0..5 {
+                let config = PsoConfig::default()
+                    .with_swarm_size(20)
+                    .with_max_iterations(200)
+                    .with_bound_handling(handling)
+                    .with_seed(seed)
+                    .with_parallel(false);
+                // `Corner` panics on positions out of bounds
+                let result = PsoRunner::run(&Corner, &config).unwrap();
+                assert!(
+                    result.best_cost < result.cost_history[0],
+                    "{handling:?}, seed {seed}: no improvement on the initial swarm"
+                );
+                // Reinitialization keeps scattering particles away from
+                // the optimum on the boundary
+                if handling != BoundHandling::Reinitialize {
+                    assert!(
+                        result.best_cost < 3.0 + 1e-2,
+                        "{handling:?}, seed {seed}: expected cost near 3, got {}",
+                        result.best_cost
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pso_confine() {
+        let mut rng = create_rng(1);
+        let mut confined = |x, v, handling| {
+            let (mut x, mut v) = (x, v);
+            confine(&mut x, &mut v, 0.8, (0.0, 1.0), handling, &mut rng);
+            (x, v)
+        };
+
+        assert_eq!(confined(-0.5, -0.7, BoundHandling::Clamp), (0.0, 0.0));
+        assert_eq!(confined(1.5, 0.7, BoundHandling::Reflect), (0.5, -0.7));
+        assert_eq!(confined(1.5, 0.7, BoundHandling::Midpoint), (0.9, 0.0));
+        assert_eq!(confined(0.5, 0.7, BoundHandling::Clamp), (0.5, 0.7));
+
+        let (x, v) = confined(2.0, 0.7, BoundHandling::Reinitialize);
+        assert!((0.0..=1.0).contains(&x));
+        assert_eq!(v, 0.0);
+    }
+
+    #[test]
+    fn test_pso_leaders() {
+        let costs = [3.0, 1.0, 2.0, 5.0, 0.0];
+        assert_eq!(leaders(&costs, Topology::Global), [4; 5]);
+        assert_eq!(
+            leaders(&costs, Topology::Ring { neighbors: 1 }),
+            [4, 1, 1, 4, 4]
+        );
+        assert_eq!(leaders(&costs, Topology::Ring { neighbors: 10 }), [4; 5]);
+    }
+
+    #[test]
+    fn test_pso_linear_inertia() {
+        let inertia = InertiaWeight::Linear {
+            start: 0.9,
+            end: 0.4,
+        };
+        let mut rng = create_rng(0);
+        assert_eq!(inertia_weight(inertia, 0, 100, &mut rng), 0.9);
+        assert!((inertia_weight(inertia, 50, 100, &mut rng) - 0.65).abs() < 1e-12);
+        for _ in 0..100 {
+            let w = inertia_weight(InertiaWeight::Random, 0, 100, &mut rng);
+            assert!((0.5..1.0).contains(&w));
+        }
+    }
+
+    #[test]
+    fn test_pso_random_keys() {
+        let decoder = SortingDecoder { n: 4 };
+        let problem = RandomKeyProblem::new(&decoder, 4);
+        let config = PsoConfig::default()
+            .with_max_iterations(200)
+            .with_seed(42)
+            .with_parallel(false);
+        let result = PsoRunner::run(&problem, &config).unwrap();
+        assert_eq!(result.best_cost, 0.0);
+        assert_eq!(decoder.decode(&result.best), result.best_cost);
+    }
+
+    #[test]
+    fn test_pso_maximize_mirrors_minimize() {
+        let config = sphere_config().with_max_iterations(50);
+        let min = PsoRunner::run(&Sphere { dim: 4 }, &config).unwrap();
+        let max = PsoRunner::run(
+            &NegatedSphere,
+            &config.clone().with_direction(Direction::Maximize),
+        )
+        .unwrap();
+        assert_eq!(max.best, min.best);
+        assert_eq!(max.best_cost, -min.best_cost);
+        assert!(max.cost_history.windows(2).all(|w| w[1] >= w[0]));
+    }
+
+    #[test]
+    fn test_pso_invalid_bounds() {
+        struct Unbounded;
+
+        impl PsoProblem for Unbounded {
+            fn bounds(&self) -> Bounds {
+                Bounds::uniform(2, f64::NEG_INFINITY, 0.0)
+            }
+
+            fn cost(&self, x: &[f64]) -> f64 {
+                x.iter().sum()
+            }
+        }
+
+        assert!(PsoRunner::run(&Unbounded, &PsoConfig::default()).is_err());
+    }
+
+    #[test]
+    fn test_pso_cancellation() {
+        let cancel = Arc::new(AtomicBool::new(true));
+        let result =
+            PsoRunner::run_with_cancel(&Sphere { dim: 3 }, &sphere_config(), Some(cancel)).unwrap();
+        assert!(result.cancelled);
+        assert_eq!(result.iterations, 0);
+        assert_eq!(result.evaluations, 30);
+        assert_eq!(result.cost_history.len(), 1);
+    }
+
+    #[test]
+    fn test_pso_observer_events() {
+        let config = sphere_config().with_max_iterations(40);
+
+        let mut iterations = Vec::new();
+        let mut bests = Vec::new();
+        let mut terminated = None;
+        let mut observer = |event: &Event<'_>| match *event {
+            Event::GenerationFinished { stats } => iterations.push(stats.generation),
+            Event::NewBest { cost, .. } => bests.push(cost),
+            Event::Terminated { reason, .. } => terminated = Some(reason),
+            _ => {}
+        };
+        let result =
+            PsoRunner::run_observed(&Sphere { dim: 3 }, &config, None, &mut observer).unwrap();
+
+        assert_eq!(iterations, (1..=40).collect::<Vec<_>>());
+        assert!(bests.windows(2).all(|w| w[1] < w[0]));
+        assert_eq!(bests.last(), Some(&result.best_cost));
+        assert_eq!(terminated, Some(TerminationReason::MaxIterations));
+    }
+}
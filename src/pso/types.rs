@@ -0,0 +1,102 @@
+//! Core trait for Particle Swarm Optimization.
+
+use crate::brkga::BrkgaDecoder;
+use crate::continuous::Bounds;
+
+/// Largest `f64` below 1, the upper bound of random keys.
+const MAX_KEY: f64 = 1.0 - f64::EPSILON / 2.0;
+
+/// Defines a continuous optimization problem for Particle Swarm
+/// Optimization.
+///
+/// Particle positions are vectors of `f64`, one value per variable, each
+/// within the interval given by [`bounds`](PsoProblem::bounds). The PSO
+/// framework handles velocities, neighborhoods and personal bests.
+///
+/// # Minimization
+///
+/// PSO minimizes the cost function. To maximize it instead, set
+/// [`PsoConfig::direction`](super::PsoConfig::direction) to
+/// [`Direction::Maximize`](crate::direction::Direction::Maximize).
+///
+/// # Examples
+///
+/// ```
+/// use u_metaheur::continuous::Bounds;
+/// use u_metaheur::pso::PsoProblem;
+///
+/// struct Sphere { dim: usize }
+///
+/// impl PsoProblem for Sphere {
+///     fn bounds(&self) -> Bounds {
+///         Bounds::uniform(self.dim, -5.0, 5.0)
+///     }
+///
+///     fn cost(&self, x: &[f64]) -> f64 {
+///         x.iter().map(|v| v * v).sum()
+///     }
+/// }
+/// ```
+///
+/// # References
+///
+/// Kennedy & Eberhart (1995)
+pub trait PsoProblem: Send + Sync {
+    /// Lower and upper limit of each variable. The number of variables is
+    /// `bounds().len()`.
+    ///
+    /// Called once per run. The limits must be finite.
+    fn bounds(&self) -> Bounds;
+
+    /// Computes the cost of a position. Lower is better unless the run
+    /// maximizes.
+    ///
+    /// `x` has one value per variable, each within its bounds.
+    fn cost(&self, x: &[f64]) -> f64;
+}
+
+/// Random-key PSO: evaluates particles with a [`BrkgaDecoder`].
+///
+/// Positions are random-key chromosomes of `chromosome_length` keys in
+/// `[0, 1)`, decoded exactly as BRKGA decodes them, so the same decoder
+/// can be optimized by either method. For permutation problems this is
+/// the smallest-position-value rule of Tasgetiren et al. (2007) when the
+/// decoder sorts the keys.
+///
+/// # Examples
+///
+/// ```ignore
+/// let problem = RandomKeyProblem::new(&decoder, 50);
+/// let result = PsoRunner::run(&problem, &PsoConfig::default())?;
+/// let cost = decoder.decode(&result.best);
+/// ```
+#[derive(Debug)]
+pub struct RandomKeyProblem<'a, D> {
+    decoder: &'a D,
+    chromosome_length: usize,
+}
+
+impl<'a, D: BrkgaDecoder> RandomKeyProblem<'a, D> {
+    /// Wraps `decoder` for chromosomes of `chromosome_length` keys.
+    pub fn new(decoder: &'a D, chromosome_length: usize) -> Self {
+        Self {
+            decoder,
+            chromosome_length,
+        }
+    }
+
+    /// The wrapped decoder.
+    pub fn decoder(&self) -> &'a D {
+        self.decoder
+    }
+}
+
+impl<D: BrkgaDecoder> PsoProblem for RandomKeyProblem<'_, D> {
+    fn bounds(&self) -> Bounds {
+        Bounds::uniform(self.chromosome_length, 0.0, MAX_KEY)
+    }
+
+    fn cost(&self, x: &[f64]) -> f64 {
+        self.decoder.decode(x)
+    }
+}
@@ -0,0 +1,73 @@
+//! Checkpointable random number generation for the GA and BRKGA runners.
+//!
+//! Run-state snapshots ([`GaState`](crate::ga::GaState) and
+//! [`BrkgaState`](crate::brkga::BrkgaState)) carry the exact state of the
+//! runner's generator as an [`RngState`]. A resumed run therefore draws the
+//! same numbers as the uninterrupted one, and taking a checkpoint does not
+//! disturb the run it is taken from.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+/// Generator of the runners whose state can be checkpointed.
+pub(crate) type CheckpointRng = ChaCha8Rng;
+
+/// Creates the generator for `seed`, or a randomly seeded one for `None`.
+pub(crate) fn create_checkpoint_rng(seed: Option<u64>) -> CheckpointRng {
+    ChaCha8Rng::seed_from_u64(seed.unwrap_or_else(rand::random))
+}
+
+/// Exact state of a runner's random number generator, stored in
+/// checkpoints. Serializable with the `serde` feature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RngState {
+    seed: [u8; 32],
+    stream: u64,
+    word_pos: u128,
+}
+
+impl RngState {
+    /// Captures the state of `rng` without advancing it.
+    pub(crate) fn capture(rng: &CheckpointRng) -> Self {
+        Self {
+            seed: rng.get_seed(),
+            stream: rng.get_stream(),
+            word_pos: rng.get_word_pos(),
+        }
+    }
+
+    /// Recreates the generator at the captured position.
+    pub(crate) fn restore(&self) -> CheckpointRng {
+        let mut rng = ChaCha8Rng::from_seed(self.seed);
+        rng.set_stream(self.stream);
+        rng.set_word_pos(self.word_pos);
+        rng
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_restore_continues_the_stream() {
+        let mut rng = create_checkpoint_rng(Some(7));
+        let _: u32 = rng.random();
+        let state = RngState::capture(&rng);
+
+        let mut restored = state.restore();
+        let expected: Vec<u64> = (0..10).map(|_| rng.random()).collect();
+        let actual: Vec<u64> = (0..10).map(|_| restored.random()).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_capture_does_not_advance() {
+        let mut rng = create_checkpoint_rng(Some(7));
+        let mut twin = create_checkpoint_rng(Some(7));
+        RngState::capture(&rng);
+        assert_eq!(rng.random::<u64>(), twin.random::<u64>());
+    }
+}
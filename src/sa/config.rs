@@ -1,5 +1,7 @@
 //! SA configuration and cooling schedules.
 
+use crate::direction::Direction;
+
 /// Cooling schedule for temperature reduction.
 ///
 /// # References
@@ -72,6 +74,19 @@ pub struct SaConfig {
     /// Maximum total iterations (hard budget). 0 = no limit.
     pub max_iterations: usize,
 
+    /// Number of best distinct solutions kept in
+    /// [`SaResult::hall_of_fame`](super::SaResult::hall_of_fame)
+    /// (0, the default, disables it).
+    pub hall_of_fame: usize,
+
+    /// Minimum [`SaProblem::distance`](super::SaProblem::distance) between
+    /// hall of fame entries. 0 only rejects duplicates.
+    pub hall_of_fame_distance: f64,
+
+    /// Whether [`SaProblem::cost`](super::SaProblem::cost) is minimized
+    /// (the default) or maximized.
+    pub direction: Direction,
+
     /// Random seed for reproducibility.
     pub seed: Option<u64>,
 }
@@ -84,6 +99,9 @@ impl Default for SaConfig {
             cooling: CoolingSchedule::default(),
             iterations_per_temperature: 100,
             max_iterations: 0,
+            hall_of_fame: 0,
+            hall_of_fame_distance: 0.0,
+            direction: Direction::Minimize,
             seed: None,
         }
     }
@@ -115,6 +133,19 @@ impl SaConfig {
         self
     }
 
+    /// Keeps the `size` best solutions that are more than `min_distance`
+    /// apart in the result's hall of fame.
+    pub fn with_hall_of_fame(mut self, size: usize, min_distance: f64) -> Self {
+        self.hall_of_fame = size;
+        self.hall_of_fame_distance = min_distance;
+        self
+    }
+
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     pub fn with_seed(mut self, seed: u64) -> Self {
         self.seed = Some(seed);
         self
@@ -144,6 +175,9 @@ impl SaConfig {
             }
             CoolingSchedule::Linear => {}
         }
+        if self.hall_of_fame_distance.is_nan() || self.hall_of_fame_distance < 0.0 {
+            return Err("hall_of_fame_distance must be non-negative".into());
+        }
         Ok(())
     }
 }
@@ -190,4 +224,10 @@ mod tests {
         let config = SaConfig::default().with_cooling(CoolingSchedule::LundyMees { beta: -1.0 });
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn test_validate_bad_hall_of_fame_distance() {
+        let config = SaConfig::default().with_hall_of_fame(5, -1.0);
+        assert!(config.validate().is_err());
+    }
 }
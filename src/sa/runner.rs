@@ -2,6 +2,8 @@
 
 use super::config::{CoolingSchedule, SaConfig};
 use super::types::SaProblem;
+use crate::hall_of_fame::HallOfFame;
+use crate::observer::{Event, NoopObserver, Observer, TerminationReason};
 use rand::Rng;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
@@ -33,6 +35,11 @@ pub struct SaResult<S: Clone> {
 
     /// Best cost sampled at regular intervals for history tracking.
     pub cost_history: Vec<f64>,
+
+    /// The best distinct solutions evaluated, with their costs, best first.
+    ///
+    /// Empty unless [`SaConfig::hall_of_fame`] is set.
+    pub hall_of_fame: Vec<(S, f64)>,
 }
 
 /// Executes the Simulated Annealing algorithm.
@@ -49,6 +56,20 @@ impl SaRunner {
         problem: &P,
         config: &SaConfig,
         cancel: Option<Arc<AtomicBool>>,
+    ) -> SaResult<P::Solution> {
+        Self::run_observed(problem, config, cancel, &mut NoopObserver)
+    }
+
+    /// Runs SA, reporting progress events to `observer`.
+    ///
+    /// Emits [`Event::NewBest`], [`Event::IterationFinished`] after every
+    /// neighbor evaluation, [`Event::TemperatureChanged`] after every
+    /// cooling step and [`Event::Terminated`].
+    pub fn run_observed<P: SaProblem>(
+        problem: &P,
+        config: &SaConfig,
+        cancel: Option<Arc<AtomicBool>>,
+        observer: &mut dyn Observer,
     ) -> SaResult<P::Solution> {
         config.validate().expect("invalid SaConfig");
 
@@ -57,11 +78,18 @@ impl SaRunner {
             None => create_rng(rand::random()),
         };
 
+        // Costs are minimized internally and reported in the configured
+        // direction
+        let direction = config.direction;
+
         // Initialize
         let mut current = problem.initial_solution(&mut rng);
-        let mut current_cost = problem.cost(&current);
+        let mut current_cost = direction.cost(problem.cost(&current));
         let mut best = current.clone();
         let mut best_cost = current_cost;
+        let mut hall_of_fame = HallOfFame::new(config.hall_of_fame, config.hall_of_fame_distance);
+        let distance = |a: &P::Solution, b: &P::Solution| problem.distance(a, b);
+        hall_of_fame.offer_solution(&current, current_cost, distance);
 
         let mut temperature = config.initial_temperature;
         let mut total_iterations = 0usize;
@@ -98,8 +126,9 @@ impl SaRunner {
                 }
 
                 let neighbor = problem.neighbor(&current, &mut rng);
-                let neighbor_cost = problem.cost(&neighbor);
+                let neighbor_cost = direction.cost(problem.cost(&neighbor));
                 let delta = neighbor_cost - current_cost;
+                hall_of_fame.offer_solution(&neighbor, neighbor_cost, distance);
 
                 // Metropolis acceptance criterion
                 let accept = if delta < 0.0 {
@@ -120,10 +149,19 @@ impl SaRunner {
                     if current_cost < best_cost {
                         best = current.clone();
                         best_cost = current_cost;
+                        observer.on_event(&Event::NewBest {
+                            iteration: total_iterations + 1,
+                            cost: direction.value(best_cost),
+                        });
                     }
                 }
 
                 total_iterations += 1;
+                observer.on_event(&Event::IterationFinished {
+                    iteration: total_iterations,
+                    current_cost: direction.value(current_cost),
+                    best_cost: direction.value(best_cost),
+                });
 
                 // Record history
                 if total_iterations.is_multiple_of(history_interval) {
@@ -139,8 +177,24 @@ impl SaRunner {
             // Cool down
             temperature = cool(temperature, config, step, linear_max_steps);
             step += 1;
+            observer.on_event(&Event::TemperatureChanged {
+                iteration: total_iterations,
+                temperature,
+            });
         }
 
+        observer.on_event(&Event::Terminated {
+            iterations: total_iterations,
+            best_cost: direction.value(best_cost),
+            reason: if cancelled {
+                TerminationReason::Cancelled
+            } else if config.max_iterations > 0 && total_iterations >= config.max_iterations {
+                TerminationReason::MaxIterations
+            } else {
+                TerminationReason::MinTemperature
+            },
+        });
+
         // Final history entry
         if cost_history
             .last()
@@ -151,13 +205,17 @@ impl SaRunner {
 
         SaResult {
             best,
-            best_cost,
+            best_cost: direction.value(best_cost),
             iterations: total_iterations,
             final_temperature: temperature,
             accepted_moves,
             improving_moves,
             cancelled,
-            cost_history,
+            cost_history: cost_history
+                .into_iter()
+                .map(|c| direction.value(c))
+                .collect(),
+            hall_of_fame: hall_of_fame.into_values(direction),
         }
     }
 }
@@ -199,6 +257,7 @@ fn compute_linear_steps(config: &SaConfig) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::direction::Direction;
     use crate::sa::{CoolingSchedule, SaConfig};
 
     // ---- Quadratic minimization: f(x) = x^2, minimum at 0 ----
@@ -242,6 +301,72 @@ mod tests {
         assert!(result.accepted_moves > result.improving_moves);
     }
 
+    #[test]
+    fn test_sa_hall_of_fame() {
+        let problem = QuadraticProblem;
+        let config = SaConfig::default()
+            .with_iterations_per_temperature(50)
+            .with_hall_of_fame(5, 0.5)
+            .with_seed(42);
+
+        let result = SaRunner::run(&problem, &config);
+        let hall_of_fame = &result.hall_of_fame;
+
+        assert_eq!(hall_of_fame.len(), 5);
+        assert_eq!(hall_of_fame[0].1, result.best_cost);
+        for (i, (x, cost)) in hall_of_fame.iter().enumerate() {
+            assert_eq!(problem.cost(x), *cost);
+            for (_, other) in &hall_of_fame[i + 1..] {
+                assert!(
+                    other - cost > 0.5,
+                    "default distance is the cost difference"
+                );
+            }
+        }
+        assert!(hall_of_fame.windows(2).all(|w| w[0].1 <= w[1].1));
+
+        let plain = SaRunner::run(&problem, &config.with_hall_of_fame(0, 0.0));
+        assert!(plain.hall_of_fame.is_empty());
+        assert_eq!(plain.cost_history, result.cost_history);
+    }
+
+    /// Quadratic problem that counts its cost evaluations.
+    struct CountingProblem(std::sync::atomic::AtomicUsize);
+
+    impl SaProblem for CountingProblem {
+        type Solution = f64;
+
+        fn initial_solution<R: Rng>(&self, rng: &mut R) -> f64 {
+            QuadraticProblem.initial_solution(rng)
+        }
+
+        fn cost(&self, x: &f64) -> f64 {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            QuadraticProblem.cost(x)
+        }
+
+        fn neighbor<R: Rng>(&self, x: &f64, rng: &mut R) -> f64 {
+            QuadraticProblem.neighbor(x, rng)
+        }
+    }
+
+    #[test]
+    fn test_sa_hall_of_fame_does_not_reevaluate() {
+        let config = SaConfig::default()
+            .with_iterations_per_temperature(20)
+            .with_seed(3);
+        let evaluations = |config: &SaConfig| {
+            let problem = CountingProblem(Default::default());
+            SaRunner::run(&problem, config);
+            problem.0.into_inner()
+        };
+
+        assert_eq!(
+            evaluations(&config.clone().with_hall_of_fame(10, 0.5)),
+            evaluations(&config)
+        );
+    }
+
     #[test]
     fn test_sa_quadratic_linear() {
         let problem = QuadraticProblem;
@@ -344,6 +469,44 @@ mod tests {
         }
     }
 
+    struct NegatedQuadratic;
+
+    impl SaProblem for NegatedQuadratic {
+        type Solution = f64;
+
+        fn initial_solution<R: Rng>(&self, rng: &mut R) -> f64 {
+            QuadraticProblem.initial_solution(rng)
+        }
+
+        fn cost(&self, x: &f64) -> f64 {
+            -QuadraticProblem.cost(x)
+        }
+
+        fn neighbor<R: Rng>(&self, x: &f64, rng: &mut R) -> f64 {
+            QuadraticProblem.neighbor(x, rng)
+        }
+    }
+
+    #[test]
+    fn test_sa_maximize_mirrors_minimize() {
+        let config = SaConfig::default()
+            .with_initial_temperature(50.0)
+            .with_min_temperature(0.01)
+            .with_seed(42);
+
+        let min = SaRunner::run(&QuadraticProblem, &config);
+        let max = SaRunner::run(
+            &NegatedQuadratic,
+            &config.with_direction(Direction::Maximize),
+        );
+
+        assert_eq!(max.best, min.best);
+        assert_eq!(max.best_cost, -min.best_cost);
+        assert!(max.best_cost <= 0.0);
+        assert!(max.cost_history.windows(2).all(|w| w[1] >= w[0]));
+        assert_eq!(max.accepted_moves, min.accepted_moves);
+    }
+
     // ---- Discrete: permutation sorting ----
 
     struct PermSortProblem {
@@ -486,4 +649,37 @@ mod tests {
             "geometric formula T_k = T_0·α^k failed: expected {expected}, got {t}"
         );
     }
+
+    #[test]
+    fn test_sa_observer_events() {
+        let problem = QuadraticProblem;
+        let config = SaConfig::default()
+            .with_iterations_per_temperature(10)
+            .with_max_iterations(500)
+            .with_seed(42);
+
+        let mut bests = Vec::new();
+        let mut iterations = 0;
+        let mut cooling_steps = 0;
+        let mut terminated = None;
+        let result =
+            SaRunner::run_observed(
+                &problem,
+                &config,
+                None,
+                &mut |event: &Event<'_>| match *event {
+                    Event::NewBest { cost, .. } => bests.push(cost),
+                    Event::IterationFinished { .. } => iterations += 1,
+                    Event::TemperatureChanged { .. } => cooling_steps += 1,
+                    Event::Terminated { reason, .. } => terminated = Some(reason),
+                    _ => {}
+                },
+            );
+
+        assert_eq!(iterations, result.iterations);
+        assert!(cooling_steps > 0);
+        assert!(bests.windows(2).all(|w| w[1] < w[0]));
+        assert_eq!(bests.last().copied(), Some(result.best_cost));
+        assert_eq!(terminated, Some(TerminationReason::MaxIterations));
+    }
 }
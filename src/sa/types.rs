@@ -10,7 +10,9 @@ use rand::Rng;
 ///
 /// # Minimization
 ///
-/// SA minimizes the cost function. For maximization, negate the cost.
+/// SA minimizes the cost function. To maximize it instead, set
+/// [`SaConfig::direction`](super::SaConfig::direction) to
+/// [`Direction::Maximize`](crate::direction::Direction::Maximize).
 ///
 /// # Examples
 ///
@@ -50,7 +52,8 @@ pub trait SaProblem: Send + Sync {
     /// Creates a random initial solution.
     fn initial_solution<R: Rng>(&self, rng: &mut R) -> Self::Solution;
 
-    /// Computes the cost of a solution. Lower is better.
+    /// Computes the cost of a solution. Lower is better unless the run
+    /// maximizes.
     fn cost(&self, solution: &Self::Solution) -> f64;
 
     /// Generates a neighbor of the current solution.
@@ -59,4 +62,16 @@ pub trait SaProblem: Send + Sync {
     /// (small perturbation) but the neighborhood must be connected
     /// (any solution reachable from any other via a sequence of moves).
     fn neighbor<R: Rng>(&self, solution: &Self::Solution, rng: &mut R) -> Self::Solution;
+
+    /// Returns the distance between two solutions, used to keep the hall
+    /// of fame diverse (see [`SaConfig::hall_of_fame`](super::SaConfig::hall_of_fame)).
+    ///
+    /// It should be symmetric, non-negative and 0 for identical solutions.
+    /// The default returns `None`, which uses the absolute difference of
+    /// the costs already computed and treats solutions of equal cost as
+    /// identical. Override it when solutions of equal cost should be kept
+    /// apart.
+    fn distance(&self, _a: &Self::Solution, _b: &Self::Solution) -> Option<f64> {
+        None
+    }
 }
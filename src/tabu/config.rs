@@ -1,5 +1,7 @@
 //! Tabu Search configuration.
 
+use crate::direction::Direction;
+
 /// Configuration parameters for Tabu Search.
 ///
 /// # Examples
@@ -25,6 +27,9 @@ pub struct TabuConfig {
     pub aspiration: bool,
     /// Maximum iterations without improvement before stopping.
     pub max_no_improve: usize,
+    /// Whether [`TabuProblem::cost`](super::TabuProblem::cost) and move
+    /// costs are minimized (the default) or maximized.
+    pub direction: Direction,
     /// Random seed (None for random).
     pub seed: Option<u64>,
 }
@@ -36,6 +41,7 @@ impl Default for TabuConfig {
             tabu_tenure: 7,
             aspiration: true,
             max_no_improve: 200,
+            direction: Direction::Minimize,
             seed: None,
         }
     }
@@ -66,6 +72,12 @@ impl TabuConfig {
         self
     }
 
+    /// Sets whether costs are minimized or maximized.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Sets the random seed.
     pub fn with_seed(mut self, seed: u64) -> Self {
         self.seed = Some(seed);
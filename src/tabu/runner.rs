@@ -20,6 +20,7 @@ use std::collections::VecDeque;
 
 use super::config::TabuConfig;
 use super::types::TabuProblem;
+use crate::observer::{Event, NoopObserver, Observer, OperatorKind, TerminationReason};
 
 /// Result of a Tabu Search run.
 #[derive(Debug, Clone)]
@@ -57,15 +58,33 @@ impl TabuRunner {
     /// }
     /// ```
     pub fn run<P: TabuProblem>(problem: &P, config: &TabuConfig) -> TabuResult<P::Solution> {
+        Self::run_observed(problem, config, &mut NoopObserver)
+    }
+
+    /// Executes Tabu Search, reporting progress events to `observer`.
+    ///
+    /// Each iteration emits [`Event::OperatorSelected`] for the chosen move
+    /// (with its key), [`Event::NewBest`] on improvement and
+    /// [`Event::IterationFinished`]. The run ends with [`Event::Terminated`].
+    pub fn run_observed<P: TabuProblem>(
+        problem: &P,
+        config: &TabuConfig,
+        observer: &mut dyn Observer,
+    ) -> TabuResult<P::Solution> {
         let mut rng = match config.seed {
             Some(s) => u_numflow::random::create_rng(s),
             None => u_numflow::random::create_rng(42),
         };
 
+        // Costs are minimized internally and reported in the configured
+        // direction
+        let direction = config.direction;
+
         // Initialize
         let mut current = problem.initial_solution(&mut rng);
+        let mut current_cost = direction.cost(problem.cost(&current));
         let mut best = current.clone();
-        let mut best_cost = problem.cost(&current);
+        let mut best_cost = current_cost;
         let mut best_iteration = 0;
 
         // Tabu list: FIFO queue of move keys with set for O(1) lookup
@@ -74,6 +93,7 @@ impl TabuRunner {
 
         let mut cost_history = Vec::with_capacity(config.max_iterations);
         let mut no_improve_count = 0;
+        let mut reason = TerminationReason::MaxIterations;
 
         for iteration in 0..config.max_iterations {
             // Generate neighborhood
@@ -81,28 +101,31 @@ impl TabuRunner {
 
             if neighbors.is_empty() {
                 cost_history.push(best_cost);
+                reason = TerminationReason::NoMoves;
                 break;
             }
 
+            let move_costs: Vec<f64> = neighbors.iter().map(|mv| direction.cost(mv.cost)).collect();
+
             // Find best admissible move
             let mut best_move = None;
             let mut best_move_cost = f64::INFINITY;
 
-            for mv in &neighbors {
+            for (i, mv) in neighbors.iter().enumerate() {
                 let is_tabu = tabu_set.contains(&mv.key);
 
                 if is_tabu {
                     // Aspiration: override tabu if this produces a new global best
-                    if config.aspiration && mv.cost < best_cost {
+                    if config.aspiration && move_costs[i] < best_cost {
                         // Aspiration criterion met
                     } else {
                         continue;
                     }
                 }
 
-                if mv.cost < best_move_cost {
-                    best_move_cost = mv.cost;
-                    best_move = Some(mv);
+                if move_costs[i] < best_move_cost {
+                    best_move_cost = move_costs[i];
+                    best_move = Some((i, mv));
                 }
             }
 
@@ -111,15 +134,23 @@ impl TabuRunner {
             if best_move.is_none() {
                 // All moves are tabu and none meets aspiration — pick least bad
                 let mut fallback_cost = f64::INFINITY;
-                for mv in &neighbors {
-                    if mv.cost < fallback_cost {
-                        fallback_cost = mv.cost;
-                        best_move = Some(mv);
+                for (i, mv) in neighbors.iter().enumerate() {
+                    if move_costs[i] < fallback_cost {
+                        fallback_cost = move_costs[i];
+                        best_move = Some((i, mv));
                     }
                 }
             }
 
-            if let Some(mv) = best_move {
+            if let Some((index, mv)) = best_move {
+                let move_cost = move_costs[index];
+                observer.on_event(&Event::OperatorSelected {
+                    iteration: iteration + 1,
+                    kind: OperatorKind::Move,
+                    index,
+                    name: Some(&mv.key),
+                });
+
                 // Update tabu list
                 if tabu_queue.len() >= config.tabu_tenure {
                     if let Some(old_key) = tabu_queue.pop_front() {
@@ -131,13 +162,18 @@ impl TabuRunner {
 
                 // Move to neighbor
                 current = mv.solution.clone();
+                current_cost = move_cost;
 
                 // Update global best
-                if mv.cost < best_cost {
+                if move_cost < best_cost {
                     best = current.clone();
-                    best_cost = mv.cost;
+                    best_cost = move_cost;
                     best_iteration = iteration;
                     no_improve_count = 0;
+                    observer.on_event(&Event::NewBest {
+                        iteration: iteration + 1,
+                        cost: direction.value(best_cost),
+                    });
                 } else {
                     no_improve_count += 1;
                 }
@@ -146,19 +182,34 @@ impl TabuRunner {
             }
 
             cost_history.push(best_cost);
+            observer.on_event(&Event::IterationFinished {
+                iteration: iteration + 1,
+                current_cost: direction.value(current_cost),
+                best_cost: direction.value(best_cost),
+            });
 
             // Stagnation check
             if no_improve_count >= config.max_no_improve {
+                reason = TerminationReason::Stagnated;
                 break;
             }
         }
 
+        observer.on_event(&Event::Terminated {
+            iterations: cost_history.len(),
+            best_cost: direction.value(best_cost),
+            reason,
+        });
+
         TabuResult {
             best,
-            best_cost,
+            best_cost: direction.value(best_cost),
             iterations: cost_history.len(),
             best_iteration,
-            cost_history,
+            cost_history: cost_history
+                .into_iter()
+                .map(|c| direction.value(c))
+                .collect(),
         }
     }
 }
@@ -166,6 +217,7 @@ impl TabuRunner {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::direction::Direction;
     use crate::tabu::{TabuConfig, TabuMove, TabuProblem};
     use rand::Rng;
 
@@ -229,6 +281,51 @@ mod tests {
         );
     }
 
+    /// `-(x - 5)^2`, maximized at x = 5.
+    struct NegatedQuadratic;
+
+    impl TabuProblem for NegatedQuadratic {
+        type Solution = i32;
+
+        fn initial_solution<R: Rng>(&self, rng: &mut R) -> i32 {
+            DiscretizedQuadratic.initial_solution(rng)
+        }
+
+        fn cost(&self, x: &i32) -> f64 {
+            -DiscretizedQuadratic.cost(x)
+        }
+
+        fn neighbors<R: Rng>(&self, x: &i32, rng: &mut R) -> Vec<TabuMove<i32>> {
+            DiscretizedQuadratic
+                .neighbors(x, rng)
+                .into_iter()
+                .map(|mv| TabuMove {
+                    cost: -mv.cost,
+                    ..mv
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_tabu_maximize_mirrors_minimize() {
+        let config = TabuConfig::default()
+            .with_max_iterations(100)
+            .with_tabu_tenure(3)
+            .with_seed(42);
+        let min = TabuRunner::run(&DiscretizedQuadratic, &config);
+        let max = TabuRunner::run(
+            &NegatedQuadratic,
+            &config.with_direction(Direction::Maximize),
+        );
+
+        assert_eq!(max.best, min.best);
+        assert_eq!(max.best_cost, -min.best_cost);
+        assert_eq!(max.best_iteration, min.best_iteration);
+        let negated: Vec<f64> = min.cost_history.iter().map(|c| -c).collect();
+        assert_eq!(max.cost_history, negated);
+    }
+
     #[test]
     fn test_tabu_cost_history_non_increasing() {
         let problem = DiscretizedQuadratic;
@@ -468,4 +565,34 @@ mod tests {
         assert_eq!(config.max_no_improve, 50);
         assert_eq!(config.seed, Some(123));
     }
+
+    #[test]
+    fn test_tabu_observer_events() {
+        let problem = DiscretizedQuadratic;
+        let config = TabuConfig::default()
+            .with_max_iterations(10_000)
+            .with_max_no_improve(20)
+            .with_seed(42);
+
+        let mut moves = 0;
+        let mut last_best = None;
+        let mut terminated = None;
+        let result =
+            TabuRunner::run_observed(&problem, &config, &mut |event: &Event<'_>| match *event {
+                Event::OperatorSelected { kind, name, .. } => {
+                    assert_eq!(kind, OperatorKind::Move);
+                    assert!(name.is_some());
+                    moves += 1;
+                }
+                Event::NewBest { cost, .. } => last_best = Some(cost),
+                Event::Terminated { reason, .. } => terminated = Some(reason),
+                _ => {}
+            });
+
+        assert_eq!(moves, result.iterations);
+        if result.best_iteration > 0 {
+            assert_eq!(last_best, Some(result.best_cost));
+        }
+        assert_eq!(terminated, Some(TerminationReason::Stagnated));
+    }
 }
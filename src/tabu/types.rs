@@ -33,7 +33,8 @@ pub trait TabuProblem: Send + Sync {
     /// Creates an initial solution.
     fn initial_solution<R: Rng>(&self, rng: &mut R) -> Self::Solution;
 
-    /// Evaluates the cost of a solution (lower is better).
+    /// Evaluates the cost of a solution (lower is better unless
+    /// [`TabuConfig::direction`](super::TabuConfig::direction) maximizes).
     fn cost(&self, solution: &Self::Solution) -> f64;
 
     /// Generates neighboring solutions with their move keys.
@@ -1,5 +1,7 @@
 //! Variable Neighborhood Search configuration.
 
+use crate::direction::Direction;
+
 /// Configuration parameters for Variable Neighborhood Search.
 ///
 /// # Examples
@@ -20,6 +22,9 @@ pub struct VnsConfig {
     pub max_iterations: usize,
     /// Maximum iterations without improvement before stopping.
     pub max_no_improve: usize,
+    /// Whether [`VnsProblem::cost`](super::VnsProblem::cost) is minimized
+    /// (the default) or maximized.
+    pub direction: Direction,
     /// Random seed (None for default seed).
     pub seed: Option<u64>,
 }
@@ -29,6 +34,7 @@ impl Default for VnsConfig {
         Self {
             max_iterations: 500,
             max_no_improve: 200,
+            direction: Direction::Minimize,
             seed: None,
         }
     }
@@ -47,6 +53,12 @@ impl VnsConfig {
         self
     }
 
+    /// Sets whether costs are minimized or maximized.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Sets the random seed.
     pub fn with_seed(mut self, seed: u64) -> Self {
         self.seed = Some(seed);
@@ -19,6 +19,7 @@
 
 use super::config::VnsConfig;
 use super::types::VnsProblem;
+use crate::observer::{Event, NoopObserver, Observer, OperatorKind, TerminationReason};
 
 /// Result of a VNS run.
 #[derive(Debug, Clone)]
@@ -58,6 +59,19 @@ impl VnsRunner {
     /// }
     /// ```
     pub fn run<P: VnsProblem>(problem: &P, config: &VnsConfig) -> VnsResult<P::Solution> {
+        Self::run_observed(problem, config, &mut NoopObserver)
+    }
+
+    /// Executes Basic VNS, reporting progress events to `observer`.
+    ///
+    /// Each shake emits [`Event::OperatorSelected`] with the neighborhood
+    /// index, followed by [`Event::NewBest`] on improvement and
+    /// [`Event::IterationFinished`]. The run ends with [`Event::Terminated`].
+    pub fn run_observed<P: VnsProblem>(
+        problem: &P,
+        config: &VnsConfig,
+        observer: &mut dyn Observer,
+    ) -> VnsResult<P::Solution> {
         let mut rng = match config.seed {
             Some(s) => u_numflow::random::create_rng(s),
             None => u_numflow::random::create_rng(42),
@@ -66,27 +80,39 @@ impl VnsRunner {
         let k_max = problem.neighborhood_count();
         assert!(k_max > 0, "neighborhood_count must be at least 1");
 
+        // Costs are minimized internally and reported in the configured
+        // direction
+        let direction = config.direction;
+
         // Initialize with local search
         let initial = problem.initial_solution(&mut rng);
         let mut current = problem.local_search(&initial);
         let mut best = current.clone();
-        let mut best_cost = problem.cost(&current);
+        let mut best_cost = direction.cost(problem.cost(&current));
         let mut best_iteration = 0;
 
         let mut cost_history = Vec::with_capacity(config.max_iterations);
         let mut no_improve_count = 0;
         let mut iteration = 0;
+        let mut reason = TerminationReason::MaxIterations;
 
         for outer in 0..config.max_iterations {
             let mut k = 0;
 
             while k < k_max {
+                observer.on_event(&Event::OperatorSelected {
+                    iteration: iteration + 1,
+                    kind: OperatorKind::Neighborhood,
+                    index: k,
+                    name: None,
+                });
+
                 // Shaking: random perturbation in neighborhood k
                 let shaken = problem.shake(&current, k, &mut rng);
 
                 // Local search on shaken solution
                 let candidate = problem.local_search(&shaken);
-                let candidate_cost = problem.cost(&candidate);
+                let candidate_cost = direction.cost(problem.cost(&candidate));
 
                 if candidate_cost < best_cost - 1e-12 {
                     // Improvement found — accept and reset to first neighborhood
@@ -96,6 +122,10 @@ impl VnsRunner {
                     best_iteration = outer;
                     k = 0;
                     no_improve_count = 0;
+                    observer.on_event(&Event::NewBest {
+                        iteration: iteration + 1,
+                        cost: direction.value(best_cost),
+                    });
                 } else {
                     // No improvement — try next neighborhood
                     k += 1;
@@ -103,22 +133,37 @@ impl VnsRunner {
                 }
 
                 iteration += 1;
+                observer.on_event(&Event::IterationFinished {
+                    iteration,
+                    current_cost: direction.value(best_cost),
+                    best_cost: direction.value(best_cost),
+                });
             }
 
             cost_history.push(best_cost);
 
             // Stagnation check
             if no_improve_count >= config.max_no_improve {
+                reason = TerminationReason::Stagnated;
                 break;
             }
         }
 
+        observer.on_event(&Event::Terminated {
+            iterations: iteration,
+            best_cost: direction.value(best_cost),
+            reason,
+        });
+
         VnsResult {
             best,
-            best_cost,
+            best_cost: direction.value(best_cost),
             iterations: iteration,
             best_iteration,
-            cost_history,
+            cost_history: cost_history
+                .into_iter()
+                .map(|c| direction.value(c))
+                .collect(),
         }
     }
 }
@@ -126,6 +171,7 @@ impl VnsRunner {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::direction::Direction;
     use crate::vns::{VnsConfig, VnsProblem};
     use rand::Rng;
 
@@ -204,6 +250,49 @@ mod tests {
         );
     }
 
+    /// `-(x - 10)^2`, maximized at x = 10.
+    struct NegatedQuadratic;
+
+    impl VnsProblem for NegatedQuadratic {
+        type Solution = i32;
+
+        fn initial_solution<R: Rng>(&self, rng: &mut R) -> i32 {
+            DiscreteQuadratic.initial_solution(rng)
+        }
+
+        fn cost(&self, x: &i32) -> f64 {
+            -DiscreteQuadratic.cost(x)
+        }
+
+        fn neighborhood_count(&self) -> usize {
+            DiscreteQuadratic.neighborhood_count()
+        }
+
+        fn shake<R: Rng>(&self, x: &i32, k: usize, rng: &mut R) -> i32 {
+            DiscreteQuadratic.shake(x, k, rng)
+        }
+
+        fn local_search(&self, x: &i32) -> i32 {
+            DiscreteQuadratic.local_search(x)
+        }
+    }
+
+    #[test]
+    fn test_vns_maximize_mirrors_minimize() {
+        let config = VnsConfig::default().with_max_iterations(30).with_seed(42);
+        let min = VnsRunner::run(&DiscreteQuadratic, &config);
+        let max = VnsRunner::run(
+            &NegatedQuadratic,
+            &config.with_direction(Direction::Maximize),
+        );
+
+        assert_eq!(max.best, min.best);
+        assert_eq!(max.best_cost, -min.best_cost);
+        assert_eq!(max.iterations, min.iterations);
+        let negated: Vec<f64> = min.cost_history.iter().map(|c| -c).collect();
+        assert_eq!(max.cost_history, negated);
+    }
+
     #[test]
     fn test_vns_cost_history_non_increasing() {
         let problem = DiscreteQuadratic;
@@ -409,4 +498,32 @@ mod tests {
             result.cost_history.len()
         );
     }
+
+    #[test]
+    fn test_vns_observer_events() {
+        let problem = DiscreteQuadratic;
+        let config = VnsConfig::default()
+            .with_max_iterations(50)
+            .with_max_no_improve(10)
+            .with_seed(42);
+
+        let mut neighborhoods = Vec::new();
+        let mut iterations = 0;
+        let mut terminated = None;
+        let result =
+            VnsRunner::run_observed(&problem, &config, &mut |event: &Event<'_>| match *event {
+                Event::OperatorSelected { kind, index, .. } => {
+                    assert_eq!(kind, OperatorKind::Neighborhood);
+                    neighborhoods.push(index);
+                }
+                Event::IterationFinished { .. } => iterations += 1,
+                Event::Terminated { reason, .. } => terminated = Some(reason),
+                _ => {}
+            });
+
+        assert_eq!(iterations, result.iterations);
+        assert_eq!(neighborhoods.len(), result.iterations);
+        assert!(neighborhoods.iter().all(|&k| k < 3));
+        assert_eq!(terminated, Some(TerminationReason::Stagnated));
+    }
 }
@@ -10,7 +10,11 @@ use rand::Rng;
 ///
 /// # Minimization
 ///
-/// VNS minimizes the cost function. For maximization, negate the cost.
+/// VNS minimizes the cost function. To maximize it instead, set
+/// [`VnsConfig::direction`](super::VnsConfig::direction) to
+/// [`Direction::Maximize`](crate::direction::Direction::Maximize).
+/// [`local_search`](Self::local_search) should then improve in the same
+/// sense.
 ///
 /// # References
 ///
@@ -23,7 +27,8 @@ pub trait VnsProblem: Send + Sync {
     /// Creates an initial solution.
     fn initial_solution<R: Rng>(&self, rng: &mut R) -> Self::Solution;
 
-    /// Computes the cost of a solution. Lower is better.
+    /// Computes the cost of a solution. Lower is better unless the run
+    /// maximizes.
     fn cost(&self, solution: &Self::Solution) -> f64;
 
     /// Returns the number of neighborhood structures (k_max).